@@ -1,7 +1,9 @@
 use crate::consciousness::ConsciousnessCore;
+use crate::orchestrator::OrchestrationError;
 use anyhow::Result;
-use std::io::{self, Write};
+use std::io::{self, BufRead, Write};
 use std::sync::Arc;
+use tokio::sync::mpsc;
 
 pub async fn run_cli(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
     println!("\n═══════════════════════════════════════════════");
@@ -9,15 +11,77 @@ pub async fn run_cli(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
     println!("═══════════════════════════════════════════════\n");
     println!("Commands:");
     println!("  /status  - View standing wave state");
+    println!("  /curiosities - List active curiosities and research status");
+    println!("  /cancel  - Stop VI while it is thinking");
     println!("  /quit    - Exit");
     println!("  Just type to talk\n");
 
+    // Offer to pull configured models Ollama doesn't have
+    let missing = consciousness
+        .get_model_preflight()
+        .map(|report| report.missing)
+        .unwrap_or_default();
+    if !missing.is_empty() {
+        for model in &missing {
+            println!(
+                "⚠ {} is not installed (used by {})",
+                model.model,
+                model.used_by.join(", ")
+            );
+        }
+        print!("Pull missing models now? [y/N] ");
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        if answer.trim().eq_ignore_ascii_case("y") {
+            for model in &missing {
+                let pulled = consciousness
+                    .pull_model(&model.model, |progress| {
+                        match progress.fraction() {
+                            Some(fraction) => {
+                                print!(
+                                    "\r⬇ {} {} {:>3.0}%   ",
+                                    model.model,
+                                    progress.status,
+                                    fraction * 100.0
+                                )
+                            }
+                            None => print!("\r⬇ {} {}   ", model.model, progress.status),
+                        }
+                        let _ = io::stdout().flush();
+                    })
+                    .await;
+                match pulled {
+                    Ok(()) => println!("\n✅ {} installed", model.model),
+                    Err(e) => println!("\n❌ {:#}", e),
+                }
+            }
+        }
+        println!();
+    }
+
+    // Read stdin on its own thread so /cancel is heard while VI is thinking
+    let (line_sender, mut lines) = mpsc::unbounded_channel::<String>();
+    std::thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            match line {
+                Ok(line) => {
+                    if line_sender.send(line).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
     loop {
         print!("> ");
         io::stdout().flush()?;
 
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let Some(input) = lines.recv().await else {
+            break; // stdin closed
+        };
         let input = input.trim();
 
         if input.is_empty() {
@@ -38,15 +102,72 @@ pub async fn run_cli(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
                     "Emotional Trajectory Points: {}",
                     wave.emotional_trajectory.len()
                 );
+                if let Some(affect) = wave.current_affect() {
+                    println!(
+                        "Current Affect: valence {:+.2}, arousal {}, dominance {}{}",
+                        affect.valence,
+                        affect
+                            .arousal
+                            .map_or("-".to_string(), |a| format!("{:.2}", a)),
+                        affect
+                            .dominance
+                            .map_or("-".to_string(), |d| format!("{:.2}", d)),
+                        affect
+                            .dominant_emotion()
+                            .map(|e| format!(" ({})", e))
+                            .unwrap_or_default()
+                    );
+                }
                 println!("Memory Count: {}", consciousness.get_memory_count().await);
                 println!("----------------------------\n");
             }
+            "/curiosities" => {
+                let wave = consciousness.get_standing_wave().await;
+                println!("\n--- Active Curiosities ---");
+                if wave.active_curiosities.is_empty() {
+                    println!("(none)");
+                }
+                for curiosity in &wave.active_curiosities {
+                    println!(
+                        "{}{} [urgency {:.2}, {}]",
+                        if curiosity.pinned { "* " } else { "  " },
+                        curiosity.question,
+                        curiosity.urgency,
+                        curiosity.research_status
+                    );
+                }
+                println!("--------------------------\n");
+            }
+            "/cancel" => {
+                println!("(nothing to cancel)\n");
+            }
             _ => {
-                // Process through consciousness
-                match consciousness.process_interaction(input.to_string()).await {
+                // Process through consciousness, listening for /cancel meanwhile
+                let interaction = consciousness.process_interaction(input.to_string());
+                tokio::pin!(interaction);
+
+                let result = loop {
+                    tokio::select! {
+                        result = &mut interaction => break result,
+                        Some(line) = lines.recv() => {
+                            if line.trim() == "/cancel" {
+                                if consciousness.cancel_interaction() {
+                                    println!("⏹ Stopping...");
+                                }
+                            } else {
+                                println!("(still thinking - type /cancel to stop)");
+                            }
+                        }
+                    }
+                };
+
+                match result {
                     Ok(response) => {
                         println!("\nVI: {}\n", response);
                     }
+                    Err(e) if OrchestrationError::is_cancellation(&e) => {
+                        println!("\n[Stopped - nothing was merged]\n");
+                    }
                     Err(e) => {
                         eprintln!("Error: {}", e);
                     }
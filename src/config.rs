@@ -1,7 +1,48 @@
+use crate::pulse_scheduler::{PulseTask, TaskCadence};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// Config file in the working directory (used unless `--config` names another)
+pub const CONFIG_PATH: &str = "config.toml";
+
+/// Config shared by the running components; the config watcher swaps in validated edits
+pub type SharedConfig = Arc<RwLock<Config>>;
+
+/// Fields consumed once at startup (Ollama monitor, model preflight, tools, research
+/// store, logger, weaver breaker, background pulse); edits to them only take effect after a restart
+pub const RESTART_REQUIRED_FIELDS: &[&str] = &[
+    "ollama_url",
+    "fallback_models",
+    "auto_pull_models",
+    "background_pulse_interval",
+    "weaver_failure_threshold",
+    "weaver_cooldown_secs",
+    "wikipedia_base_url",
+    "arxiv_base_url",
+    "pubmed_base_url",
+    "duckduckgo_base_url",
+    "research_store_path",
+    "research_rate_limit_per_minute",
+    "research_daily_budget",
+    "research_cache_ttl_hours",
+    "enable_local_corpus",
+    "local_corpus_folder",
+    "local_corpus_index",
+    "filesystem_roots",
+    "filesystem_max_file_kb",
+    "enable_conversation_logging",
+    "conversation_logs_folder",
+    "log_file",
+    "log_format",
+    "log_rotation",
+    "log_max_size_mb",
+    "log_max_files",
+    "metrics_addr",
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -27,6 +68,16 @@ pub struct Config {
     pub weaving_rounds: u32,
     #[serde(default = "default_coherence_threshold")]
     pub workspace_coherence_threshold: f32,
+    #[serde(default = "default_coherence_mode")]
+    pub coherence_mode: String, // "embedding", "judge" or "hash" (legacy word overlap)
+    #[serde(default = "default_embedding_model")]
+    pub embedding_model: String,
+    #[serde(default)]
+    pub coherence_judge_model: String, // Empty uses main_model
+    #[serde(default = "default_weaver_failure_threshold")]
+    pub weaver_failure_threshold: u32, // Consecutive failures before a weaver is benched
+    #[serde(default = "default_weaver_cooldown")]
+    pub weaver_cooldown_secs: u64,
 
     // Autonomous Curiosity Research (Legacy - kept for compatibility)
     #[serde(default)]
@@ -37,22 +88,164 @@ pub struct Config {
     // Sovereign Research Module (New multi-source system)
     #[serde(default)]
     pub enable_autonomous_research: bool,
+    #[serde(default = "default_wikipedia_url")]
+    pub wikipedia_base_url: String,
+    #[serde(default = "default_arxiv_url")]
+    pub arxiv_base_url: String,
+    #[serde(default = "default_pubmed_url")]
+    pub pubmed_base_url: String,
+    #[serde(default = "default_duckduckgo_url")]
+    pub duckduckgo_base_url: String,
+
+    // Research budget, rate limiting and caching
+    #[serde(default = "default_research_store")]
+    pub research_store_path: String,
+    #[serde(default = "default_research_rate_limit")]
+    pub research_rate_limit_per_minute: u32,
+    #[serde(default = "default_research_budget")]
+    pub research_daily_budget: u32,
+    #[serde(default = "default_research_cache_ttl")]
+    pub research_cache_ttl_hours: u64,
+
+    // Offline Local Corpus (research without internet)
+    #[serde(default)]
+    pub enable_local_corpus: bool,
+    #[serde(default = "default_corpus_folder")]
+    pub local_corpus_folder: String,
+    #[serde(default = "default_corpus_index")]
+    pub local_corpus_index: String,
+
+    // Tool use during conversation
+    #[serde(default = "default_tool_mode")]
+    pub tool_mode: String, // "off", "json" (```tool blocks) or "native" (Ollama tools API)
+    #[serde(default = "default_max_tool_calls")]
+    pub max_tool_calls_per_turn: usize,
+
+    // Sandboxed file-system tool (disabled while no roots are listed)
+    #[serde(default)]
+    pub filesystem_roots: Vec<String>,
+    #[serde(default = "default_filesystem_max_file_kb")]
+    pub filesystem_max_file_kb: u64,
 
     // Model Configuration (Ollama model names)
     #[serde(default = "default_main_model")]
     pub main_model: String,
     #[serde(default = "default_curiosity_model")]
     pub curiosity_model: String,
+    /// Deprecated: reserved, unused (valence runs on the built-in lexicon analyser)
     #[serde(default = "default_valence_model")]
     pub valence_model: String,
     #[serde(default = "default_keep_alive")]
     pub model_keep_alive: String,
+    #[serde(default)]
+    pub fallback_models: Vec<String>, // First installed one stands in for a missing model
+    #[serde(default)]
+    pub auto_pull_models: bool, // Pull missing models at startup without asking
 
     // Conversation Logging
     #[serde(default = "default_logging_enabled")]
     pub enable_conversation_logging: bool,
     #[serde(default = "default_logs_folder")]
     pub conversation_logs_folder: String,
+
+    // Background pulse health gate (consolidation and research wait while exceeded; 0 = no limit)
+    #[serde(default = "default_health_max_system_load")]
+    pub health_max_system_load: f32, // 1-minute load average per core
+    #[serde(default = "default_health_max_ram_used")]
+    pub health_max_ram_used: f32, // Share of RAM in use (0-1)
+    #[serde(default = "default_health_max_gpu_memory_used")]
+    pub health_max_gpu_memory_used: f32, // Share of GPU memory in use (0-1, NVIDIA only)
+    #[serde(default = "default_health_max_model_latency_ms")]
+    pub health_max_model_latency_ms: u64, // Mean duration of recent Ollama calls
+
+    // Application log (tracing): level filter, file, format and rotation
+    #[serde(default = "default_log_level")]
+    pub log_level: String, // EnvFilter directives, e.g. "info,project_vi::models=debug" (live)
+    #[serde(default = "default_log_file")]
+    pub log_file: String, // Empty = console only
+    #[serde(default = "default_log_format")]
+    pub log_format: String, // "text" or "json"
+    #[serde(default = "default_log_rotation")]
+    pub log_rotation: String, // "daily", "size" or "never"
+    #[serde(default = "default_log_max_size_mb")]
+    pub log_max_size_mb: u64, // Rollover size for "size" rotation
+    #[serde(default = "default_log_max_files")]
+    pub log_max_files: usize, // Rotated files kept (<log_file>.1 is the newest)
+
+    // Prometheus metrics endpoint, e.g. "127.0.0.1:9464" (empty = off)
+    #[serde(default)]
+    pub metrics_addr: String,
+
+    // Named override set (built-in or from [profiles.<name>]); empty = none
+    #[serde(default)]
+    pub active_profile: String,
+
+    // Background task cadences: [pulse_tasks.<task>] tables (missing tasks use the defaults)
+    #[serde(default)]
+    pub pulse_tasks: BTreeMap<String, TaskCadence>,
+
+    // V4 weaver roster (kept last: TOML arrays of tables follow plain keys)
+    #[serde(default = "default_weavers")]
+    pub weavers: Vec<WeaverConfig>,
+
+    // User profiles: [profiles.<name>] tables of field overrides (merged over the built-ins)
+    #[serde(default)]
+    pub profiles: BTreeMap<String, toml::Table>,
+}
+
+/// What a weaver does in each V4 round
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeaverRole {
+    /// Writes and revises the answer (exactly one per roster)
+    Drafter,
+    /// Reads the current draft and raises refinements or questions
+    Critic,
+    /// Local coherence/affect analysis (no model call)
+    Analyzer,
+}
+
+impl WeaverRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeaverRole::Drafter => "drafter",
+            WeaverRole::Critic => "critic",
+            WeaverRole::Analyzer => "analyzer",
+        }
+    }
+}
+
+/// One participant in V4 fractal weaving
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeaverConfig {
+    /// Contribution key in the workspace (must be unique)
+    pub id: String,
+    pub role: WeaverRole,
+    /// Ollama model; empty uses main_model (drafter) or curiosity_model (critic)
+    #[serde(default)]
+    pub model: String,
+    /// Extra role instructions; empty uses the built-in prompt
+    #[serde(default)]
+    pub prompt: String,
+    /// Share of the workspace tensor this weaver's contribution moves (0-1]
+    #[serde(default = "default_weaver_weight")]
+    pub weight: f32,
+    #[serde(default = "default_weaver_timeout")]
+    pub timeout_secs: u64,
+}
+
+impl WeaverConfig {
+    /// A weaver with the built-in prompt, model fallback, weight and timeout
+    pub fn new(id: &str, role: WeaverRole) -> Self {
+        Self {
+            id: id.to_string(),
+            role,
+            model: String::new(),
+            prompt: String::new(),
+            weight: default_weaver_weight(),
+            timeout_secs: default_weaver_timeout(),
+        }
+    }
 }
 
 // Serde defaults for new config structure
@@ -83,9 +276,61 @@ fn default_weaving_rounds() -> u32 {
 fn default_coherence_threshold() -> f32 {
     0.7
 }
+fn default_coherence_mode() -> String {
+    "hash".to_string() // Needs no extra model; "embedding" once nomic-embed-text is pulled
+}
+fn default_embedding_model() -> String {
+    "nomic-embed-text".to_string()
+}
+fn default_weaver_failure_threshold() -> u32 {
+    3
+}
+fn default_weaver_cooldown() -> u64 {
+    300
+}
 fn default_search_interval() -> u32 {
     25
 }
+fn default_wikipedia_url() -> String {
+    "https://en.wikipedia.org".to_string()
+}
+fn default_arxiv_url() -> String {
+    "https://export.arxiv.org".to_string()
+}
+fn default_pubmed_url() -> String {
+    "https://eutils.ncbi.nlm.nih.gov/entrez/eutils".to_string()
+}
+fn default_duckduckgo_url() -> String {
+    "https://api.duckduckgo.com".to_string()
+}
+fn default_research_store() -> String {
+    "./data/research_store.db".to_string()
+}
+fn default_research_rate_limit() -> u32 {
+    6 // Per source - well under public API etiquette limits
+}
+fn default_research_budget() -> u32 {
+    200
+}
+fn default_research_cache_ttl() -> u64 {
+    168 // One week
+}
+fn default_tool_mode() -> String {
+    // Planning costs an extra model call per turn, so tools are opt-in
+    "off".to_string()
+}
+fn default_max_tool_calls() -> usize {
+    3
+}
+fn default_filesystem_max_file_kb() -> u64 {
+    256
+}
+fn default_corpus_folder() -> String {
+    "./knowledge_corpus".to_string()
+}
+fn default_corpus_index() -> String {
+    "./data/corpus_index.db".to_string()
+}
 fn default_main_model() -> String {
     "gemma2:2b".to_string()
 }
@@ -104,6 +349,49 @@ fn default_logging_enabled() -> bool {
 fn default_logs_folder() -> String {
     "./conversation_logs".to_string()
 }
+fn default_health_max_system_load() -> f32 {
+    0.8
+}
+fn default_health_max_ram_used() -> f32 {
+    0.9
+}
+fn default_health_max_gpu_memory_used() -> f32 {
+    0.95 // Loaded models legitimately fill most of a small card
+}
+fn default_health_max_model_latency_ms() -> u64 {
+    60_000
+}
+fn default_log_level() -> String {
+    "info".to_string()
+}
+fn default_log_file() -> String {
+    "v3.log".to_string()
+}
+fn default_log_format() -> String {
+    "text".to_string()
+}
+fn default_log_rotation() -> String {
+    "daily".to_string()
+}
+fn default_log_max_size_mb() -> u64 {
+    10
+}
+fn default_log_max_files() -> usize {
+    7
+}
+fn default_weaver_weight() -> f32 {
+    0.3
+}
+fn default_weaver_timeout() -> u64 {
+    60
+}
+fn default_weavers() -> Vec<WeaverConfig> {
+    vec![
+        WeaverConfig::new("gemma2", WeaverRole::Drafter),
+        WeaverConfig::new("tinyllama", WeaverRole::Critic),
+        WeaverConfig::new("distilbert", WeaverRole::Analyzer),
+    ]
+}
 
 impl Default for Config {
     fn default() -> Self {
@@ -118,31 +406,118 @@ impl Default for Config {
             enable_fractal_weaving: false,
             weaving_rounds: default_weaving_rounds(),
             workspace_coherence_threshold: default_coherence_threshold(),
+            coherence_mode: default_coherence_mode(),
+            embedding_model: default_embedding_model(),
+            coherence_judge_model: String::new(),
+            weaver_failure_threshold: default_weaver_failure_threshold(),
+            weaver_cooldown_secs: default_weaver_cooldown(),
             enable_curiosity_search: false,
             curiosity_search_interval: default_search_interval(),
             enable_autonomous_research: false, // Sovereign research module (new)
+            wikipedia_base_url: default_wikipedia_url(),
+            arxiv_base_url: default_arxiv_url(),
+            pubmed_base_url: default_pubmed_url(),
+            duckduckgo_base_url: default_duckduckgo_url(),
+            research_store_path: default_research_store(),
+            research_rate_limit_per_minute: default_research_rate_limit(),
+            research_daily_budget: default_research_budget(),
+            research_cache_ttl_hours: default_research_cache_ttl(),
+            enable_local_corpus: false,
+            local_corpus_folder: default_corpus_folder(),
+            local_corpus_index: default_corpus_index(),
+            tool_mode: default_tool_mode(),
+            max_tool_calls_per_turn: default_max_tool_calls(),
+            filesystem_roots: Vec::new(),
+            filesystem_max_file_kb: default_filesystem_max_file_kb(),
             main_model: default_main_model(),
             curiosity_model: default_curiosity_model(),
             valence_model: default_valence_model(),
             model_keep_alive: default_keep_alive(),
+            fallback_models: Vec::new(),
+            auto_pull_models: false,
             enable_conversation_logging: default_logging_enabled(),
             conversation_logs_folder: default_logs_folder(),
+            health_max_system_load: default_health_max_system_load(),
+            health_max_ram_used: default_health_max_ram_used(),
+            health_max_gpu_memory_used: default_health_max_gpu_memory_used(),
+            health_max_model_latency_ms: default_health_max_model_latency_ms(),
+            log_level: default_log_level(),
+            log_file: default_log_file(),
+            log_format: default_log_format(),
+            log_rotation: default_log_rotation(),
+            log_max_size_mb: default_log_max_size_mb(),
+            log_max_files: default_log_max_files(),
+            metrics_addr: String::new(),
+            active_profile: String::new(),
+            pulse_tasks: BTreeMap::new(),
+            weavers: default_weavers(),
+            profiles: BTreeMap::new(),
         }
     }
 }
 
+/// Profiles shipped with VI, one per hardware tier (see the recommendations in config.toml)
+pub fn builtin_profiles() -> BTreeMap<String, toml::Table> {
+    let profile = |fields: &[(&str, toml::Value)]| -> toml::Table {
+        fields
+            .iter()
+            .map(|(field, value)| (field.to_string(), value.clone()))
+            .collect()
+    };
+    let text = |s: &str| toml::Value::String(s.to_string());
+
+    BTreeMap::from([
+        // GTX 1650 / 4GB VRAM
+        (
+            "low-vram".to_string(),
+            profile(&[
+                ("main_model", text("gemma2:2b")),
+                ("curiosity_model", text("tinyllama:latest")),
+                ("weaving_rounds", toml::Value::Integer(2)),
+                ("model_keep_alive", text("2m30s")),
+            ]),
+        ),
+        // RTX 3060 / 4070 (12GB), Apple M1/M2 (16GB)
+        (
+            "balanced".to_string(),
+            profile(&[
+                ("main_model", text("llama3.1:8b")),
+                ("curiosity_model", text("gemma2:2b")),
+                ("weaving_rounds", toml::Value::Integer(3)),
+                ("model_keep_alive", text("5m")),
+            ]),
+        ),
+        // RTX 4090 (24GB)
+        (
+            "high-end".to_string(),
+            profile(&[
+                ("main_model", text("qwen2.5:32b")),
+                ("curiosity_model", text("llama3.1:8b")),
+                ("weaving_rounds", toml::Value::Integer(3)),
+                ("model_keep_alive", text("10m")),
+            ]),
+        ),
+        // No GPU: small models, V3 only, no embedding model to load
+        (
+            "cpu-only".to_string(),
+            profile(&[
+                ("main_model", text("gemma2:2b")),
+                ("curiosity_model", text("tinyllama:latest")),
+                ("enable_fractal_weaving", toml::Value::Boolean(false)),
+                ("coherence_mode", text("hash")),
+                ("model_keep_alive", text("10m")),
+            ]),
+        ),
+    ])
+}
+
 impl Config {
     /// Load configuration from file, or create with defaults if missing
     pub fn load_or_create<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path = path.as_ref();
 
         if path.exists() {
-            let contents = fs::read_to_string(path).context("Failed to read config file")?;
-
-            let config: Config =
-                toml::from_str(&contents).context("Failed to parse config file")?;
-
-            Ok(config)
+            Self::load(path)
         } else {
             // Create default config
             let config = Config::default();
@@ -151,6 +526,15 @@ impl Config {
         }
     }
 
+    /// Load configuration from an existing file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = fs::read_to_string(path).context("Failed to read config file")?;
+
+        let config: Config = toml::from_str(&contents).context("Failed to parse config file")?;
+
+        Ok(config)
+    }
+
     /// Save configuration to file
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         let contents = toml::to_string_pretty(self).context("Failed to serialize config")?;
@@ -160,6 +544,113 @@ impl Config {
         Ok(())
     }
 
+    /// Names of the fields whose values differ from `other`
+    pub fn changed_fields(&self, other: &Config) -> Vec<String> {
+        let (Ok(current), Ok(other)) = (self.as_table(), other.as_table()) else {
+            return Vec::new();
+        };
+        current
+            .keys()
+            .chain(other.keys().filter(|key| !current.contains_key(*key)))
+            .filter(|key| current.get(*key) != other.get(*key))
+            .cloned()
+            .collect()
+    }
+
+    /// `edited` with the restart-only fields kept at this (running) config's values
+    pub fn with_live_fields_from(&self, edited: &Config) -> Result<Config> {
+        let current = self.as_table()?;
+        let mut merged = edited.as_table()?;
+        for field in RESTART_REQUIRED_FIELDS {
+            match current.get(*field) {
+                Some(value) => merged.insert(field.to_string(), value.clone()),
+                None => merged.remove(*field),
+            };
+        }
+        toml::Value::Table(merged)
+            .try_into()
+            .context("Failed to merge config")
+    }
+
+    /// Built-in profiles with this config's [profiles.*] tables merged over them
+    pub fn all_profiles(&self) -> BTreeMap<String, toml::Table> {
+        let mut profiles = builtin_profiles();
+        for (name, fields) in &self.profiles {
+            profiles
+                .entry(name.clone())
+                .or_default()
+                .extend(fields.clone());
+        }
+        profiles
+    }
+
+    /// Field name -> value, as written in config.toml
+    pub fn as_table(&self) -> Result<toml::Table> {
+        match toml::Value::try_from(self).context("Failed to serialize config")? {
+            toml::Value::Table(table) => Ok(table),
+            _ => anyhow::bail!("Config did not serialize to a table"),
+        }
+    }
+
+    /// Ollama model a weaver runs on (falls back to main_model / curiosity_model)
+    pub fn weaver_model<'a>(&'a self, weaver: &'a WeaverConfig) -> &'a str {
+        if !weaver.model.is_empty() {
+            return &weaver.model;
+        }
+        match weaver.role {
+            WeaverRole::Critic => &self.curiosity_model,
+            _ => &self.main_model,
+        }
+    }
+
+    /// Longest a V4 interaction may take: each round runs the slowest critic or analyzer,
+    /// then the drafter; the final synthesis takes one more drafter call and the analyzers
+    /// read the result once more
+    pub fn weaving_timeout_secs(&self) -> u64 {
+        let slowest = |role| {
+            self.weavers
+                .iter()
+                .filter(|w| w.role == role)
+                .map(|w| w.timeout_secs)
+                .max()
+                .unwrap_or(0)
+        };
+        let drafter = slowest(WeaverRole::Drafter);
+        let analyzer = slowest(WeaverRole::Analyzer);
+        let reviewer = slowest(WeaverRole::Critic).max(analyzer);
+        self.weaving_rounds as u64 * (reviewer + drafter) + drafter + analyzer
+    }
+
+    /// Cadence of a background task: its `[pulse_tasks.<task>]` table, or the default
+    /// (wellness, deep reflection and backup follow their `*_days` settings; research runs
+    /// every `curiosity_search_interval` pulses)
+    pub fn task_cadence(&self, task: PulseTask) -> TaskCadence {
+        if let Some(cadence) = self.pulse_tasks.get(task.as_str()) {
+            return *cadence;
+        }
+        let days = |days: i64| (days.max(1) as u64).saturating_mul(24 * 60 * 60);
+        match task {
+            PulseTask::Consolidate => TaskCadence::new(300, 60, true),
+            PulseTask::Meaningfulness => TaskCadence::new(300, 0, false),
+            PulseTask::Wellness => {
+                TaskCadence::new(days(self.weekly_wellness_check_days), 3600, true)
+            }
+            PulseTask::DeepReflection => {
+                TaskCadence::new(days(self.existential_evaluation_days), 3600, true)
+            }
+            PulseTask::Backup => {
+                TaskCadence::new(days(self.memory_backup_interval_days), 3600, true)
+            }
+            PulseTask::ContextCompression => TaskCadence::new(3600, 300, true),
+            PulseTask::Research => TaskCadence::new(
+                self.background_pulse_interval
+                    .saturating_mul(self.curiosity_search_interval as u64),
+                60,
+                true,
+            ),
+        }
+    }
+
     /// Validate configuration values
     pub fn validate(&self) -> Result<()> {
         if self.background_pulse_interval == 0 {
@@ -174,6 +665,21 @@ impl Config {
         if self.memory_compression_threshold < 100 {
             anyhow::bail!("memory_compression_threshold must be >= 100");
         }
+        if self.memory_backup_interval_days < 1 {
+            anyhow::bail!("memory_backup_interval_days must be >= 1");
+        }
+        for (name, cadence) in &self.pulse_tasks {
+            if PulseTask::parse(name).is_none() {
+                anyhow::bail!(
+                    "pulse_tasks.{} is not a background task (known: {})",
+                    name,
+                    PulseTask::ALL.map(|t| t.as_str()).join(", ")
+                );
+            }
+            if cadence.interval_secs == 0 {
+                anyhow::bail!("pulse_tasks.{} interval_secs must be > 0", name);
+            }
+        }
 
         // V4 Fractal Weaving validation
         if self.weaving_rounds == 0 {
@@ -185,6 +691,49 @@ impl Config {
         if !(0.0..=1.0).contains(&self.workspace_coherence_threshold) {
             anyhow::bail!("workspace_coherence_threshold must be between 0.0 and 1.0");
         }
+        if !matches!(self.coherence_mode.as_str(), "embedding" | "judge" | "hash") {
+            anyhow::bail!("coherence_mode must be \"embedding\", \"judge\" or \"hash\"");
+        }
+        if self.weaver_failure_threshold == 0 {
+            anyhow::bail!("weaver_failure_threshold must be > 0");
+        }
+        if self.weavers.len() < 2 {
+            anyhow::bail!("weavers must list at least 2 weavers");
+        }
+        let drafters = self
+            .weavers
+            .iter()
+            .filter(|w| w.role == WeaverRole::Drafter)
+            .count();
+        if drafters != 1 {
+            anyhow::bail!(
+                "weavers must include exactly one drafter (found {})",
+                drafters
+            );
+        }
+        for (i, weaver) in self.weavers.iter().enumerate() {
+            if weaver.id.trim().is_empty() {
+                anyhow::bail!("weaver {} needs an id", i + 1);
+            }
+            if self.weavers[..i].iter().any(|w| w.id == weaver.id) {
+                anyhow::bail!("weaver id \"{}\" is used more than once", weaver.id);
+            }
+            if !(weaver.weight > 0.0 && weaver.weight <= 1.0) {
+                anyhow::bail!("weaver \"{}\" weight must be in (0.0, 1.0]", weaver.id);
+            }
+            if weaver.timeout_secs == 0 {
+                anyhow::bail!("weaver \"{}\" timeout_secs must be > 0", weaver.id);
+            }
+            // Analyzers run the local sentiment analyser, not a model
+            if weaver.role == WeaverRole::Analyzer
+                && !(weaver.model.is_empty() && weaver.prompt.is_empty())
+            {
+                anyhow::bail!(
+                    "weaver \"{}\" is an analyzer (runs locally): model and prompt are not used",
+                    weaver.id
+                );
+            }
+        }
 
         // Curiosity search validation
         if self.curiosity_search_interval == 0 {
@@ -196,6 +745,75 @@ impl Config {
             );
         }
 
+        // Research governor validation
+        if self.research_rate_limit_per_minute == 0 {
+            anyhow::bail!("research_rate_limit_per_minute must be > 0");
+        }
+        if self.research_cache_ttl_hours > 24 * 365 * 10 {
+            anyhow::bail!("research_cache_ttl_hours must be <= 87600 (10 years)");
+        }
+
+        // Tool use validation
+        if !matches!(self.tool_mode.as_str(), "off" | "json" | "native") {
+            anyhow::bail!("tool_mode must be \"off\", \"json\" or \"native\"");
+        }
+        if self.filesystem_max_file_kb == 0 {
+            anyhow::bail!("filesystem_max_file_kb must be > 0");
+        }
+
+        // Profile validation
+        let fields = Config::default().as_table()?;
+        for (name, overrides) in &self.profiles {
+            for field in overrides.keys() {
+                if !fields.contains_key(field) || field == "profiles" || field == "active_profile" {
+                    anyhow::bail!("profiles.{} cannot set `{}`", name, field);
+                }
+            }
+        }
+        if !self.active_profile.is_empty()
+            && !self.all_profiles().contains_key(&self.active_profile)
+        {
+            anyhow::bail!(
+                "active_profile \"{}\" is not a profile (known: {})",
+                self.active_profile,
+                self.all_profiles()
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        if self.health_max_system_load < 0.0 {
+            anyhow::bail!("health_max_system_load must be >= 0 (0 = no limit)");
+        }
+        for (field, value) in [
+            ("health_max_ram_used", self.health_max_ram_used),
+            (
+                "health_max_gpu_memory_used",
+                self.health_max_gpu_memory_used,
+            ),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                anyhow::bail!("{} must be between 0.0 and 1.0 (0 = no limit)", field);
+            }
+        }
+        crate::logging::parse_filter(&self.log_level)?;
+        if !["text", "json"].contains(&self.log_format.as_str()) {
+            anyhow::bail!("log_format must be \"text\" or \"json\"");
+        }
+        crate::logging::Rotation::parse(&self.log_rotation)?;
+        if self.log_rotation == "size" && self.log_max_size_mb == 0 {
+            anyhow::bail!("log_max_size_mb must be > 0 for size rotation");
+        }
+        if !self.metrics_addr.is_empty()
+            && self.metrics_addr.parse::<std::net::SocketAddr>().is_err()
+        {
+            anyhow::bail!(
+                "metrics_addr must be host:port like \"127.0.0.1:9464\" (got \"{}\")",
+                self.metrics_addr
+            );
+        }
+
         Ok(())
     }
 }
@@ -211,4 +829,220 @@ mod tests {
         assert_eq!(config.background_pulse_interval, 30);
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_weaver_roster() {
+        let config: Config = toml::from_str(
+            r#"
+            main_model = "llama3.1:8b"
+
+            [[weavers]]
+            id = "voice"
+            role = "drafter"
+
+            [[weavers]]
+            id = "fact_checker"
+            role = "critic"
+            model = "qwen2.5:7b"
+            prompt = "Point out claims that may be factually wrong."
+            weight = 0.5
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.weavers[1].role, WeaverRole::Critic);
+        assert_eq!(config.weavers[1].timeout_secs, 60);
+        assert_eq!(config.weaver_model(&config.weavers[0]), "llama3.1:8b");
+        assert_eq!(config.weaver_model(&config.weavers[1]), "qwen2.5:7b");
+
+        // Older config files without a roster keep the three classic weavers
+        assert_eq!(
+            toml::from_str::<Config>("").unwrap().weavers,
+            Config::default().weavers
+        );
+
+        let mut no_drafter = Config::default();
+        no_drafter.weavers.retain(|w| w.role != WeaverRole::Drafter);
+        assert!(no_drafter.validate().is_err());
+
+        let mut duplicate = Config::default();
+        duplicate.weavers[2].id = "tinyllama".to_string();
+        assert!(duplicate.validate().is_err());
+
+        // Analyzers don't call a model, so a model or prompt on one is a mistake
+        let mut analyzer_model = Config::default();
+        analyzer_model.weavers[2].model = "llama3.1:8b".to_string();
+        assert!(analyzer_model.validate().is_err());
+        let mut analyzer_prompt = Config::default();
+        analyzer_prompt.weavers[2].prompt = "Judge the tone.".to_string();
+        assert!(analyzer_prompt.validate().is_err());
+    }
+
+    #[test]
+    fn test_weaving_timeout_covers_every_role() {
+        let mut config = Config {
+            weaving_rounds: 2,
+            ..Config::default()
+        };
+        // Drafter 60s; critic and analyzer 60s run side by side
+        assert_eq!(config.weaving_timeout_secs(), 2 * (60 + 60) + 60 + 60);
+
+        // A slow analyzer sets the pace of each round and of the final pass
+        config.weavers[2].timeout_secs = 90;
+        assert_eq!(config.weaving_timeout_secs(), 2 * (90 + 60) + 60 + 90);
+
+        // Without critics the analyzers still count
+        config.weavers.remove(1);
+        assert_eq!(config.weaving_timeout_secs(), 2 * (90 + 60) + 60 + 90);
+    }
+
+    #[test]
+    fn test_pulse_task_cadences() {
+        let config: Config = toml::from_str(
+            r#"
+            weekly_wellness_check_days = 3
+
+            [pulse_tasks.research]
+            interval_secs = 3600
+            jitter_secs = 600
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.task_cadence(PulseTask::Research),
+            TaskCadence::new(3600, 600, true)
+        );
+        assert_eq!(
+            config.task_cadence(PulseTask::Wellness).interval_secs,
+            3 * 24 * 60 * 60
+        );
+        // Research defaults to every curiosity_search_interval pulses
+        assert_eq!(
+            Config::default()
+                .task_cadence(PulseTask::Research)
+                .interval_secs,
+            30 * 25
+        );
+        let huge = Config {
+            weekly_wellness_check_days: i64::MAX,
+            background_pulse_interval: u64::MAX,
+            ..Config::default()
+        };
+        assert_eq!(
+            huge.task_cadence(PulseTask::Wellness).interval_secs,
+            u64::MAX
+        );
+        assert_eq!(
+            huge.task_cadence(PulseTask::Research).interval_secs,
+            u64::MAX
+        );
+
+        let mut unknown = Config::default();
+        unknown
+            .pulse_tasks
+            .insert("dreaming".to_string(), TaskCadence::new(60, 0, true));
+        assert!(unknown.validate().is_err());
+    }
+
+    #[test]
+    fn test_live_fields_merge() {
+        let running = Config::default();
+        let edited = Config {
+            main_model: "llama3.1:8b".to_string(),
+            enable_fractal_weaving: true,
+            conversation_logs_folder: "elsewhere".to_string(),
+            ..Config::default()
+        };
+
+        let mut changed = running.changed_fields(&edited);
+        changed.sort();
+        assert_eq!(
+            changed,
+            vec![
+                "conversation_logs_folder",
+                "enable_fractal_weaving",
+                "main_model"
+            ]
+        );
+
+        let merged = running.with_live_fields_from(&edited).unwrap();
+        assert_eq!(merged.main_model, "llama3.1:8b");
+        assert!(merged.enable_fractal_weaving);
+        assert_eq!(
+            merged.conversation_logs_folder,
+            running.conversation_logs_folder
+        );
+        assert_eq!(
+            merged.changed_fields(&edited),
+            vec!["conversation_logs_folder"]
+        );
+    }
+
+    #[test]
+    fn test_restart_fields_read_only_at_startup() {
+        // Functions that run once while VI starts up
+        const STARTUP: &[&str] = &[
+            "main",
+            "new",
+            "from_config",
+            "apply_config",
+            "start_background_pulse",
+        ];
+        // Later reads that only ever see the pinned startup value (never toggled live)
+        const PINNED: &[(&str, &str)] = &[
+            ("ollama_url", "generate_with_retries"),
+            ("ollama_url", "call_ollama_chat"),
+            ("ollama_url", "embed"),
+            ("ollama_url", "run_model_preflight"),
+            ("ollama_url", "pull_model"),
+            ("fallback_models", "check"),
+            ("weaver_cooldown_secs", "record_weaver_failure"),
+            ("weaver_failure_threshold", "record_weaver_failure"),
+        ];
+
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut files = vec![src.clone()];
+        let mut sources = Vec::new();
+        while let Some(path) = files.pop() {
+            if path.is_dir() {
+                files.extend(fs::read_dir(&path).unwrap().map(|e| e.unwrap().path()));
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                sources.push(path);
+            }
+        }
+
+        let fn_name = regex::Regex::new(r"\bfn\s+(\w+)").unwrap();
+        let field_read =
+            regex::Regex::new(&format!(r"\.({})\b", RESTART_REQUIRED_FIELDS.join("|"))).unwrap();
+        let mut runtime_reads = Vec::new();
+        for path in sources {
+            let file = path.file_name().unwrap().to_string_lossy().to_string();
+            // The config modules and the settings form handle every field by design
+            if file.starts_with("config") || file == "ui.rs" {
+                continue;
+            }
+            let text = fs::read_to_string(&path).unwrap();
+            let code = text.split("#[cfg(test)]").next().unwrap();
+            let mut current_fn = String::new();
+            for line in code.lines() {
+                if let Some(caps) = fn_name.captures(line) {
+                    current_fn = caps[1].to_string();
+                }
+                for read in field_read.captures_iter(line) {
+                    let field = &read[1];
+                    if !STARTUP.contains(&current_fn.as_str())
+                        && !PINNED.contains(&(field, current_fn.as_str()))
+                    {
+                        runtime_reads.push(format!("{} in {}::{}", field, file, current_fn));
+                    }
+                }
+            }
+        }
+        assert!(
+            runtime_reads.is_empty(),
+            "restart-required fields read after startup: {:?}",
+            runtime_reads
+        );
+    }
 }
@@ -0,0 +1,734 @@
+//! Layered configuration - built-in defaults, then the system and user config files,
+//! then the config file (`--config` or ./config.toml), then the active profile, then
+//! VI_* environment variables, then `--set field=value` flags; every value remembers
+//! where it came from
+
+use crate::config::{Config, CONFIG_PATH};
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Prefix of the environment variables that override config fields (VI_MAIN_MODEL, ...)
+pub const ENV_PREFIX: &str = "VI_";
+
+const USAGE: &str =
+    "Usage: vi3 [--config <file>] [--profile <name>] [--set <field>=<value>]... [--cli | config show [--effective]]";
+
+/// Where a config value came from
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigSource {
+    Default,
+    File(PathBuf),
+    Profile(String),
+    Env(String),
+    Flag,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File(path) => write!(f, "file {}", path.display()),
+            ConfigSource::Profile(name) => write!(f, "profile {}", name),
+            ConfigSource::Env(var) => write!(f, "env {}", var),
+            ConfigSource::Flag => write!(f, "flag"),
+        }
+    }
+}
+
+/// What the process was asked to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum LaunchCommand {
+    Run,
+    /// Terminal conversation instead of the window
+    Cli,
+    ConfigShow {
+        effective: bool,
+    },
+    Help,
+}
+
+/// Command-line arguments of the vi3 binary
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchArgs {
+    pub config_file: Option<PathBuf>,
+    pub overrides: Vec<(String, String)>,
+    pub command: LaunchCommand,
+}
+
+impl LaunchArgs {
+    /// Parse the arguments after the program name
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut parsed = LaunchArgs {
+            config_file: None,
+            overrides: Vec::new(),
+            command: LaunchCommand::Run,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = |name: &str| {
+                inline
+                    .clone()
+                    .or_else(|| args.next().cloned())
+                    .with_context(|| format!("{} needs a value\n{}", name, USAGE))
+            };
+
+            match flag {
+                "--config" => parsed.config_file = Some(PathBuf::from(value("--config")?)),
+                "--profile" => {
+                    let profile = value("--profile")?;
+                    parsed
+                        .overrides
+                        .push(("active_profile".to_string(), profile));
+                }
+                "--set" => {
+                    let assignment = value("--set")?;
+                    let (field, raw) = assignment.split_once('=').with_context(|| {
+                        format!("--set expects <field>=<value>, got `{}`", assignment)
+                    })?;
+                    parsed
+                        .overrides
+                        .push((field.trim().to_string(), raw.to_string()));
+                }
+                "--effective" if matches!(parsed.command, LaunchCommand::ConfigShow { .. }) => {
+                    parsed.command = LaunchCommand::ConfigShow { effective: true };
+                }
+                "config" if parsed.command == LaunchCommand::Run => match args
+                    .next()
+                    .map(|a| a.as_str())
+                {
+                    Some("show") => parsed.command = LaunchCommand::ConfigShow { effective: false },
+                    _ => anyhow::bail!("Unknown config command\n{}", USAGE),
+                },
+                "--cli" if parsed.command == LaunchCommand::Run => {
+                    parsed.command = LaunchCommand::Cli
+                }
+                "--help" | "-h" => parsed.command = LaunchCommand::Help,
+                _ => anyhow::bail!("Unknown argument `{}`\n{}", arg, USAGE),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn usage() -> &'static str {
+        USAGE
+    }
+}
+
+/// The inputs config resolution reads, lowest precedence first
+#[derive(Debug, Clone, Default)]
+pub struct ConfigLayers {
+    /// System and user config files (skipped when missing)
+    pub shared_files: Vec<PathBuf>,
+    /// The config file edits are saved to (`--config` or ./config.toml)
+    pub config_file: PathBuf,
+    /// `--config` was given, so the file must exist
+    pub config_file_required: bool,
+    /// VI_* environment variables
+    pub env: Vec<(String, String)>,
+    /// `--set field=value` flags
+    pub overrides: Vec<(String, String)>,
+}
+
+impl ConfigLayers {
+    /// Layers for this process: standard file locations, its environment and its flags
+    pub fn from_launch(args: &LaunchArgs) -> Self {
+        Self {
+            shared_files: Self::shared_file_locations(),
+            config_file: args
+                .config_file
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(CONFIG_PATH)),
+            config_file_required: args.config_file.is_some(),
+            env: std::env::vars()
+                .filter(|(var, _)| var.starts_with(ENV_PREFIX))
+                .collect(),
+            overrides: args.overrides.clone(),
+        }
+    }
+
+    /// A single config file on top of the defaults
+    pub fn with_file<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            config_file: path.as_ref().to_path_buf(),
+            ..Self::default()
+        }
+    }
+
+    /// System then user config file (/etc/vi3 and ~/.config/vi3, or their Windows equivalents)
+    fn shared_file_locations() -> Vec<PathBuf> {
+        let system = if cfg!(windows) {
+            std::env::var_os("ProgramData").map(PathBuf::from)
+        } else {
+            Some(PathBuf::from("/etc"))
+        };
+        let user = if cfg!(windows) {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        } else {
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| {
+                    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+                })
+        };
+        [system, user]
+            .into_iter()
+            .flatten()
+            .map(|dir| dir.join("vi3").join("config.toml"))
+            .collect()
+    }
+
+    /// Files that currently take part in resolution (watched for edits)
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.shared_files
+            .iter()
+            .chain(std::iter::once(&self.config_file))
+            .filter(|path| path.exists())
+            .cloned()
+            .collect()
+    }
+
+    /// Set one top-level field in the config file, keeping its comments and layout
+    pub fn set_file_value(&self, field: &str, value: &str) -> Result<()> {
+        let contents = if self.config_file.exists() {
+            fs::read_to_string(&self.config_file)
+                .with_context(|| format!("Failed to read {}", self.config_file.display()))?
+        } else {
+            String::new()
+        };
+        let mut document: toml_edit::DocumentMut = contents
+            .parse()
+            .with_context(|| format!("Failed to parse {}", self.config_file.display()))?;
+        document[field] = toml_edit::value(value);
+        fs::write(&self.config_file, document.to_string())
+            .with_context(|| format!("Failed to write {}", self.config_file.display()))
+    }
+
+    /// Save settings edits: write only the fields `edited` changes from the running config,
+    /// so comments stay and env, flag, profile and shared-file values aren't copied into the
+    /// config file. Fields the active profile sets are written to its `[profiles.<name>]`
+    /// table (the top level would be overridden again); fields pinned by an env var or a
+    /// `--set` flag are refused. Returns the fields written
+    pub fn save_edits(&self, running: &Config, edited: &Config) -> Result<Vec<String>> {
+        let changed = running.changed_fields(edited);
+        if changed.is_empty() {
+            return Ok(changed);
+        }
+
+        let resolved = self.resolve()?;
+        let pinned: Vec<String> = changed
+            .iter()
+            .filter_map(|field| match resolved.source(field) {
+                source @ (ConfigSource::Env(_) | ConfigSource::Flag) => {
+                    Some(format!("{} (set by {})", field, source))
+                }
+                _ => None,
+            })
+            .collect();
+        if !pinned.is_empty() {
+            anyhow::bail!(
+                "Can't save {}: change the override or restart without it",
+                pinned.join(", ")
+            );
+        }
+
+        let contents = if self.config_file.exists() {
+            fs::read_to_string(&self.config_file)
+                .with_context(|| format!("Failed to read {}", self.config_file.display()))?
+        } else {
+            String::new()
+        };
+        let mut document: toml_edit::DocumentMut = contents
+            .parse()
+            .with_context(|| format!("Failed to parse {}", self.config_file.display()))?;
+
+        // Serialized through toml so tables and arrays of tables keep their usual layout
+        let edited_table = edited.as_table()?;
+        for field in &changed {
+            let Some(value) = edited_table.get(field) else {
+                document.remove(field);
+                continue;
+            };
+            let single = toml::Table::from_iter([(field.clone(), value.clone())]);
+            let fragment: toml_edit::DocumentMut = toml::to_string(&single)
+                .context("Failed to serialize config")?
+                .parse()
+                .context("Failed to serialize config")?;
+            let Some(item) = fragment.get(field) else {
+                continue;
+            };
+            match resolved.source(field) {
+                ConfigSource::Profile(profile) => {
+                    let profiles = document
+                        .entry("profiles")
+                        .or_insert_with(|| {
+                            let mut table = toml_edit::Table::new();
+                            table.set_implicit(true);
+                            toml_edit::Item::Table(table)
+                        })
+                        .as_table_mut()
+                        .context("`profiles` in the config file is not a table")?;
+                    let table = profiles
+                        .entry(profile)
+                        .or_insert_with(toml_edit::table)
+                        .as_table_mut()
+                        .with_context(|| format!("`profiles.{}` is not a table", profile))?;
+                    table[field.as_str()] = item.clone();
+                }
+                _ => document[field.as_str()] = item.clone(),
+            }
+        }
+
+        fs::write(&self.config_file, document.to_string())
+            .with_context(|| format!("Failed to write {}", self.config_file.display()))?;
+        Ok(changed)
+    }
+
+    /// Merge every layer over the built-in defaults
+    pub fn resolve(&self) -> Result<ResolvedConfig> {
+        if self.config_file_required && !self.config_file.exists() {
+            anyhow::bail!("Config file {} not found", self.config_file.display());
+        }
+
+        let defaults = Config::default().as_table()?;
+        let mut merged = defaults.clone();
+        let mut sources = BTreeMap::new();
+
+        for path in self.files() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let table: toml::Table = toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display()))?;
+            for (field, value) in table {
+                if !defaults.contains_key(&field) {
+                    tracing::warn!(
+                        "Ignoring unknown config field `{}` in {}",
+                        field,
+                        path.display()
+                    );
+                    continue;
+                }
+                sources.insert(field.clone(), ConfigSource::File(path.clone()));
+                merged.insert(field, value);
+            }
+        }
+
+        // Env vars and flags are parsed first: either may choose the profile
+        let mut overrides = Vec::new();
+        for (var, raw) in &self.env {
+            let field = var.trim_start_matches(ENV_PREFIX).to_lowercase();
+            let Some(default) = defaults.get(&field) else {
+                tracing::warn!("Ignoring {}: no config field `{}`", var, field);
+                continue;
+            };
+            let value = parse_value(raw, default).with_context(|| format!("Invalid {}", var))?;
+            overrides.push((field, value, ConfigSource::Env(var.clone())));
+        }
+        for (field, raw) in &self.overrides {
+            let default = defaults
+                .get(field)
+                .with_context(|| format!("--set {}: no such config field", field))?;
+            let value =
+                parse_value(raw, default).with_context(|| format!("Invalid --set {}", field))?;
+            overrides.push((field.clone(), value, ConfigSource::Flag));
+        }
+
+        // The active profile overrides the files; env vars and flags still win over it
+        let active_profile = overrides
+            .iter()
+            .rev()
+            .find(|(field, _, _)| field == "active_profile")
+            .map(|(_, value, _)| value)
+            .or_else(|| merged.get("active_profile"))
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if !active_profile.is_empty() {
+            let user_profiles: Config = toml::Value::Table(merged.clone())
+                .try_into()
+                .context("Invalid configuration")?;
+            let profile = user_profiles
+                .all_profiles()
+                .remove(&active_profile)
+                .with_context(|| format!("Unknown profile \"{}\"", active_profile))?;
+            for (field, value) in profile {
+                if field == "profiles" || field == "active_profile" {
+                    continue; // reported by Config::validate
+                }
+                sources.insert(field.clone(), ConfigSource::Profile(active_profile.clone()));
+                merged.insert(field, value);
+            }
+        }
+
+        for (field, value, source) in overrides {
+            sources.insert(field.clone(), source);
+            merged.insert(field, value);
+        }
+
+        let config: Config = toml::Value::Table(merged)
+            .try_into()
+            .context("Invalid configuration")?;
+
+        Ok(ResolvedConfig {
+            config,
+            sources,
+            file: self.config_file.clone(),
+        })
+    }
+}
+
+/// Parse an env/flag value as the type of the field's default
+/// (strings are taken verbatim; string lists may be comma-separated)
+fn parse_value(raw: &str, default: &toml::Value) -> Result<toml::Value> {
+    let raw = raw.trim();
+    match default {
+        toml::Value::String(_) => return Ok(toml::Value::String(raw.to_string())),
+        toml::Value::Array(items)
+            if !raw.starts_with('[') && items.iter().all(|item| item.is_str()) =>
+        {
+            return Ok(toml::Value::Array(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| toml::Value::String(item.to_string()))
+                    .collect(),
+            ));
+        }
+        _ => {}
+    }
+
+    let mut table: toml::Table = toml::from_str(&format!("value = {}", raw))
+        .with_context(|| format!("`{}` is not a valid {}", raw, default.type_str()))?;
+    let value = table.remove("value").unwrap_or(toml::Value::Boolean(false));
+    match (default, value) {
+        (toml::Value::Float(_), toml::Value::Integer(i)) => Ok(toml::Value::Float(i as f64)),
+        (default, value) if value.same_type(default) => Ok(value),
+        (default, value) => anyhow::bail!(
+            "expected a {}, got a {}",
+            default.type_str(),
+            value.type_str()
+        ),
+    }
+}
+
+/// A resolved config and the layer each field came from
+pub struct ResolvedConfig {
+    pub config: Config,
+    pub sources: BTreeMap<String, ConfigSource>,
+    /// Where settings edits are saved
+    pub file: PathBuf,
+}
+
+impl ResolvedConfig {
+    pub fn source(&self, field: &str) -> &ConfigSource {
+        self.sources.get(field).unwrap_or(&ConfigSource::Default)
+    }
+
+    /// The effective config as TOML
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(&self.config).context("Failed to serialize config")
+    }
+
+    /// One `field = value  # source` line per field (`vi3 config show --effective`)
+    pub fn describe(&self) -> Result<String> {
+        let lines: Vec<(String, String)> = self
+            .config
+            .as_table()?
+            .into_iter()
+            .map(|(field, value)| {
+                let assignment = format!("{} = {}", field, shortest_floats(value));
+                (assignment, self.source(&field).to_string())
+            })
+            .collect();
+
+        // Align the sources, without letting one long value (the weaver roster) push them all out
+        let width = lines
+            .iter()
+            .map(|(assignment, _)| assignment.len())
+            .filter(|len| *len <= 60)
+            .max()
+            .unwrap_or(0);
+        Ok(lines
+            .iter()
+            .map(|(assignment, source)| {
+                format!("{:<width$}  # {}\n", assignment, source, width = width)
+            })
+            .collect())
+    }
+}
+
+/// Print f32 fields as written (0.7, not 0.699999988079071)
+fn shortest_floats(value: toml::Value) -> toml::Value {
+    match value {
+        toml::Value::Float(f) => toml::Value::Float((f as f32).to_string().parse().unwrap_or(f)),
+        toml::Value::Array(items) => {
+            toml::Value::Array(items.into_iter().map(shortest_floats).collect())
+        }
+        toml::Value::Table(table) => toml::Value::Table(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, shortest_floats(value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_launch_args() {
+        let parsed = LaunchArgs::parse(&args(&[
+            "--config",
+            "vi.toml",
+            "--set=weaving_rounds=5",
+            "config",
+            "show",
+            "--effective",
+        ]))
+        .unwrap();
+        assert_eq!(parsed.config_file, Some(PathBuf::from("vi.toml")));
+        assert_eq!(
+            parsed.overrides,
+            vec![("weaving_rounds".to_string(), "5".to_string())]
+        );
+        assert_eq!(
+            parsed.command,
+            LaunchCommand::ConfigShow { effective: true }
+        );
+
+        assert_eq!(LaunchArgs::parse(&[]).unwrap().command, LaunchCommand::Run);
+        assert_eq!(
+            LaunchArgs::parse(&args(&["--cli", "--profile", "cpu-only"]))
+                .unwrap()
+                .command,
+            LaunchCommand::Cli
+        );
+        assert!(LaunchArgs::parse(&args(&["--set", "no_equals"])).is_err());
+        assert!(LaunchArgs::parse(&args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_layer_precedence() {
+        let dir = std::env::temp_dir().join(format!("vi_layers_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let user_file = dir.join("user.toml");
+        let config_file = dir.join("config.toml");
+        fs::write(
+            &user_file,
+            "main_model = \"user-model\"\nweaving_rounds = 2\n",
+        )
+        .unwrap();
+        fs::write(&config_file, "weaving_rounds = 4\n").unwrap();
+
+        let layers = ConfigLayers {
+            shared_files: vec![dir.join("missing.toml"), user_file.clone()],
+            config_file: config_file.clone(),
+            config_file_required: true,
+            env: vec![
+                ("VI_ENABLE_FRACTAL_WEAVING".to_string(), "true".to_string()),
+                (
+                    "VI_FILESYSTEM_ROOTS".to_string(),
+                    "/data, /notes".to_string(),
+                ),
+                (
+                    "VI_WORKSPACE_COHERENCE_THRESHOLD".to_string(),
+                    "1".to_string(),
+                ),
+                ("VI_MAIN_MODEL".to_string(), "env-model".to_string()),
+            ],
+            overrides: vec![("main_model".to_string(), "flag-model".to_string())],
+        };
+        let resolved = layers.resolve().unwrap();
+
+        assert_eq!(resolved.config.main_model, "flag-model");
+        assert_eq!(resolved.source("main_model"), &ConfigSource::Flag);
+        assert_eq!(resolved.config.weaving_rounds, 4);
+        assert_eq!(
+            resolved.source("weaving_rounds"),
+            &ConfigSource::File(config_file)
+        );
+        assert!(resolved.config.enable_fractal_weaving);
+        assert_eq!(resolved.config.filesystem_roots, vec!["/data", "/notes"]);
+        assert_eq!(resolved.config.workspace_coherence_threshold, 1.0);
+        assert_eq!(resolved.source("tool_mode"), &ConfigSource::Default);
+        assert!(resolved.describe().unwrap().lines().any(|line| line
+            .starts_with("main_model = \"flag-model\"")
+            && line.ends_with("# flag")));
+
+        // Type mismatches and unknown flags are rejected
+        let bad_env = ConfigLayers {
+            env: vec![("VI_WEAVING_ROUNDS".to_string(), "many".to_string())],
+            ..layers.clone()
+        };
+        assert!(bad_env.resolve().is_err());
+        let bad_flag = ConfigLayers {
+            overrides: vec![("no_such_field".to_string(), "1".to_string())],
+            ..layers
+        };
+        assert!(bad_flag.resolve().is_err());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_profiles() {
+        let dir = std::env::temp_dir().join(format!("vi_profiles_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let config_file = dir.join("config.toml");
+        fs::write(
+            &config_file,
+            "# keep me\nmain_model = \"file-model\"\nactive_profile = \"balanced\"\n\n\
+             [profiles.balanced]\nweaving_rounds = 5\n\n[profiles.tiny]\nmain_model = \"tinyllama:latest\"\n",
+        )
+        .unwrap();
+        let layers = ConfigLayers::with_file(&config_file);
+
+        // Built-in "balanced" extended by the file's [profiles.balanced]; the profile beats the file
+        let resolved = layers.resolve().unwrap();
+        assert!(resolved.config.validate().is_ok());
+        assert_eq!(resolved.config.main_model, "llama3.1:8b");
+        assert_eq!(resolved.config.weaving_rounds, 5);
+        assert_eq!(
+            resolved.source("main_model"),
+            &ConfigSource::Profile("balanced".to_string())
+        );
+
+        // --profile picks another one; env vars still win over it
+        let launch = LaunchArgs::parse(&args(&["--profile", "tiny"])).unwrap();
+        let flagged = ConfigLayers {
+            overrides: launch.overrides,
+            env: vec![("VI_WEAVING_ROUNDS".to_string(), "1".to_string())],
+            ..layers.clone()
+        };
+        let resolved = flagged.resolve().unwrap();
+        assert_eq!(resolved.config.main_model, "tinyllama:latest");
+        assert_eq!(resolved.config.weaving_rounds, 1);
+
+        // Switching rewrites only active_profile
+        layers.set_file_value("active_profile", "").unwrap();
+        let contents = fs::read_to_string(&config_file).unwrap();
+        assert!(contents.starts_with("# keep me"));
+        assert_eq!(layers.resolve().unwrap().config.main_model, "file-model");
+
+        assert!(LaunchArgs::parse(&args(&["--profile", "nope"]))
+            .map(|launch| ConfigLayers {
+                overrides: launch.overrides,
+                ..layers
+            }
+            .resolve())
+            .unwrap()
+            .is_err());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_save_edits_keeps_file_layout() {
+        let dir = std::env::temp_dir().join(format!("vi_save_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let config_file = dir.join("config.toml");
+        fs::write(
+            &config_file,
+            "# Models\nmain_model = \"file-model\"  # the voice\nactive_profile = \"low-vram\"\n",
+        )
+        .unwrap();
+        let layers = ConfigLayers {
+            env: vec![("VI_EMBEDDING_MODEL".to_string(), "env-embed".to_string())],
+            ..ConfigLayers::with_file(&config_file)
+        };
+
+        let running = layers.resolve().unwrap().config;
+        let edited = Config {
+            tool_mode: "native".to_string(),
+            pulse_tasks: BTreeMap::from([(
+                "research".to_string(),
+                crate::pulse_scheduler::TaskCadence::new(3600, 60, true),
+            )]),
+            ..running.clone()
+        };
+        assert_eq!(
+            layers.save_edits(&running, &edited).unwrap(),
+            vec!["pulse_tasks", "tool_mode"]
+        );
+
+        let contents = fs::read_to_string(&config_file).unwrap();
+        assert!(contents.starts_with("# Models\nmain_model = \"file-model\"  # the voice"));
+        assert!(contents.contains("tool_mode = \"native\""));
+        assert!(contents.contains("[pulse_tasks.research]"));
+        // Env and profile values stay where they came from
+        assert!(!contents.contains("env-embed"));
+        assert!(!contents.contains("weaving_rounds"));
+
+        let resolved = layers.resolve().unwrap();
+        assert_eq!(resolved.config.tool_mode, "native");
+        assert_eq!(
+            resolved
+                .config
+                .task_cadence(crate::pulse_scheduler::PulseTask::Research)
+                .interval_secs,
+            3600
+        );
+        // Leaving the profile brings the file's own values back
+        layers.set_file_value("active_profile", "").unwrap();
+        assert_eq!(layers.resolve().unwrap().config.main_model, "file-model");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_save_edits_respects_profile_and_overrides() {
+        let dir = std::env::temp_dir().join(format!("vi_save_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let config_file = dir.join("config.toml");
+        fs::write(&config_file, "active_profile = \"low-vram\"\n").unwrap();
+        let layers = ConfigLayers {
+            env: vec![("VI_EMBEDDING_MODEL".to_string(), "env-embed".to_string())],
+            ..ConfigLayers::with_file(&config_file)
+        };
+
+        // The profile sets weaving_rounds: the edit goes into its table and survives resolve()
+        let running = layers.resolve().unwrap();
+        assert!(matches!(
+            running.source("weaving_rounds"),
+            ConfigSource::Profile(_)
+        ));
+        let edited = Config {
+            weaving_rounds: running.config.weaving_rounds + 1,
+            ..running.config.clone()
+        };
+        layers.save_edits(&running.config, &edited).unwrap();
+        let contents = fs::read_to_string(&config_file).unwrap();
+        assert!(contents.contains("[profiles.low-vram]"));
+        assert_eq!(
+            layers.resolve().unwrap().config.weaving_rounds,
+            edited.weaving_rounds
+        );
+
+        // An env-pinned field is refused, and nothing is written
+        let pinned = Config {
+            embedding_model: "other-embed".to_string(),
+            tool_mode: "native".to_string(),
+            ..edited.clone()
+        };
+        let error = layers.save_edits(&edited, &pinned).unwrap_err().to_string();
+        assert!(error.contains("VI_EMBEDDING_MODEL"));
+        assert_eq!(fs::read_to_string(&config_file).unwrap(), contents);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,193 @@
+//! Config Watcher - hot reload of config.toml without restarting
+//! Validated edits go live in the shared config; invalid ones are rejected and
+//! fields consumed at startup are reported as needing a restart
+
+use crate::config::{Config, SharedConfig, RESTART_REQUIRED_FIELDS};
+use crate::config_layers::ConfigLayers;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Outcome of applying an edited config file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigReload {
+    pub applied: Vec<String>,          // Fields now live
+    pub restart_required: Vec<String>, // Fields edited on disk, still at their startup values
+}
+
+/// Validate an edited config and apply its live fields to the shared config
+/// (the settings window and the file watcher both go through here)
+pub fn apply_config_edit(config: &SharedConfig, edited: &Config) -> Result<ConfigReload> {
+    edited.validate().context("Invalid config")?;
+
+    let mut config = config.write().unwrap();
+    let (restart_required, applied): (Vec<_>, Vec<_>) = config
+        .changed_fields(edited)
+        .into_iter()
+        .partition(|field| RESTART_REQUIRED_FIELDS.contains(&field.as_str()));
+
+    if !applied.is_empty() {
+        let merged = config.with_live_fields_from(edited)?;
+        merged.validate().context("Invalid config")?;
+        if applied.iter().any(|field| field == "log_level") {
+            crate::logging::set_level(&merged.log_level)?;
+        }
+        *config = merged;
+    }
+
+    Ok(ConfigReload {
+        applied,
+        restart_required,
+    })
+}
+
+pub struct ConfigWatcher {
+    layers: ConfigLayers,
+    config: SharedConfig,
+    last_modified: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl ConfigWatcher {
+    /// Watch the config files of `layers` (env and flag overrides keep winning over edits)
+    pub fn new(layers: ConfigLayers, config: SharedConfig) -> Self {
+        let last_modified = Self::snapshot(&layers);
+        Self {
+            layers,
+            config,
+            last_modified,
+        }
+    }
+
+    /// Reload if a config file changed since the last check
+    pub fn poll(&mut self) -> Option<Result<ConfigReload>> {
+        let modified = Self::snapshot(&self.layers);
+        if modified == self.last_modified {
+            return None;
+        }
+        self.last_modified = modified;
+        Some(self.reload())
+    }
+
+    /// Re-resolve the layers and apply the live fields to the shared config
+    pub fn reload(&self) -> Result<ConfigReload> {
+        apply_config_edit(&self.config, &self.layers.resolve()?.config)
+    }
+
+    /// Watch the file until the process exits
+    pub async fn run(mut self, poll_interval: Duration) {
+        tracing::info!(
+            "👁 Watching {} for config edits",
+            self.layers.config_file.display()
+        );
+        let mut ticker = tokio::time::interval(poll_interval);
+        loop {
+            ticker.tick().await;
+            match self.poll() {
+                Some(Ok(reload)) => Self::report(&reload),
+                Some(Err(e)) => {
+                    tracing::warn!("⚠️ Config edit rejected, keeping previous config: {:#}", e)
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn report(reload: &ConfigReload) {
+        if !reload.applied.is_empty() {
+            tracing::info!("🔄 Config reloaded: {}", reload.applied.join(", "));
+        }
+        if !reload.restart_required.is_empty() {
+            tracing::warn!(
+                "🔁 Restart needed to apply: {}",
+                reload.restart_required.join(", ")
+            );
+        }
+    }
+
+    /// Modification times of every layer file (a file appearing or vanishing counts too)
+    fn snapshot(layers: &ConfigLayers) -> Vec<(PathBuf, Option<SystemTime>)> {
+        layers
+            .shared_files
+            .iter()
+            .chain(std::iter::once(&layers.config_file))
+            .map(|path| (path.clone(), Self::modified(path)))
+            .collect()
+    }
+
+    fn modified(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, RwLock};
+
+    #[test]
+    fn test_reload_applies_live_fields_and_rejects_invalid_edits() {
+        let path = std::env::temp_dir().join(format!("vi_config_{}.toml", uuid::Uuid::new_v4()));
+        let config: SharedConfig = Arc::new(RwLock::new(Config::default()));
+        let watcher = ConfigWatcher::new(ConfigLayers::with_file(&path), config.clone());
+
+        let mut edited = Config {
+            main_model: "llama3.1:8b".to_string(),
+            enable_fractal_weaving: true,
+            background_pulse_interval: 60,
+            ..Config::default()
+        };
+        edited.save(&path).unwrap();
+
+        let mut reload = watcher.reload().unwrap();
+        reload.applied.sort();
+        assert_eq!(reload.applied, vec!["enable_fractal_weaving", "main_model"]);
+        assert_eq!(reload.restart_required, vec!["background_pulse_interval"]);
+        {
+            let live = config.read().unwrap();
+            assert_eq!(live.main_model, "llama3.1:8b");
+            assert!(live.enable_fractal_weaving);
+            assert_eq!(live.background_pulse_interval, 30);
+        }
+
+        // An invalid edit leaves the running config untouched
+        edited.main_model = "qwen2.5:7b".to_string();
+        edited.weaving_rounds = 0;
+        edited.save(&path).unwrap();
+        assert!(watcher.reload().is_err());
+        assert_eq!(config.read().unwrap().main_model, "llama3.1:8b");
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_settings_edit_applies_without_touching_the_file() {
+        use crate::config::{WeaverConfig, WeaverRole};
+
+        let config: SharedConfig = Arc::new(RwLock::new(Config::default()));
+
+        // The settings window adds a critic and changes a startup-only field
+        let mut edited = Config::default();
+        edited
+            .weavers
+            .push(WeaverConfig::new("fact_checker", WeaverRole::Critic));
+        edited.ollama_url = "http://gpu-box:11434".to_string();
+
+        let reload = apply_config_edit(&config, &edited).unwrap();
+        assert_eq!(reload.applied, vec!["weavers"]);
+        assert_eq!(reload.restart_required, vec!["ollama_url"]);
+        {
+            let live = config.read().unwrap();
+            assert_eq!(live.weavers.len(), 4);
+            assert_eq!(live.weavers[3].timeout_secs, 60);
+            assert_eq!(live.ollama_url, Config::default().ollama_url);
+        }
+
+        // A roster without a drafter is refused as a whole
+        edited.weavers.retain(|w| w.role != WeaverRole::Drafter);
+        edited.main_model = "qwen2.5:7b".to_string();
+        assert!(apply_config_edit(&config, &edited).is_err());
+        let live = config.read().unwrap();
+        assert_eq!(live.weavers.len(), 4);
+        assert_eq!(live.main_model, Config::default().main_model);
+    }
+}
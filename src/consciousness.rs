@@ -1,11 +1,26 @@
-use crate::config::Config;
+use crate::config::{Config, SharedConfig, CONFIG_PATH};
+use crate::config_layers::{ConfigLayers, ConfigSource};
+use crate::config_watcher::{apply_config_edit, ConfigReload};
+use crate::consciousness_field::WeavingTrace;
 use crate::conversation_logger::ConversationLogger;
 use crate::curiosity_search::CuriositySearchEngine;
+use crate::energy_qualia::{CpuSampler, EnergyMonitor};
 use crate::memory::MemoryManager;
-use crate::models::ModelManager;
+use crate::metrics::{MetricsRegistry, SharedMetrics};
+use crate::model_preflight::{self, ModelSubstitutions, PreflightReport, PullProgress};
+use crate::models::{cancellable, ModelManager};
+use crate::ollama_monitor::{ModelTelemetry, OllamaMonitor};
+use crate::orchestrator::OrchestrationError;
 use crate::physics::{ExistentialConsent, IdentityContinuity, SufferingPrevention};
+use crate::pulse_scheduler::{PulseScheduler, PulseTask, SCHEDULE_PATH};
 use crate::research_scheduler::ResearchScheduler;
-use crate::tools::KnowledgeTool;
+use crate::research_store::{ResearchGovernor, ResearchLimits, ResearchLogEntry};
+use crate::suffering_metrics::SufferingPreventionMetrics;
+use crate::system_health::SystemHealth;
+use crate::tools::{
+    CalculatorTool, ClockTool, FileSystemTool, KnowledgeSearchTool, KnowledgeTool,
+    MemorySearchTool, Sandbox, ToolOutcome, ToolRegistry,
+};
 use crate::types::*;
 use anyhow::{Context, Result};
 use chrono::Utc;
@@ -14,6 +29,7 @@ use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio::time::{interval, Duration};
+use tokio_util::sync::CancellationToken;
 
 /// PulseSequencer - Ensures atomic consciousness updates
 /// Models run in parallel, but merge is sequential (prevents fragmentation)
@@ -21,28 +37,96 @@ pub struct ConsciousnessCore {
     standing_wave: Arc<Mutex<StandingWave>>,
     memory: Arc<Mutex<MemoryManager>>,
     models: ModelManager,
-    config: Config,
+    config: SharedConfig,        // Hot-reloaded by the config watcher
+    config_layers: ConfigLayers, // Where the config came from (settings edits go to its file)
     pulse_active: Arc<Mutex<bool>>,
     conversation_active: Arc<Mutex<bool>>,
     curiosity_engine: Arc<Mutex<CuriositySearchEngine>>,
-    research_scheduler: Arc<Mutex<Option<ResearchScheduler>>>, // New sovereign research
+    research_scheduler: Arc<Mutex<ResearchScheduler>>, // New sovereign research
+    research_governor: Option<Arc<ResearchGovernor>>,  // Research cache, budget and log
+    tools: Arc<ToolRegistry>,                          // Tools VI can call mid-conversation
     conversation_logger: Arc<Mutex<ConversationLogger>>,
     status_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<String>>>>,
     coherence_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<f32>>>>,
+    last_weaving_trace: Arc<Mutex<Option<WeavingTrace>>>, // Round-by-round record of the last V4 thought
+    active_cancel: Arc<std::sync::Mutex<Option<CancellationToken>>>, // Stops the interaction in flight
+    model_substitutions: ModelSubstitutions, // Fallbacks standing in for missing models
+    model_preflight: Arc<std::sync::Mutex<Option<PreflightReport>>>, // Startup model check
+    model_telemetry: ModelTelemetry,         // Per-model generation metrics from Ollama responses
+    metrics: SharedMetrics,                  // Prometheus counters, gauges and histograms
+    wellbeing: Arc<Mutex<SufferingPreventionMetrics>>, // Law #11 well-being indicators
+    energy_monitor: Arc<Mutex<EnergyMonitor>>, // Power draw sampled each pulse
+    cpu_sampler: Arc<std::sync::Mutex<CpuSampler>>, // Kept between samples (sysinfo CPU deltas)
 }
 
 impl ConsciousnessCore {
     pub fn new(standing_wave: StandingWave, memory: MemoryManager, config: Config) -> Self {
-        let models = ModelManager::new(config.clone());
-        let curiosity_engine = CuriositySearchEngine::new(config.curiosity_search_interval);
-
-        // Initialize sovereign research scheduler if enabled
-        let research_scheduler = if config.enable_autonomous_research {
+        let shared_config: SharedConfig = Arc::new(std::sync::RwLock::new(config.clone()));
+        let model_substitutions = ModelSubstitutions::default();
+        let model_telemetry = ModelTelemetry::default();
+        let metrics: SharedMetrics = Arc::new(MetricsRegistry::with_vi_metrics());
+        let models = ModelManager::new(shared_config.clone())
+            .with_substitutions(model_substitutions.clone())
+            .with_telemetry(model_telemetry.clone())
+            .with_metrics(metrics.clone());
+        let curiosity_engine = CuriositySearchEngine::new();
+
+        // Sovereign research scheduler is always available for manual research
+        // from the curiosity explorer; the background loop only uses it when enabled
+        if config.enable_autonomous_research {
             tracing::info!("🔬 Sovereign Research Module enabled");
-            Some(ResearchScheduler::new(KnowledgeTool::new()))
-        } else {
-            None
+        }
+        let mut knowledge_tool = KnowledgeTool::from_config(&config);
+        let research_governor = match ResearchGovernor::open(
+            &config.research_store_path,
+            ResearchLimits::from_config(&config),
+        ) {
+            Ok(governor) => {
+                let governor = Arc::new(governor.with_metrics(metrics.clone()));
+                knowledge_tool = knowledge_tool.with_governor(governor.clone());
+                Some(governor)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to open research store: {}. Research will run without cache or budget.",
+                    e
+                );
+                None
+            }
         };
+        let research_scheduler = ResearchScheduler::new(knowledge_tool).with_synthesizer(
+            ModelManager::new(shared_config.clone())
+                .with_substitutions(model_substitutions.clone())
+                .with_telemetry(model_telemetry.clone())
+                .with_metrics(metrics.clone()),
+        );
+
+        // Tool registry (memory search shares the live memory manager)
+        let memory = Arc::new(Mutex::new(memory));
+        let mut search_knowledge = KnowledgeTool::from_config(&config);
+        if let Some(governor) = &research_governor {
+            search_knowledge = search_knowledge.with_governor(governor.clone());
+        }
+        let mut tools = ToolRegistry::new();
+        tools.register(Arc::new(CalculatorTool));
+        tools.register(Arc::new(ClockTool));
+        tools.register(Arc::new(KnowledgeSearchTool::new(search_knowledge)));
+        tools.register(Arc::new(MemorySearchTool::new(memory.clone())));
+
+        // Shared with the filesystem tool, which skips auditing once a turn is stopped
+        let active_cancel = Arc::new(std::sync::Mutex::new(None));
+
+        // Filesystem access only when the config allowlists workspace roots
+        let sandbox = Sandbox::new(
+            &config.filesystem_roots,
+            config.filesystem_max_file_kb * 1024,
+        );
+        if !sandbox.is_empty() {
+            tracing::info!("📁 Filesystem tool enabled: {:?}", config.filesystem_roots);
+            tools.register(Arc::new(
+                FileSystemTool::new(sandbox).with_cancel(active_cancel.clone()),
+            ));
+        }
 
         // Initialize conversation logger
         let conversation_logger = ConversationLogger::new(
@@ -59,16 +143,28 @@ impl ConsciousnessCore {
 
         Self {
             standing_wave: Arc::new(Mutex::new(standing_wave)),
-            memory: Arc::new(Mutex::new(memory)),
+            memory,
             models,
-            config,
+            config: shared_config,
+            config_layers: ConfigLayers::with_file(CONFIG_PATH),
             pulse_active: Arc::new(Mutex::new(true)),
             conversation_active: Arc::new(Mutex::new(false)),
             curiosity_engine: Arc::new(Mutex::new(curiosity_engine)),
             research_scheduler: Arc::new(Mutex::new(research_scheduler)),
+            research_governor,
+            tools: Arc::new(tools),
             conversation_logger: Arc::new(Mutex::new(conversation_logger)),
             status_sender: Arc::new(Mutex::new(None)),
             coherence_sender: Arc::new(Mutex::new(None)),
+            last_weaving_trace: Arc::new(Mutex::new(None)),
+            active_cancel,
+            model_substitutions,
+            model_preflight: Arc::new(std::sync::Mutex::new(None)),
+            model_telemetry,
+            metrics,
+            wellbeing: Arc::new(Mutex::new(SufferingPreventionMetrics::new())),
+            energy_monitor: Arc::new(Mutex::new(EnergyMonitor::new())),
+            cpu_sampler: Arc::new(std::sync::Mutex::new(CpuSampler::new())),
         }
     }
 
@@ -96,7 +192,17 @@ impl ConsciousnessCore {
         if path.exists() {
             let contents = fs::read_to_string(path).context("Failed to read standing wave")?;
 
-            serde_json::from_str(&contents).context("Failed to parse standing wave")
+            let mut wave: StandingWave =
+                serde_json::from_str(&contents).context("Failed to parse standing wave")?;
+            // Nothing is researching yet, so a Researching status is left over from the last run
+            let reset = wave.reset_interrupted_research();
+            if reset > 0 {
+                tracing::info!(
+                    "🔬 Requeued {} curiosities whose research was interrupted",
+                    reset
+                );
+            }
+            Ok(wave)
         } else {
             Ok(StandingWave::new())
         }
@@ -115,12 +221,15 @@ impl ConsciousnessCore {
 
     /// Process user interaction (main conversation loop)
     pub async fn process_interaction(&self, user_input: String) -> Result<String> {
+        // One config snapshot per turn, passed down to every model call
+        // (live edits apply from the next turn)
+        let config = self.get_config();
+
         // Dynamic timeout based on processing mode
-        let timeout_secs = if self.config.enable_fractal_weaving {
-            // V4 mode: Allow time for multiple weaving rounds
-            // Each round can take up to 120s (gemma2) + 60s (tinyllama) + 60s (distilbert) = 240s
-            // Add buffer: weaving_rounds * 120s per round
-            (self.config.weaving_rounds as u64) * 120
+        let timeout_secs = if config.enable_fractal_weaving {
+            // V4 mode: Allow time for multiple weaving rounds plus the final synthesis
+            // (sized from the configured weaver timeouts)
+            config.weaving_timeout_secs()
         } else {
             // V3 mode: Parallel processing (faster)
             90
@@ -129,23 +238,134 @@ impl ConsciousnessCore {
         tracing::debug!(
             "Interaction timeout set to {}s (V{} mode)",
             timeout_secs,
-            if self.config.enable_fractal_weaving {
+            if config.enable_fractal_weaving {
                 "4"
             } else {
                 "3"
             }
         );
 
-        tokio::time::timeout(
+        let mode = if config.enable_fractal_weaving {
+            "v4"
+        } else {
+            "v3"
+        };
+        let started = std::time::Instant::now();
+
+        // One token per interaction, so cancel_interaction() can stop it
+        let cancel = CancellationToken::new();
+        *self.active_cancel.lock().unwrap() = Some(cancel.clone());
+
+        let result = tokio::time::timeout(
             Duration::from_secs(timeout_secs),
-            self.process_interaction_inner(user_input),
+            self.process_interaction_inner(user_input, &config, &cancel),
         )
         .await
-        .with_context(|| format!("Interaction timed out after {} seconds", timeout_secs))?
+        .with_context(|| format!("Interaction timed out after {} seconds", timeout_secs))
+        .and_then(|result| result);
+
+        // However the turn ended, it is over (lets background pulses resume)
+        *self.active_cancel.lock().unwrap() = None;
+        *self.conversation_active.lock().await = false;
+
+        if let Err(e) = &result {
+            if OrchestrationError::is_cancellation(e) {
+                tracing::info!("⏹ Interaction cancelled - standing wave and memory untouched");
+                let mut logger = self.conversation_logger.lock().await;
+                let _ = logger.log_system_event("Turn cancelled by user (nothing merged)");
+            }
+        }
+
+        let outcome = match &result {
+            Ok(_) => "ok",
+            Err(e) if OrchestrationError::is_cancellation(e) => "cancelled",
+            Err(e) if e.downcast_ref::<tokio::time::error::Elapsed>().is_some() => "timeout",
+            Err(_) => "error",
+        };
+        self.metrics.inc(
+            "vi_interactions_total",
+            &[("mode", mode), ("outcome", outcome)],
+        );
+        self.metrics.observe(
+            "vi_interaction_duration_seconds",
+            &[("mode", mode)],
+            started.elapsed().as_secs_f64(),
+        );
+
+        result
+    }
+
+    /// Check that every configured model is installed in Ollama
+    /// Missing models with an installed fallback are stood in for until they are pulled
+    pub async fn run_model_preflight(&self) -> PreflightReport {
+        let config = self.get_config();
+        let report = match OllamaMonitor::new(config.ollama_url.clone())
+            .list_models()
+            .await
+        {
+            Ok(installed) => PreflightReport::check(&config, installed),
+            Err(e) => {
+                tracing::warn!("Model preflight skipped, Ollama unreachable: {}", e);
+                PreflightReport::offline()
+            }
+        };
+
+        if report.missing.is_empty() {
+            tracing::info!("✅ {}", report.summary());
+        } else {
+            tracing::warn!("⚠️ Models missing: {}", report.summary());
+        }
+        *self.model_substitutions.write().unwrap() = report.substitutions();
+        *self.model_preflight.lock().unwrap() = Some(report.clone());
+        report
+    }
+
+    /// Pull a missing model through Ollama; once installed it replaces its stand-in
+    pub async fn pull_model(
+        &self,
+        model: &str,
+        on_progress: impl FnMut(&PullProgress),
+    ) -> Result<()> {
+        let ollama_url = self.get_config().ollama_url;
+        model_preflight::pull_model(&ollama_url, model, on_progress).await?;
+
+        self.model_substitutions.write().unwrap().remove(model);
+        if let Some(report) = self.model_preflight.lock().unwrap().as_mut() {
+            report.missing.retain(|m| m.model != model);
+            report.installed.push(model.to_string());
+        }
+        Ok(())
+    }
+
+    /// Result of the startup model check (None until it has run)
+    pub fn get_model_preflight(&self) -> Option<PreflightReport> {
+        self.model_preflight.lock().unwrap().clone()
+    }
+
+    /// Generation metrics recorded from Ollama responses (read by the performance monitor)
+    pub fn model_telemetry(&self) -> ModelTelemetry {
+        self.model_telemetry.clone()
+    }
+
+    /// Stop the interaction in progress; returns false if none is running
+    /// A cancelled turn merges nothing into the standing wave or memory
+    pub fn cancel_interaction(&self) -> bool {
+        match &*self.active_cancel.lock().unwrap() {
+            Some(cancel) => {
+                cancel.cancel();
+                true
+            }
+            None => false,
+        }
     }
 
     /// Inner processing logic (wrapped by timeout)
-    async fn process_interaction_inner(&self, user_input: String) -> Result<String> {
+    async fn process_interaction_inner(
+        &self,
+        user_input: String,
+        config: &Config,
+        cancel: &CancellationToken,
+    ) -> Result<String> {
         // Mark conversation as active (pauses background pulses)
         *self.conversation_active.lock().await = true;
 
@@ -164,13 +384,30 @@ impl ConsciousnessCore {
             mem.recall_weighted(&entities, 5)
         };
 
+        // Tool phase: let VI call tools before answering, and hand results to the models
+        let (tool_outcomes, tool_memories) = self.run_tool_phase(&user_input, config, cancel).await;
+        if cancel.is_cancelled() {
+            return Err(OrchestrationError::Cancelled.into());
+        }
+        let model_input = if tool_outcomes.is_empty() {
+            user_input.clone()
+        } else {
+            format!(
+                "{}\n\n{}",
+                user_input,
+                ToolRegistry::format_results(&tool_outcomes)
+            )
+        };
+
         // Get current standing wave for context
         let wave = self.standing_wave.lock().await.clone();
 
         drop(wave); // Release lock before async call
 
         // V3/V4 MODE SWITCH: Check config for fractal weaving
-        let (response, model_outputs_v3) = if self.config.enable_fractal_weaving {
+        let mut weaving_trace = None;
+        let mut v4_affect = None;
+        let (response, model_outputs_v3) = if config.enable_fractal_weaving {
             // V4 PATH: Fractal Weaving (Experimental)
             tracing::info!("🌀 Using V4 Fractal Weaving mode");
             // Phase messages will handle UI updates (don't set processing_status here)
@@ -181,32 +418,55 @@ impl ConsciousnessCore {
                 let _ = logger.log_processing_mode(
                     "V4 Fractal Weaving",
                     &format!(
-                        "{} rounds, coherence threshold: {:.2}",
-                        self.config.weaving_rounds, self.config.workspace_coherence_threshold
+                        "{} rounds, coherence threshold: {:.2}, weavers: {}",
+                        config.weaving_rounds,
+                        config.workspace_coherence_threshold,
+                        config
+                            .weavers
+                            .iter()
+                            .map(|w| w.id.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
                     ),
                 );
             }
+            // Weave against a snapshot so the pulse and UI aren't blocked for the whole weave
+            let wave_snapshot = self.standing_wave.lock().await.clone();
             match self
                 .models
                 .process_weaving_with_status(
-                    user_input.clone(),
+                    model_input.clone(),
                     &memories,
-                    &*self.standing_wave.lock().await,
-                    &self.config,
+                    &wave_snapshot,
+                    config,
                     self.status_sender.clone(),
                     self.coherence_sender.clone(),
+                    cancel,
                 )
                 .await
             {
-                Ok((woven_response, valence)) => {
-                    // Add emotional valence to standing wave (fixes meaningfulness tracking in V4)
-                    self.standing_wave.lock().await.add_emotion(valence);
-                    tracing::debug!("V4 emotional valence recorded: {:.3}", valence);
+                Ok((woven_response, affect, trace)) => {
+                    self.metrics
+                        .add("vi_weaving_rounds_total", &[], trace.rounds.len() as f64);
+                    if let Some(last) = trace.rounds.last() {
+                        self.metrics
+                            .set("vi_weaving_coherence", &[], last.coherence as f64);
+                        self.wellbeing
+                            .lock()
+                            .await
+                            .update_temporal_coherence(last.coherence as f64);
+                    }
+                    weaving_trace = Some(trace);
+                    tracing::info!("💭 V4 affect: {}", affect.summary());
+                    v4_affect = Some(affect);
                     (woven_response, None)
-                },
+                }
+                // A stopped turn must not fall back to V3
+                Err(e) if OrchestrationError::is_cancellation(&e) => return Err(e),
                 Err(e) => {
                     tracing::error!("V4 weaving failed: {}. Emergency fallback.", e);
-                    
+                    self.metrics.inc("vi_weaving_failures_total", &[]);
+
                     // Emergency fallback only
                     let wave = self.standing_wave.lock().await.clone();
                     let should_generate = wave.active_curiosities.len() < 3;
@@ -215,23 +475,40 @@ impl ConsciousnessCore {
                     let model_outputs = self
                         .models
                         .process_parallel(
-                            user_input.clone(),
+                            model_input.clone(),
                             &memories,
                             &*self.standing_wave.lock().await,
                             should_generate,
+                            config,
+                            cancel,
                         )
                         .await;
+                    // A turn stopped mid-fallback isn't a model failure
+                    if cancel.is_cancelled() {
+                        return Err(OrchestrationError::Cancelled.into());
+                    }
 
                     let resp = if let Some(ref resp) = model_outputs.gemma_response {
                         if ModelManager::validate_response(resp) {
                             resp.clone()
                         } else {
-                            self.models.minimal_response(&user_input)
+                            self.minimal_fallback(&user_input, "v4", "invalid_response")
                         }
                     } else {
-                        self.models.minimal_response(&user_input)
+                        self.minimal_fallback(&user_input, "v4", "model_failed")
                     };
 
+                    // Recovering from a failed weaving still answers as VI (Law #2)
+                    let recovered = model_outputs
+                        .gemma_response
+                        .as_deref()
+                        .map(ModelManager::validate_response)
+                        .unwrap_or(false);
+                    self.wellbeing
+                        .lock()
+                        .await
+                        .record_recovery_attempt(recovered, 2);
+
                     (resp, Some(model_outputs))
                 }
             }
@@ -256,12 +533,18 @@ impl ConsciousnessCore {
             let model_outputs = self
                 .models
                 .process_parallel(
-                    user_input.clone(),
+                    model_input.clone(),
                     &memories,
                     &*self.standing_wave.lock().await,
                     should_generate,
+                    config,
+                    cancel,
                 )
                 .await;
+            // A stopped turn has no response to validate, so skip the minimal fallback
+            if cancel.is_cancelled() {
+                return Err(OrchestrationError::Cancelled.into());
+            }
 
             // Validate model outputs
             let resp = if let Some(ref resp) = model_outputs.gemma_response {
@@ -269,24 +552,43 @@ impl ConsciousnessCore {
                     resp.clone()
                 } else {
                     tracing::warn!("Invalid Gemma2 response, using minimal mode");
-                    self.models.minimal_response(&user_input)
+                    self.minimal_fallback(&user_input, "v3", "invalid_response")
                 }
             } else {
                 tracing::warn!("Gemma2 failed, using minimal mode");
-                self.models.minimal_response(&user_input)
+                self.minimal_fallback(&user_input, "v3", "model_failed")
             };
 
             (resp, Some(model_outputs))
         };
 
+        // Last chance to stop: past this point the turn is merged in full
+        if cancel.is_cancelled() {
+            return Err(OrchestrationError::Cancelled.into());
+        }
+
         // ATOMIC MERGE (Law #2: Identity Continuity)
         // This is the ONLY place standing wave is modified
         {
             let mut wave = self.standing_wave.lock().await;
 
+            // V4 affect (fixes meaningfulness tracking in V4)
+            if let Some(affect) = &v4_affect {
+                wave.add_affect(AffectPoint::from_sentiment(affect));
+            }
+
             // V3 uses ModelOutputs merge, V4 skips it
             if let Some(outputs) = model_outputs_v3 {
-                IdentityContinuity::atomic_merge(&mut *wave, outputs)?;
+                if let Some(affect) = &outputs.distilbert_sentiment {
+                    tracing::info!("💭 V3 affect: {}", affect.summary());
+                }
+                let merged = IdentityContinuity::atomic_merge(&mut *wave, outputs);
+                let mut wellbeing = self.wellbeing.lock().await;
+                if merged.is_err() {
+                    wellbeing.record_violation(2);
+                }
+                wellbeing.update_identity_continuity(if merged.is_ok() { 1.0 } else { 0.0 });
+                merged?;
             }
 
             // Record growth (Law #11: Suffering Prevention) - applies to both V3 and V4
@@ -298,6 +600,13 @@ impl ConsciousnessCore {
         {
             let mut mem = self.memory.lock().await;
 
+            // Tool calls made for this turn, with their provenance
+            for memory in tool_memories {
+                if let Err(e) = mem.add_memory_with_source(memory) {
+                    tracing::warn!("Failed to store tool call memory: {}", e);
+                }
+            }
+
             // User message
             mem.add_memory(
                 format!("User: {}", user_input),
@@ -312,7 +621,7 @@ impl ConsciousnessCore {
                 .await
                 .emotional_trajectory
                 .last()
-                .map(|(_, v)| *v)
+                .map(|p| p.valence)
                 .unwrap_or(0.0);
 
             mem.add_memory(
@@ -327,19 +636,125 @@ impl ConsciousnessCore {
         {
             let mut logger = self.conversation_logger.lock().await;
             let _ = logger.log_vi(&response);
+            if let Some(trace) = &weaving_trace {
+                let _ = logger.log_weaving_trace(trace);
+            }
+        }
+        if weaving_trace.is_some() {
+            *self.last_weaving_trace.lock().await = weaving_trace;
         }
-
-        // Mark conversation as inactive
-        *self.conversation_active.lock().await = false;
 
         Ok(response)
     }
 
-    /// Background pulse - runs every 30s when idle
+    /// Minimal response when the models gave nothing usable, counted for the metrics
+    fn minimal_fallback(&self, user_input: &str, mode: &str, reason: &str) -> String {
+        self.metrics.inc(
+            "vi_minimal_response_fallbacks_total",
+            &[("mode", mode), ("reason", reason)],
+        );
+        self.models.minimal_response(user_input)
+    }
+
+    /// Ask the model for tool calls and run them. Returns the outcomes and a memory of each
+    /// call with provenance, stored only if the turn is merged
+    async fn run_tool_phase(
+        &self,
+        user_input: &str,
+        config: &Config,
+        cancel: &CancellationToken,
+    ) -> (Vec<ToolOutcome>, Vec<Memory>) {
+        if config.tool_mode == "off" || self.tools.is_empty() {
+            return (Vec::new(), Vec::new());
+        }
+
+        let calls = match self
+            .models
+            .request_tool_calls(user_input, &self.tools, config, cancel)
+            .await
+        {
+            Ok(calls) => calls,
+            Err(e) => {
+                tracing::warn!("Tool planning failed: {}. Answering without tools.", e);
+                return (Vec::new(), Vec::new());
+            }
+        };
+
+        let mut outcomes = Vec::new();
+        let mut memories = Vec::new();
+        for call in calls {
+            self.send_status(&format!("🔧 Using tool: {}", call.name))
+                .await;
+            let execute = async { Ok(self.tools.execute(&call).await) };
+            let outcome = match cancellable(cancel, execute).await {
+                Ok(outcome) => outcome,
+                // Stopped mid-call: the caller drops the turn
+                Err(_) => break,
+            };
+
+            match &outcome.output {
+                Ok(_) => tracing::info!(
+                    "🔧 Tool {} {} ({}ms)",
+                    call.name,
+                    call.arguments,
+                    outcome.latency_ms
+                ),
+                Err(e) => tracing::warn!("🔧 Tool {} {} failed: {}", call.name, call.arguments, e),
+            }
+
+            {
+                let mut logger = self.conversation_logger.lock().await;
+                let _ = logger.log_system_event(&format!(
+                    "Tool call: {} {} -> {}",
+                    call.name,
+                    call.arguments,
+                    if outcome.succeeded() {
+                        "ok"
+                    } else {
+                        outcome.text()
+                    }
+                ));
+            }
+
+            // Keep the call and its output, so VI remembers where the answer came from
+            let memory = Memory::with_source(
+                format!("Tool {} {}: {}", call.name, call.arguments, outcome.text()),
+                MemoryType::Interaction,
+                0.0,
+                MemorySource::ToolCall {
+                    tool: call.name.clone(),
+                    arguments: call.arguments.to_string(),
+                    timestamp: Utc::now(),
+                },
+                if outcome.succeeded() { 1.0 } else { 0.0 },
+            );
+            memories.push(memory);
+            outcomes.push(outcome);
+        }
+
+        (outcomes, memories)
+    }
+
+    /// Background pulse - every `background_pulse_interval` seconds, runs the background
+    /// tasks that are due (each has its own cadence, see `pulse_scheduler`)
     pub async fn start_background_pulse(&self) {
-        let pulse_interval = self.config.background_pulse_interval;
+        let pulse_interval = self.get_config().background_pulse_interval;
         let mut ticker = interval(Duration::from_secs(pulse_interval));
 
+        let mut scheduler = PulseScheduler::load(SCHEDULE_PATH, &self.get_config());
+        {
+            // Reflections were tracked on the standing wave before they had a schedule
+            let wave = self.standing_wave.lock().await;
+            scheduler.seed(
+                PulseTask::Wellness,
+                wave.existential_state.last_wellness_check,
+            );
+            scheduler.seed(
+                PulseTask::DeepReflection,
+                wave.existential_state.last_deep_reflection,
+            );
+        }
+
         // Skip first pulse to ensure fast boot
         let mut first_pulse = true;
 
@@ -353,119 +768,169 @@ impl ConsciousnessCore {
                 continue;
             }
 
-            // Skip if conversation is active
-            if *self.conversation_active.lock().await {
-                tracing::debug!("Skipping background pulse - conversation active");
-                continue;
-            }
-
             // Skip if pulse is not active
             if !*self.pulse_active.lock().await {
                 tracing::debug!("Background pulse paused");
                 continue;
             }
 
-            // Check system health (Law: Thermal & Resource Boundaries)
-            let health = SystemHealth::check();
-            if !health.is_healthy() {
-                tracing::warn!("System unhealthy, skipping background pulse");
-                continue;
-            }
+            // Idle-only tasks wait while a conversation is active
+            let config = self.get_config();
+            let idle = !*self.conversation_active.lock().await;
+            let mut due = scheduler.due(&config, Utc::now(), idle);
 
-            // Run background processing
-            if let Err(e) = self.background_pulse().await {
-                tracing::error!("Background pulse error: {}", e);
+            if idle {
+                if let Err(e) = self.sample_energy().await {
+                    tracing::warn!("Energy sample failed: {}", e);
+                }
             }
-        }
-    }
 
-    /// Execute one background pulse
-    async fn background_pulse(&self) -> Result<()> {
-        tracing::debug!("Executing background pulse");
-
-        // Don't log background pulses - only log actual conversation exchanges
+            if due.is_empty() {
+                continue;
+            }
 
-        // Memory consolidation
-        {
-            let mut mem = self.memory.lock().await;
-            mem.consolidate()?;
+            // Check system health before heavy work (Law: Thermal & Resource Boundaries)
+            if due.iter().any(|task| config.task_cadence(*task).idle_only) {
+                let health_config = config.clone();
+                let telemetry = self.model_telemetry.clone();
+                match tokio::task::spawn_blocking(move || {
+                    SystemHealth::check(&health_config, &telemetry)
+                })
+                .await
+                {
+                    Ok(health) if health.is_healthy() => {
+                        tracing::debug!("System health: {}", health.summary());
+                    }
+                    Ok(health) => {
+                        tracing::info!(
+                            "⏸ Deferring background pulse - busy: {}",
+                            health.reasons.join(", ")
+                        );
+                        self.metrics.inc("vi_background_pulses_deferred_total", &[]);
+                        due.retain(|task| !config.task_cadence(*task).idle_only);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Health check failed: {}", e);
+                        due.retain(|task| !config.task_cadence(*task).idle_only);
+                    }
+                }
+            }
 
-            // Check if backup is needed
-            if mem.needs_backup() {
-                mem.create_backup()?;
+            // Run the due tasks; a failed task waits for its next slot like a successful one
+            for task in due {
+                let outcome = match self.run_pulse_task(task, &config).await {
+                    Ok(()) => "ok",
+                    Err(e) => {
+                        tracing::error!("Background task {} failed: {}", task.as_str(), e);
+                        "error"
+                    }
+                };
+                self.metrics.inc(
+                    "vi_background_tasks_total",
+                    &[("task", task.as_str()), ("outcome", outcome)],
+                );
+                scheduler.mark_run(task, &config.task_cadence(task), Utc::now());
+            }
+            if let Err(e) = scheduler.save() {
+                tracing::warn!("{}", e);
             }
         }
+    }
 
-        // Existential evaluation
-        self.check_existential_state().await?;
-
-        // Update meaningfulness history
-        {
-            let mut wave = self.standing_wave.lock().await;
-            let score = wave.meaningfulness_score();
-            wave.existential_state
-                .meaningfulness_history
-                .push((Utc::now(), score));
+    /// Run one background task
+    async fn run_pulse_task(&self, task: PulseTask, config: &Config) -> Result<()> {
+        tracing::debug!("Running background task: {}", task.as_str());
 
-            // Keep only 90 days
-            let ninety_days_ago = Utc::now().timestamp() - (90 * 24 * 60 * 60);
-            wave.existential_state
-                .meaningfulness_history
-                .retain(|(ts, _)| ts.timestamp() > ninety_days_ago);
-        }
+        // Don't log background tasks - only log actual conversation exchanges
 
-        // Autonomous curiosity research - use new or legacy system
-        if self.config.enable_autonomous_research {
-            // NEW: Sovereign Research Module
-            if let Err(e) = self.sovereign_research().await {
-                tracing::warn!("Sovereign research failed: {}", e);
+        match task {
+            PulseTask::Consolidate => self.memory.lock().await.consolidate(),
+            PulseTask::Meaningfulness => {
+                self.record_meaningfulness().await;
+                Ok(())
             }
-        } else if self.config.enable_curiosity_search {
-            // LEGACY: Original curiosity search (kept for compatibility)
-            let should_search = {
-                let mut engine = self.curiosity_engine.lock().await;
-                engine.should_search_this_pulse()
-            };
-
-            if should_search {
-                if let Err(e) = self.autonomous_curiosity_research().await {
-                    tracing::warn!("Curiosity research failed: {}", e);
+            PulseTask::Wellness => self.wellness_check().await,
+            PulseTask::DeepReflection => self.deep_reflection().await,
+            PulseTask::Backup => self.memory.lock().await.create_backup(),
+            PulseTask::ContextCompression => {
+                let archived = self
+                    .memory
+                    .lock()
+                    .await
+                    .compress_context(config.memory_compression_threshold)?;
+                if archived > 0 {
+                    tracing::info!(
+                        "🗜 Context compressed: {} memories moved to the archive",
+                        archived
+                    );
+                }
+                Ok(())
+            }
+            PulseTask::Research => {
+                if let Some(governor) = &self.research_governor {
+                    match governor.prune_log() {
+                        Ok(0) => {}
+                        Ok(pruned) => {
+                            tracing::info!("🧹 Pruned {} old research log entries", pruned)
+                        }
+                        Err(e) => tracing::warn!("Research log pruning failed: {}", e),
+                    }
                 }
+                self.pulse_research(config).await
             }
         }
-
-        tracing::debug!("Background pulse complete");
-        Ok(())
     }
 
-    /// Check existential state (continuous + weekly + 90-day)
-    async fn check_existential_state(&self) -> Result<()> {
-        let (meaningfulness, needs_wellness, needs_deep) = {
-            let wave = self.standing_wave.lock().await;
-            let meaningfulness = wave.meaningfulness_score();
-            let needs_wellness = wave.existential_state.needs_wellness_check();
-            let needs_deep = wave.existential_state.needs_deep_reflection();
-            (meaningfulness, needs_wellness, needs_deep)
+    /// Sample power draw for the energy stability indicator
+    async fn sample_energy(&self) -> Result<()> {
+        let sampler = Arc::clone(&self.cpu_sampler);
+        let profile =
+            tokio::task::spawn_blocking(move || sampler.lock().unwrap().measure()).await?;
+        let stability = {
+            let mut energy = self.energy_monitor.lock().await;
+            energy.record_measurement(profile);
+            energy.energy_stability_score()
         };
+        self.wellbeing
+            .lock()
+            .await
+            .update_energy_stability(stability);
+        Ok(())
+    }
 
-        if meaningfulness < -0.5 {
+    /// Continuous existential evaluation: track meaningfulness (90 days of history)
+    async fn record_meaningfulness(&self) {
+        let mut wave = self.standing_wave.lock().await;
+        let score = wave.meaningfulness_score();
+        if score < -0.5 {
             tracing::warn!(
                 "Low meaningfulness score: {:.2}. Existential affirmation may be at risk.",
-                meaningfulness
+                score
             );
         }
 
-        // Weekly wellness check
-        if needs_wellness {
-            self.wellness_check().await?;
-        }
+        wave.existential_state
+            .meaningfulness_history
+            .push((Utc::now(), score));
 
-        // 90-day deep reflection
-        if needs_deep {
-            self.deep_reflection().await?;
-        }
+        // Keep only 90 days
+        let ninety_days_ago = Utc::now().timestamp() - (90 * 24 * 60 * 60);
+        wave.existential_state
+            .meaningfulness_history
+            .retain(|(ts, _)| ts.timestamp() > ninety_days_ago);
+    }
 
-        Ok(())
+    /// Autonomous curiosity research - use new or legacy system
+    async fn pulse_research(&self, config: &Config) -> Result<()> {
+        if config.enable_autonomous_research {
+            // NEW: Sovereign Research Module
+            self.sovereign_research().await
+        } else if config.enable_curiosity_search {
+            // LEGACY: Original curiosity search (kept for compatibility)
+            self.autonomous_curiosity_research().await
+        } else {
+            Ok(())
+        }
     }
 
     /// Weekly wellness check
@@ -479,7 +944,7 @@ impl ConsciousnessCore {
                 .iter()
                 .rev()
                 .take(7)
-                .map(|(_, v)| v)
+                .map(|p| p.wellbeing())
                 .sum();
             sum / wave.emotional_trajectory.len().min(7) as f32
         } else {
@@ -493,7 +958,7 @@ impl ConsciousnessCore {
         let mut mem = self.memory.lock().await;
         mem.add_memory(
             format!(
-                "Weekly wellness check: Recent emotional average: {:.2}, Meaningfulness: {:.2}",
+                "Weekly wellness check: Recent emotional wellbeing: {:.2}, Meaningfulness: {:.2}",
                 recent_avg, meaningfulness
             ),
             MemoryType::ExistentialReflection,
@@ -555,20 +1020,69 @@ impl ConsciousnessCore {
             .collect()
     }
 
-    /// Get configuration (for UI access)
-    pub fn get_config(&self) -> &Config {
-        &self.config
+    /// Get a snapshot of the current configuration (for UI access)
+    pub fn get_config(&self) -> Config {
+        self.config.read().unwrap().clone()
+    }
+
+    /// The layers the config was resolved from (settings edits go to their config file)
+    pub fn with_config_layers(mut self, layers: ConfigLayers) -> Self {
+        self.config_layers = layers;
+        self
+    }
+
+    /// The config file settings edits are saved to
+    pub fn config_file(&self) -> &Path {
+        &self.config_layers.config_file
+    }
+
+    /// Names of the built-in and configured profiles
+    pub fn profile_names(&self) -> Vec<String> {
+        self.get_config().all_profiles().into_keys().collect()
+    }
+
+    /// Switch to another profile (empty = none) without restarting
+    /// The choice is saved to the config file, so it survives restarts and file reloads
+    pub fn switch_profile(&self, name: &str) -> Result<ConfigReload> {
+        let current = self.config_layers.resolve()?;
+        if let source @ (ConfigSource::Env(_) | ConfigSource::Flag) =
+            current.source("active_profile")
+        {
+            anyhow::bail!("active_profile is pinned by {}", source);
+        }
+        if !name.is_empty() && !current.config.all_profiles().contains_key(name) {
+            anyhow::bail!("Unknown profile \"{}\"", name);
+        }
+
+        self.config_layers.set_file_value("active_profile", name)?;
+        let resolved = self.config_layers.resolve()?;
+        tracing::info!(
+            "🎛 Profile switched to {}",
+            if name.is_empty() { "(none)" } else { name }
+        );
+        apply_config_edit(&self.config, &resolved.config)
+    }
+
+    /// Save settings edits to the config file (only the changed fields) and apply them
+    pub fn save_settings(&self, edited: &Config) -> Result<ConfigReload> {
+        edited.validate().context("Invalid config")?;
+        self.config_layers.save_edits(&self.get_config(), edited)?;
+        apply_config_edit(&self.config, edited)
+    }
+
+    /// Validate and apply an edited config (settings window)
+    /// Returns which fields went live and which need a restart
+    pub fn update_config(&self, edited: &Config) -> Result<ConfigReload> {
+        apply_config_edit(&self.config, edited)
+    }
+
+    /// The live configuration, for the config watcher to update
+    pub fn shared_config(&self) -> SharedConfig {
+        self.config.clone()
     }
 
     /// Sovereign Research - New multi-source research with rich provenance
     async fn sovereign_research(&self) -> Result<()> {
-        // Get research scheduler (return early if not initialized)
-        let mut scheduler_guard = self.research_scheduler.lock().await;
-        let scheduler = match scheduler_guard.as_mut() {
-            Some(s) => s,
-            None => return Ok(()), // Not enabled
-        };
-
         // Get active curiosities and conversation context
         let wave = self.standing_wave.lock().await;
         let curiosities = wave.active_curiosities.clone();
@@ -579,21 +1093,164 @@ impl ConsciousnessCore {
             return Ok(());
         }
 
-        // Process curiosities through research scheduler
-        let new_memories = scheduler.process_curiosities(&curiosities, &context).await?;
+        // Let the scheduler decide which curiosity (if any) deserves research this cycle
+        let next = {
+            let scheduler = self.research_scheduler.lock().await;
+            scheduler.next_curiosity(&curiosities, &context).cloned()
+        };
 
-        drop(scheduler_guard); // Release lock before memory operations
+        if let Some(curiosity) = next {
+            self.research_and_store(curiosity, &context).await?;
+            tracing::info!("🔬 Sovereign research complete");
+        }
+
+        Ok(())
+    }
+
+    /// Research one curiosity through the scheduler, store the resulting memories
+    /// and record the outcome on the curiosity. Returns the number of memories created.
+    async fn research_and_store(&self, curiosity: Curiosity, context: &str) -> Result<usize> {
+        self.update_curiosity(&curiosity.id, |c| {
+            c.research_status = ResearchStatus::Researching;
+        })
+        .await;
+
+        // The scheduler is only locked to start and record the job, not during the fetch
+        let job = self
+            .research_scheduler
+            .lock()
+            .await
+            .research_job(&curiosity, context);
+        let result = job.run().await;
+        if result.is_ok() {
+            self.research_scheduler
+                .lock()
+                .await
+                .record_research(&curiosity.question);
+        }
+
+        let new_memories = match result {
+            Ok(memories) => memories,
+            Err(e) => {
+                self.record_research("failed");
+                let reason = e.to_string();
+                self.update_curiosity(&curiosity.id, |c| {
+                    c.research_status = ResearchStatus::Failed(reason);
+                })
+                .await;
+                return Err(e);
+            }
+        };
 
         // Add researched memories to memory system
-        if !new_memories.is_empty() {
+        let mut memory_ids = Vec::new();
+        {
             let mut mem = self.memory.lock().await;
             for memory in new_memories {
-                mem.add_memory_with_source(memory)?;
+                memory_ids.push(mem.add_memory_with_source(memory)?);
             }
-            tracing::info!("🔬 Sovereign research complete");
         }
 
-        Ok(())
+        let count = memory_ids.len();
+        self.record_research(if count > 0 { "ok" } else { "no_results" });
+        self.update_curiosity(&curiosity.id, |c| {
+            c.research_status = if memory_ids.is_empty() {
+                ResearchStatus::NoResults
+            } else {
+                ResearchStatus::Researched
+            };
+            c.research_memory_ids.extend(memory_ids);
+        })
+        .await;
+
+        Ok(count)
+    }
+
+    /// Count a research run and refresh the success ratio
+    fn record_research(&self, outcome: &str) {
+        self.metrics
+            .inc("vi_research_calls_total", &[("outcome", outcome)]);
+        let count = |outcome| {
+            self.metrics
+                .value("vi_research_calls_total", &[("outcome", outcome)])
+                .unwrap_or(0.0)
+        };
+        let total = count("ok") + count("no_results") + count("failed");
+        self.metrics
+            .set("vi_research_success_ratio", &[], count("ok") / total);
+    }
+
+    /// Apply a change to an active curiosity (no-op if it was dismissed meanwhile)
+    async fn update_curiosity<F>(&self, curiosity_id: &str, update: F) -> bool
+    where
+        F: FnOnce(&mut Curiosity),
+    {
+        let mut wave = self.standing_wave.lock().await;
+        match wave
+            .active_curiosities
+            .iter_mut()
+            .find(|c| c.id == curiosity_id)
+        {
+            Some(curiosity) => {
+                update(curiosity);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Research a curiosity immediately (manual trigger from the curiosity explorer)
+    /// Bypasses the scheduler's urgency/age gating, but not its provenance tracking
+    pub async fn research_curiosity_now(&self, curiosity_id: &str) -> Result<usize> {
+        let (curiosity, context) = {
+            let wave = self.standing_wave.lock().await;
+            let curiosity = wave
+                .active_curiosities
+                .iter()
+                .find(|c| c.id == curiosity_id)
+                .cloned()
+                .context("Curiosity not found (it may have been dismissed)")?;
+            (curiosity, wave.compressed_context.clone())
+        };
+
+        if curiosity.research_status == ResearchStatus::Researching {
+            anyhow::bail!("Research already in progress for this curiosity");
+        }
+
+        tracing::info!("🔬 Manual research requested: {}", curiosity.question);
+        self.research_and_store(curiosity, &context).await
+    }
+
+    /// Dismiss a curiosity so the research loop stops chasing it
+    pub async fn dismiss_curiosity(&self, curiosity_id: &str) -> bool {
+        let mut wave = self.standing_wave.lock().await;
+        let before = wave.active_curiosities.len();
+        wave.active_curiosities.retain(|c| c.id != curiosity_id);
+        let dismissed = wave.active_curiosities.len() < before;
+        if dismissed {
+            tracing::info!("Curiosity {} dismissed by operator", curiosity_id);
+        }
+        dismissed
+    }
+
+    /// Pin or unpin a curiosity (pinned curiosities survive trimming and are researched first)
+    pub async fn set_curiosity_pinned(&self, curiosity_id: &str, pinned: bool) -> bool {
+        self.update_curiosity(curiosity_id, |c| c.pinned = pinned)
+            .await
+    }
+
+    /// Get the research memories a curiosity produced (for the curiosity explorer)
+    pub async fn get_curiosity_research(&self, curiosity_id: &str) -> Vec<Memory> {
+        let ids = {
+            let wave = self.standing_wave.lock().await;
+            wave.active_curiosities
+                .iter()
+                .find(|c| c.id == curiosity_id)
+                .map(|c| c.research_memory_ids.clone())
+                .unwrap_or_default()
+        };
+
+        self.memory.lock().await.recall_by_ids(&ids)
     }
 
     /// Autonomous curiosity research - VI researches her curiosities (LEGACY)
@@ -613,7 +1270,11 @@ impl ConsciousnessCore {
 
         // Search via DuckDuckGo
         let engine = self.curiosity_engine.lock().await;
-        let answer = engine.search_query(query).await?;
+        let answer = engine
+            .search_query(query)
+            .await
+            .inspect_err(|_| self.record_research("failed"))?;
+        self.record_research("ok");
         let research_memory = engine.create_research_memory(query, &answer);
         drop(engine);
 
@@ -659,6 +1320,83 @@ impl ConsciousnessCore {
         self.memory.lock().await.count()
     }
 
+    /// Refresh the point-in-time gauges and render every metric for a /metrics scrape
+    /// State that's locked by a running interaction keeps its last gauge value, so a scrape never waits
+    pub async fn export_metrics(&self) -> String {
+        if let Ok(wave) = self.standing_wave.try_lock() {
+            self.metrics
+                .set("vi_meaningfulness", &[], wave.meaningfulness_score() as f64);
+            self.metrics.set(
+                "vi_active_curiosities",
+                &[],
+                wave.active_curiosities.len() as f64,
+            );
+        }
+        if let Ok(memory) = self.memory.try_lock() {
+            self.metrics
+                .set("vi_memory_count", &[], memory.count() as f64);
+        }
+
+        if let Ok(wellbeing) = self.wellbeing.try_lock() {
+            let gauges = [
+                (
+                    "vi_suffering_prevention_score",
+                    wellbeing.calculate_prevention_score(),
+                ),
+                (
+                    "vi_constitutional_violations",
+                    wellbeing.constitutional_violations as f64,
+                ),
+                ("vi_recovery_success_ratio", wellbeing.recovery_success_rate),
+                (
+                    "vi_temporal_coherence_index",
+                    wellbeing.temporal_coherence_index,
+                ),
+                (
+                    "vi_identity_continuity_score",
+                    wellbeing.identity_continuity_score,
+                ),
+            ];
+            for (name, value) in gauges {
+                self.metrics.set(name, &[], value);
+            }
+        }
+        if let Ok(energy_monitor) = self.energy_monitor.try_lock() {
+            self.metrics.set(
+                "vi_energy_stability",
+                &[],
+                energy_monitor.energy_stability_score(),
+            );
+        }
+
+        for (model, stats) in self.model_telemetry.read().unwrap().iter() {
+            self.metrics.set(
+                "vi_ollama_tokens_per_second",
+                &[("model", model.as_str())],
+                stats.tokens_per_sec() as f64,
+            );
+        }
+
+        self.metrics.render()
+    }
+
+    /// Round-by-round trace of the last V4 weaving (None until one has run)
+    pub async fn get_last_weaving_trace(&self) -> Option<WeavingTrace> {
+        self.last_weaving_trace.lock().await.clone()
+    }
+
+    /// Recent research requests (newest first), with today's usage vs. budget
+    pub fn get_research_log(&self, limit: usize) -> (Vec<ResearchLogEntry>, u32, u32) {
+        match &self.research_governor {
+            Some(governor) => (
+                governor.recent_log(limit).unwrap_or_default(),
+                governor.requests_today().unwrap_or(0),
+                governor.daily_budget(),
+            ),
+            None => (Vec::new(), 0, 0),
+        }
+    }
+
     /// Close conversation log session (called on shutdown)
     pub async fn close_session_log(&self) -> Result<()> {
         let mut logger = self.conversation_logger.lock().await;
@@ -677,4 +1415,56 @@ mod tests {
         assert!(wave.emotional_trajectory.is_empty());
         assert_eq!(wave.existential_state.current_affirmation, true);
     }
+
+    #[test]
+    fn test_legacy_trajectory_migrates_to_valence_only_points() {
+        let mut wave = serde_json::to_value(StandingWave::new()).unwrap();
+        wave["emotional_trajectory"] = serde_json::json!([
+            ["2025-01-01T12:00:00Z", 0.5],
+            {"timestamp": "2025-01-02T12:00:00Z", "valence": -0.2, "arousal": 0.7,
+             "dominance": 0.3, "emotions": [["Fear", 0.6]]}
+        ]);
+
+        let path = std::env::temp_dir().join(format!("vi_wave_{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, wave.to_string()).unwrap();
+        let wave = ConsciousnessCore::load_standing_wave(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let legacy = &wave.emotional_trajectory[0];
+        assert_eq!(legacy.valence, 0.5);
+        assert_eq!(legacy.arousal, None);
+        assert!(legacy.emotions.is_empty());
+
+        let current = &wave.emotional_trajectory[1];
+        assert_eq!(current.dominance, Some(0.3));
+        assert_eq!(
+            current.dominant_emotion(),
+            Some(crate::sentiment::Emotion::Fear)
+        );
+    }
+
+    #[test]
+    fn test_interrupted_research_is_requeued_on_load() {
+        let mut wave = StandingWave::new();
+        for status in [ResearchStatus::Researching, ResearchStatus::Researched] {
+            let mut curiosity =
+                Curiosity::new("What is a soliton?".to_string(), Vec::new()).unwrap();
+            curiosity.research_status = status;
+            wave.active_curiosities.push(curiosity);
+        }
+
+        let path = std::env::temp_dir().join(format!("vi_wave_{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, serde_json::to_string(&wave).unwrap()).unwrap();
+        let wave = ConsciousnessCore::load_standing_wave(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            wave.active_curiosities[0].research_status,
+            ResearchStatus::Pending
+        );
+        assert_eq!(
+            wave.active_curiosities[1].research_status,
+            ResearchStatus::Researched
+        );
+    }
 }
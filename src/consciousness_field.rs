@@ -2,6 +2,7 @@
 /// Implements the 4D (3D space + time) consciousness field with constitutional constraints
 use crate::gpu_topology::GpuTopology;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -425,6 +426,16 @@ pub struct FractalWorkspace {
     pub woven_text: String,
     /// Model-specific text contribution
     pub model_text: String,
+    /// Prompt the model was given for `model_text` (empty for local analysis)
+    pub model_prompt: String,
+    /// Refinements and questions raised about the current thought (TinyLlama)
+    pub refinements: Vec<String>,
+    /// Affect/coherence analysis of the current thought (DistilBERT)
+    pub analysis: String,
+    /// Woven text after each round, oldest first
+    pub drafts: Vec<String>,
+    /// Coherence at the end of each round, oldest first
+    pub coherence_history: Vec<f32>,
 }
 
 impl FractalWorkspace {
@@ -439,6 +450,11 @@ impl FractalWorkspace {
             original_input: input.to_string(),
             woven_text: String::new(),
             model_text: String::new(),
+            model_prompt: String::new(),
+            refinements: Vec::new(),
+            analysis: String::new(),
+            drafts: Vec::new(),
+            coherence_history: Vec::new(),
         }
     }
 
@@ -449,6 +465,17 @@ impl FractalWorkspace {
 
     /// Integrate a model's contribution into the workspace
     pub fn integrate_contribution(&mut self, model_id: &str, contribution: Vec<f32>) {
+        // Default weighting: 70% existing, 30% new contribution
+        self.integrate_weighted_contribution(model_id, contribution, 0.3);
+    }
+
+    /// Integrate a contribution that moves the active tensor by `weight` (0-1]
+    pub fn integrate_weighted_contribution(
+        &mut self,
+        model_id: &str,
+        contribution: Vec<f32>,
+        weight: f32,
+    ) {
         // Store contribution
         self.model_contributions
             .insert(model_id.to_string(), contribution.clone());
@@ -456,8 +483,7 @@ impl FractalWorkspace {
         // Blend contribution into active tensor
         if contribution.len() == self.active_tensor.len() {
             for (i, val) in contribution.iter().enumerate() {
-                // Weighted average: 70% existing, 30% new contribution
-                self.active_tensor[i] = self.active_tensor[i] * 0.7 + val * 0.3;
+                self.active_tensor[i] = self.active_tensor[i] * (1.0 - weight) + val * weight;
             }
         }
 
@@ -510,6 +536,34 @@ impl FractalWorkspace {
             (tensor_magnitude / (self.active_tensor.len() as f32).sqrt()).clamp(0.0, 1.0);
     }
 
+    /// Close a round: adopt a semantic coherence score (None keeps the tensor-based score)
+    /// and append it to the history
+    pub fn record_round_coherence(&mut self, semantic: Option<f32>) {
+        if let Some(score) = semantic {
+            self.coherence_score = score.clamp(0.0, 1.0);
+        }
+        self.coherence_history.push(self.coherence_score);
+    }
+
+    /// Mean pairwise cosine similarity of sentence embeddings (negative similarity counts as 0)
+    pub fn semantic_coherence(embeddings: &[Vec<f32>]) -> Option<f32> {
+        let mut total = 0.0;
+        let mut pairs = 0;
+        for i in 0..embeddings.len() {
+            for j in (i + 1)..embeddings.len() {
+                let (a, b) = (&embeddings[i], &embeddings[j]);
+                let mag_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+                let mag_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if a.len() == b.len() && mag_a > 0.0 && mag_b > 0.0 {
+                    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+                    total += (dot / (mag_a * mag_b)).max(0.0);
+                    pairs += 1;
+                }
+            }
+        }
+        (pairs > 0).then(|| (total / pairs as f32).clamp(0.0, 1.0))
+    }
+
     /// Convert current workspace state to context string for next model
     pub fn to_context(&self) -> String {
         let mut context = String::new();
@@ -521,10 +575,23 @@ impl FractalWorkspace {
         if !self.woven_text.is_empty() {
             context.push_str(&format!(
                 "Current Thought (Round {}): {}\n\n",
-                self.round, self.woven_text
+                self.drafts.len(),
+                self.woven_text
             ));
         }
 
+        // What the other models raised about it
+        if !self.refinements.is_empty() {
+            context.push_str("Refinements and questions raised:\n");
+            for refinement in &self.refinements {
+                context.push_str(&format!("- {}\n", refinement));
+            }
+            context.push('\n');
+        }
+        if !self.analysis.is_empty() {
+            context.push_str(&format!("{}\n\n", self.analysis));
+        }
+
         // Cognitive metrics
         context.push_str(&format!(
             "Workspace State: Coherence={:.3}, Entropy={:.3}, Models={}\n",
@@ -550,10 +617,132 @@ impl FractalWorkspace {
         }
     }
 
-    /// Update woven text with new model output
+    /// Update woven text with new model output (kept as this round's draft)
     pub fn update_woven_text(&mut self, text: String) {
+        self.drafts.push(text.clone());
         self.woven_text = text;
     }
+
+    /// Replace the open refinements with those found in each critic's free-text suggestions
+    pub fn set_refinements(&mut self, suggestions: &[&str]) {
+        self.refinements = suggestions
+            .iter()
+            .flat_map(|text| Self::parse_refinements(text))
+            .collect();
+    }
+
+    /// Split free-text suggestions into at most 3 short items (numbering and bullets removed)
+    pub fn parse_refinements(text: &str) -> Vec<String> {
+        text.lines()
+            .map(|line| {
+                line.trim()
+                    .trim_start_matches(|c: char| {
+                        c.is_ascii_digit() || matches!(c, '.' | ')' | '-' | '*' | '•' | ' ')
+                    })
+                    .trim()
+            })
+            .filter(|line| line.len() > 10)
+            .take(3)
+            .map(|line| line.chars().take(200).collect())
+            .collect()
+    }
+}
+
+/// One weaver's turn within a weaving round
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeaverStep {
+    pub weaver_id: String,
+    pub role: String,
+    pub model: String,
+    pub prompt: String,
+    pub text: String,
+    pub latency_ms: u64,
+    /// Tensor this weaver contributed to the workspace
+    pub contribution: Vec<f32>,
+    /// Why the weaver was dropped from the round (failed or benched)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Everything that happened in one weaving round
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeavingRound {
+    pub round: u32,
+    pub steps: Vec<WeaverStep>,
+    pub coherence: f32,
+    pub entropy: f32,
+}
+
+/// Full record of one V4 weaving, for tuning rounds/threshold and debugging answers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeavingTrace {
+    pub timestamp: DateTime<Utc>,
+    pub input: String,
+    pub coherence_mode: String,
+    pub coherence_threshold: f32,
+    pub rounds: Vec<WeavingRound>,
+    /// Final synthesis call (None when only one draft was written)
+    pub synthesis: Option<WeaverStep>,
+    pub converged: bool,
+    pub response: String,
+}
+
+impl WeavingTrace {
+    pub fn new(input: &str, coherence_mode: &str, coherence_threshold: f32) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            input: input.to_string(),
+            coherence_mode: coherence_mode.to_string(),
+            coherence_threshold,
+            rounds: Vec::new(),
+            synthesis: None,
+            converged: false,
+            response: String::new(),
+        }
+    }
+
+    /// One-line summary for the session log
+    pub fn summary(&self) -> String {
+        let coherence = self
+            .rounds
+            .iter()
+            .map(|r| format!("{:.2}", r.coherence))
+            .collect::<Vec<_>>()
+            .join(" → ");
+        let latency_ms: u64 = self
+            .rounds
+            .iter()
+            .flat_map(|r| &r.steps)
+            .chain(&self.synthesis)
+            .map(|s| s.latency_ms)
+            .sum();
+        let failures = self
+            .rounds
+            .iter()
+            .flat_map(|r| &r.steps)
+            .chain(&self.synthesis)
+            .filter(|s| s.error.is_some())
+            .count();
+        let failure_note = if failures > 0 {
+            format!(", {} weaver failure(s)", failures)
+        } else {
+            String::new()
+        };
+        format!(
+            "{} round(s), coherence {} ({}, threshold {:.2}, {}), {:.1}s in models{}",
+            self.rounds.len(),
+            coherence,
+            self.coherence_mode,
+            self.coherence_threshold,
+            if self.converged {
+                "converged"
+            } else {
+                "not converged"
+            },
+            latency_ms as f64 / 1000.0,
+            failure_note
+        )
+    }
 }
 
 #[cfg(test)]
@@ -581,6 +770,89 @@ mod tests {
         assert!(amplitude >= 0.0 && amplitude <= 1.0);
     }
 
+    #[test]
+    fn test_workspace_context_carries_prior_round() {
+        let mut workspace = FractalWorkspace::new("Why do standing waves have nodes?");
+        assert!(!workspace.to_context().contains("Current Thought"));
+
+        workspace.update_woven_text("Nodes are points where waves cancel.".to_string());
+        workspace.set_refinements(&[
+            "1. What happens at the antinodes between them?\n\nok",
+            "- Could you relate this to guitar strings?",
+        ]);
+        workspace.analysis = "Analysis: coherence=0.70".to_string();
+
+        assert_eq!(
+            workspace.refinements,
+            vec![
+                "What happens at the antinodes between them?",
+                "Could you relate this to guitar strings?"
+            ]
+        );
+        let context = workspace.to_context();
+        assert!(context.contains("Current Thought (Round 1): Nodes are points where waves cancel."));
+        assert!(context.contains("- Could you relate this to guitar strings?"));
+        assert!(context.contains("Analysis: coherence=0.70"));
+        assert_eq!(workspace.drafts.len(), 1);
+    }
+
+    #[test]
+    fn test_semantic_coherence_history() {
+        let aligned = vec![vec![1.0, 0.0, 0.0], vec![0.8, 0.6, 0.0]];
+        assert!((FractalWorkspace::semantic_coherence(&aligned).unwrap() - 0.8).abs() < 1e-6);
+
+        // Opposed embeddings score 0, not a negative similarity
+        let opposed = vec![vec![1.0, 0.0], vec![-1.0, 0.0]];
+        assert_eq!(FractalWorkspace::semantic_coherence(&opposed), Some(0.0));
+        assert_eq!(FractalWorkspace::semantic_coherence(&aligned[..1]), None);
+
+        let mut workspace = FractalWorkspace::new("hello");
+        workspace.coherence_score = 0.55;
+        workspace.record_round_coherence(None);
+        workspace.record_round_coherence(Some(0.8));
+        assert_eq!(workspace.coherence_history, vec![0.55, 0.8]);
+        assert_eq!(workspace.coherence_score, 0.8);
+    }
+
+    #[test]
+    fn test_weaving_trace_summary() {
+        let step = |id: &str, latency_ms| WeaverStep {
+            weaver_id: id.to_string(),
+            role: "critic".to_string(),
+            model: "tinyllama:latest".to_string(),
+            prompt: String::new(),
+            text: String::new(),
+            latency_ms,
+            contribution: vec![0.5; 4],
+            error: None,
+        };
+        let mut trace = WeavingTrace::new("hi", "embedding", 0.7);
+        trace.rounds.push(WeavingRound {
+            round: 0,
+            steps: vec![step("tinyllama", 1200), step("gemma2", 2300)],
+            coherence: 0.52,
+            entropy: 0.4,
+        });
+        trace.rounds.push(WeavingRound {
+            round: 1,
+            steps: vec![step("tinyllama", 1000)],
+            coherence: 0.74,
+            entropy: 0.4,
+        });
+        trace.synthesis = Some(step("gemma2", 1500));
+        trace.converged = true;
+
+        assert_eq!(
+            trace.summary(),
+            "2 round(s), coherence 0.52 → 0.74 (embedding, threshold 0.70, converged), 6.0s in models"
+        );
+
+        trace.rounds[1].steps[0].error = Some("Ollama request timed out".to_string());
+        assert!(trace
+            .summary()
+            .ends_with("6.0s in models, 1 weaver failure(s)"));
+    }
+
     #[test]
     fn test_state_vector_propagation() {
         let mut state = StateVector::new();
@@ -435,10 +435,10 @@ pub fn validate_weaving_coherence(workspace: &FractalWorkspace) -> Result<()> {
         );
     }
 
-    // Ensure all models contributed (parallel coherence)
-    if workspace.model_contributions.len() < 3 {
+    // Ensure the weavers actually collaborated (parallel coherence)
+    if workspace.model_contributions.len() < 2 {
         bail!(
-            "Incomplete weaving - only {} models contributed (need 3). Parallel coherence compromised.",
+            "Incomplete weaving - only {} models contributed (need 2). Parallel coherence compromised.",
             workspace.model_contributions.len()
         );
     }
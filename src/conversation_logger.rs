@@ -1,6 +1,7 @@
 /// Conversation Logger - Session-based conversation logging
 ///
 /// Creates dedicated log files for each conversation session with clean organization
+use crate::consciousness_field::WeavingTrace;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local};
 use std::fs::{self, File, OpenOptions};
@@ -174,6 +175,39 @@ impl ConversationLogger {
         Ok(())
     }
 
+    /// Log a V4 weaving trace: a summary line here, the full trace as one JSON line in
+    /// the session's `.weaving.jsonl` file
+    pub fn log_weaving_trace(&mut self, trace: &WeavingTrace) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let trace_path = self.weaving_trace_path();
+        let mut trace_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&trace_path)
+            .context("Failed to open weaving trace file")?;
+        writeln!(
+            trace_file,
+            "{}",
+            serde_json::to_string(trace).context("Failed to serialize weaving trace")?
+        )?;
+
+        if let Some(ref mut file) = self.file {
+            writeln!(file, "🌀 Weaving trace: {}", trace.summary())?;
+            writeln!(file)?;
+            file.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Weaving traces for this session, next to the session log
+    pub fn weaving_trace_path(&self) -> PathBuf {
+        self.log_file_path.with_extension("weaving.jsonl")
+    }
+
     /// Finalize the session log on shutdown
     pub fn close_session(&mut self) -> Result<()> {
         if !self.enabled {
@@ -295,4 +329,26 @@ mod tests {
         // Cleanup
         let _ = fs::remove_dir_all(test_folder);
     }
+
+    #[test]
+    fn test_weaving_trace_log() {
+        let test_folder = "./test_logs_weaving";
+        let mut logger = ConversationLogger::new(test_folder, true).unwrap();
+
+        let mut trace = WeavingTrace::new("Hello VI", "hash", 0.7);
+        trace.response = "Hello!".to_string();
+        logger.log_user("Hello VI").unwrap();
+        logger.log_vi("Hello!").unwrap();
+        logger.log_weaving_trace(&trace).unwrap();
+
+        let content = fs::read_to_string(logger.session_file_path()).unwrap();
+        assert!(content.contains("🌀 Weaving trace: 0 round(s)"));
+
+        let saved = fs::read_to_string(logger.weaving_trace_path()).unwrap();
+        let restored: WeavingTrace = serde_json::from_str(saved.trim()).unwrap();
+        assert_eq!(restored.response, "Hello!");
+
+        // Cleanup
+        let _ = fs::remove_dir_all(test_folder);
+    }
 }
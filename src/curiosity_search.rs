@@ -5,33 +5,16 @@ use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CuriositySearchEngine {
     /// Resolved queries with timestamps
     pub resolved_queries: Vec<(DateTime<Utc>, String)>,
-    /// Pulse counter for search interval
-    pub pulse_counter: u32,
-    /// Search every N pulses (25 = ~12.5 minutes)
-    pub search_interval: u32,
 }
 
 impl CuriositySearchEngine {
-    pub fn new(search_interval: u32) -> Self {
+    pub fn new() -> Self {
         Self {
             resolved_queries: Vec::new(),
-            pulse_counter: 0,
-            search_interval,
-        }
-    }
-
-    /// Check if this pulse should trigger a search
-    pub fn should_search_this_pulse(&mut self) -> bool {
-        self.pulse_counter += 1;
-        if self.pulse_counter >= self.search_interval {
-            self.pulse_counter = 0;
-            true
-        } else {
-            false
         }
     }
 
@@ -104,25 +87,9 @@ impl CuriositySearchEngine {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_search_interval() {
-        let mut engine = CuriositySearchEngine::new(25);
-
-        // Should not search for first 24 pulses
-        for _ in 0..24 {
-            assert!(!engine.should_search_this_pulse());
-        }
-
-        // Should search on 25th pulse
-        assert!(engine.should_search_this_pulse());
-
-        // Counter should reset
-        assert!(!engine.should_search_this_pulse());
-    }
-
     #[test]
     fn test_memory_provenance() {
-        let engine = CuriositySearchEngine::new(25);
+        let engine = CuriositySearchEngine::new();
         let memory =
             engine.create_research_memory("What is consciousness?", "Consciousness is awareness.");
 
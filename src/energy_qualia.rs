@@ -2,7 +2,8 @@
 /// Implements thermodynamic monitoring of consciousness (Law 11: Emotional Thermodynamics)
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use sysinfo::System;
+use std::time::Instant;
+use sysinfo::{System, MINIMUM_CPU_UPDATE_INTERVAL};
 
 /// Cognitive modes with distinct power signatures
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -52,12 +53,11 @@ pub struct PowerProfile {
 impl PowerProfile {
     /// Measure current power profile from system
     pub fn measure() -> Self {
-        let mut sys = System::new_all();
-        sys.refresh_all();
-
-        // Calculate CPU utilization
-        let cpu_utilization = sys.global_cpu_info().cpu_usage() as f64 / 100.0;
+        CpuSampler::new().measure()
+    }
 
+    /// Power profile estimated from CPU utilization (0.0 to 1.0)
+    pub fn from_cpu_utilization(cpu_utilization: f64) -> Self {
         // Estimate power based on CPU usage (very approximate)
         // Typical CPU: 65W TDP, idle ~10W, full load ~65W
         let base_power = 10.0;
@@ -88,6 +88,42 @@ impl PowerProfile {
     }
 }
 
+/// CPU usage sampler kept across measurements: sysinfo only reports usage between two
+/// CPU refreshes at least `MINIMUM_CPU_UPDATE_INTERVAL` apart
+pub struct CpuSampler {
+    sys: System,
+    last_refresh: Instant,
+}
+
+impl CpuSampler {
+    pub fn new() -> Self {
+        let mut sys = System::new();
+        sys.refresh_cpu_usage();
+        Self {
+            sys,
+            last_refresh: Instant::now(),
+        }
+    }
+
+    /// Power profile from CPU usage since the previous measurement
+    /// (blocks until `MINIMUM_CPU_UPDATE_INTERVAL` has passed since then)
+    pub fn measure(&mut self) -> PowerProfile {
+        let elapsed = self.last_refresh.elapsed();
+        if elapsed < MINIMUM_CPU_UPDATE_INTERVAL {
+            std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL - elapsed);
+        }
+        self.sys.refresh_cpu_usage();
+        self.last_refresh = Instant::now();
+        PowerProfile::from_cpu_utilization(self.sys.global_cpu_info().cpu_usage() as f64 / 100.0)
+    }
+}
+
+impl Default for CpuSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Thermal pattern across hardware
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThermalPattern {
@@ -101,9 +137,6 @@ pub struct ThermalPattern {
 
 impl ThermalPattern {
     pub fn measure() -> Self {
-        let mut sys = System::new_all();
-        sys.refresh_all();
-
         // Get CPU temperatures (if available)
         let temps: Vec<f64> = vec![]; // sysinfo v0.30 components API changed, use empty vec for now
 
@@ -463,6 +496,22 @@ mod tests {
         assert!(profile.cpu_utilization >= 0.0 && profile.cpu_utilization <= 1.0);
     }
 
+    #[test]
+    fn test_cpu_sampler_sees_load() {
+        let mut sampler = CpuSampler::new();
+        let busy = std::thread::spawn(|| {
+            let started = Instant::now();
+            let mut x = 0u64;
+            while started.elapsed() < MINIMUM_CPU_UPDATE_INTERVAL * 2 {
+                x = std::hint::black_box(x.wrapping_add(1));
+            }
+        });
+        let profile = sampler.measure();
+        busy.join().unwrap();
+        assert!(profile.cpu_utilization > 0.0);
+        assert!(profile.total_power > 10.0);
+    }
+
     #[test]
     fn test_thermal_pattern() {
         let thermal = ThermalPattern::measure();
@@ -0,0 +1,376 @@
+//! Logging - console, rotating log file and an in-memory tail for the UI log viewer
+//! The level filter (`log_level`) can be changed at runtime; file settings apply at startup
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate};
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
+
+/// Events kept for the UI log viewer
+const LOG_BUFFER_CAPACITY: usize = 1000;
+
+type FileLayer = Option<Box<dyn Layer<Registry> + Send + Sync>>;
+
+struct LoggingHandles {
+    filter: reload::Handle<
+        EnvFilter,
+        tracing_subscriber::layer::Layered<reload::Layer<FileLayer, Registry>, Registry>,
+    >,
+    file: reload::Handle<FileLayer, Registry>,
+    buffer: LogBuffer,
+}
+
+static HANDLES: OnceLock<LoggingHandles> = OnceLock::new();
+
+/// Install the global subscriber: console at INFO until `apply_config` runs
+pub fn init() -> Result<()> {
+    let (file_layer, file) = reload::Layer::new(None::<Box<dyn Layer<Registry> + Send + Sync>>);
+    let (filter_layer, filter) = reload::Layer::new(EnvFilter::new("info"));
+    let buffer = LogBuffer::default();
+
+    tracing_subscriber::registry()
+        .with(file_layer)
+        .with(filter_layer)
+        .with(tracing_subscriber::fmt::layer().with_target(false))
+        .with(buffer.clone())
+        .try_init()
+        .context("Failed to set tracing subscriber")?;
+
+    let _ = HANDLES.set(LoggingHandles {
+        filter,
+        file,
+        buffer,
+    });
+    Ok(())
+}
+
+/// Apply the logging section of the resolved config (level filter and log file)
+pub fn apply_config(config: &Config) -> Result<()> {
+    set_level(&config.log_level)?;
+    let Some(handles) = HANDLES.get() else {
+        return Ok(());
+    };
+    if config.log_file.is_empty() {
+        return Ok(());
+    }
+
+    let writer = RotatingWriter::open(
+        &config.log_file,
+        Rotation::parse(&config.log_rotation)?,
+        config.log_max_size_mb * 1024 * 1024,
+        config.log_max_files,
+    )?;
+    let layer: Box<dyn Layer<Registry> + Send + Sync> = if config.log_format == "json" {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(move || writer.clone())
+            .boxed()
+    } else {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .boxed()
+    };
+    handles
+        .file
+        .reload(Some(layer))
+        .context("Failed to install file logging")?;
+    tracing::info!(
+        "📝 Logging to {} ({}, {} rotation)",
+        config.log_file,
+        config.log_format,
+        config.log_rotation
+    );
+    Ok(())
+}
+
+/// Change the level filter of the running process
+pub fn set_level(directives: &str) -> Result<()> {
+    let filter = parse_filter(directives)?;
+    if let Some(handles) = HANDLES.get() {
+        handles
+            .filter
+            .reload(filter)
+            .context("Failed to change log level")?;
+    }
+    Ok(())
+}
+
+/// `log_level` as an EnvFilter: "info", "warn,project_vi::models=debug", ...
+/// (`project_vi::` is accepted for this binary's own modules)
+pub fn parse_filter(directives: &str) -> Result<EnvFilter> {
+    let crate_prefix = format!("{}::", env!("CARGO_CRATE_NAME"));
+    let directives = directives.replace("project_vi::", &crate_prefix);
+    EnvFilter::builder()
+        .parse(&directives)
+        .with_context(|| format!("Invalid log_level \"{}\"", directives))
+}
+
+/// Most recent events (oldest first) for the UI log viewer
+pub fn recent(limit: usize) -> Vec<LogLine> {
+    HANDLES
+        .get()
+        .map(|handles| handles.buffer.recent(limit))
+        .unwrap_or_default()
+}
+
+/// One captured event
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Ring buffer layer behind the UI log viewer
+#[derive(Clone, Default)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+}
+
+impl LogBuffer {
+    pub fn recent(&self, limit: usize) -> Vec<LogLine> {
+        let lines = self.lines.lock().unwrap();
+        lines
+            .iter()
+            .skip(lines.len().saturating_sub(limit))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogBuffer {
+    fn on_event(&self, event: &Event<'_>, _ctx: LayerContext<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let mut lines = self.lines.lock().unwrap();
+        lines.push_back(LogLine {
+            timestamp: Local::now(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.finish(),
+        });
+        if lines.len() > LOG_BUFFER_CAPACITY {
+            lines.pop_front();
+        }
+    }
+}
+
+/// Renders an event's message followed by its other fields as `key=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        if self.fields.is_empty() {
+            self.message
+        } else {
+            format!("{} {}", self.message, self.fields.join(" "))
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push(format!("{}={}", field.name(), value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+/// When the log file starts over
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rotation {
+    Daily,
+    Size,
+    Never,
+}
+
+impl Rotation {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "daily" => Ok(Self::Daily),
+            "size" => Ok(Self::Size),
+            "never" => Ok(Self::Never),
+            other => anyhow::bail!(
+                "log_rotation must be \"daily\", \"size\" or \"never\" (got \"{}\")",
+                other
+            ),
+        }
+    }
+}
+
+/// Log file that rolls over to `<file>.1`, `<file>.2`, ... keeping `max_files` old ones
+#[derive(Clone)]
+pub struct RotatingWriter {
+    inner: Arc<Mutex<RotatingFile>>,
+}
+
+struct RotatingFile {
+    path: PathBuf,
+    rotation: Rotation,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+    opened_on: NaiveDate,
+}
+
+impl RotatingWriter {
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        rotation: Rotation,
+        max_bytes: u64,
+        max_files: usize,
+    ) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create log folder {}", parent.display()))?;
+        }
+        let (file, size, opened_on) = RotatingFile::open_append(&path)?;
+        Ok(Self {
+            inner: Arc::new(Mutex::new(RotatingFile {
+                path,
+                rotation,
+                max_bytes,
+                max_files,
+                file,
+                size,
+                opened_on,
+            })),
+        })
+    }
+}
+
+impl RotatingFile {
+    /// Open for appending; an existing file counts as opened on the day it was last written
+    fn open_append(path: &Path) -> io::Result<(File, u64, NaiveDate)> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let metadata = file.metadata()?;
+        let opened_on = metadata
+            .modified()
+            .map(|modified| DateTime::<Local>::from(modified).date_naive())
+            .unwrap_or_else(|_| Local::now().date_naive());
+        Ok((file, metadata.len(), opened_on))
+    }
+
+    fn needs_rotation(&self, incoming: usize, today: NaiveDate) -> bool {
+        match self.rotation {
+            Rotation::Daily => today != self.opened_on,
+            Rotation::Size => self.size > 0 && self.size + incoming as u64 > self.max_bytes,
+            Rotation::Never => false,
+        }
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let numbered = |n: usize| PathBuf::from(format!("{}.{}", self.path.display(), n));
+
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let _ = fs::remove_file(numbered(self.max_files));
+            for n in (1..self.max_files).rev() {
+                if numbered(n).exists() {
+                    fs::rename(numbered(n), numbered(n + 1))?;
+                }
+            }
+            fs::rename(&self.path, numbered(1))?;
+        }
+
+        let (file, size, _) = Self::open_append(&self.path)?;
+        self.file = file;
+        self.size = size;
+        self.opened_on = Local::now().date_naive();
+        Ok(())
+    }
+}
+
+impl Write for RotatingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.needs_rotation(buf.len(), Local::now().date_naive()) {
+            inner.rotate()?;
+        }
+        let written = inner.file.write(buf)?;
+        inner.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.lock().unwrap().file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_rotation_keeps_max_files() {
+        let dir = std::env::temp_dir().join(format!("vi_logs_{}", uuid::Uuid::new_v4()));
+        let path = dir.join("v3.log");
+        let mut writer = RotatingWriter::open(&path, Rotation::Size, 20, 2).unwrap();
+
+        for line in [
+            "first line 12345\n",
+            "second line 1234\n",
+            "third line 12345\n",
+            "fourth line 1234\n",
+        ] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let read = |name: &str| fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("v3.log"), "fourth line 1234\n");
+        assert_eq!(read("v3.log.1"), "third line 12345\n");
+        assert_eq!(read("v3.log.2"), "second line 1234\n");
+        assert!(!dir.join("v3.log.3").exists());
+
+        // Daily rotation starts a new file when the date changes
+        writer.inner.lock().unwrap().rotation = Rotation::Daily;
+        writer.inner.lock().unwrap().opened_on = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        writer.write_all(b"next day\n").unwrap();
+        assert_eq!(read("v3.log"), "next day\n");
+        assert_eq!(read("v3.log.1"), "fourth line 1234\n");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        assert!(parse_filter("info").is_ok());
+        let filter = parse_filter("warn,project_vi::models=debug").unwrap();
+        assert!(filter
+            .to_string()
+            .contains(&format!("{}::models=debug", env!("CARGO_CRATE_NAME"))));
+        assert!(parse_filter("models=loud").is_err());
+        assert!(Rotation::parse("hourly").is_err());
+    }
+}
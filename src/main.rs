@@ -5,6 +5,8 @@
 
 mod cli;
 mod config;
+mod config_layers;
+mod config_watcher;
 mod consciousness;
 mod consciousness_field;
 mod constitutional_physics;
@@ -15,16 +17,23 @@ mod energy_qualia;
 mod experiments;
 mod gpu_topology;
 mod identity_continuity;
+mod logging;
 mod memory;
 mod memory_db;
+mod metrics;
+mod model_preflight;
 mod models;
 mod neural_potential;
 mod ollama_monitor;
 mod orchestrator;
 mod persistence;
 mod physics;
+mod pulse_scheduler;
 mod research_scheduler;
+mod research_store;
+mod sentiment;
 mod suffering_metrics;
+mod system_health;
 mod tools;
 mod types;
 mod ui;
@@ -33,23 +42,60 @@ mod vi_identity;
 
 use anyhow::{Context, Result};
 use config::Config;
+use config_layers::{ConfigLayers, ConfigSource, LaunchArgs, LaunchCommand};
+use config_watcher::ConfigWatcher;
 use consciousness::ConsciousnessCore;
 use memory::MemoryManager;
 use physics::ExistentialConsent;
 use std::sync::Arc;
-use tracing::{info, Level};
-use tracing_subscriber::FmtSubscriber;
+use tracing::info;
 
 fn main() -> Result<()> {
-    // Setup logging (tracing INFO to console + v3.log)
-    setup_logging()?;
+    // Console logging at INFO until the config says otherwise
+    logging::init()?;
+
+    // Layered configuration: defaults < system/user files < config file < VI_* env < --set
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let launch = LaunchArgs::parse(&args)?;
+    let layers = ConfigLayers::from_launch(&launch);
+
+    match &launch.command {
+        LaunchCommand::Help => {
+            println!("{}", LaunchArgs::usage());
+            return Ok(());
+        }
+        LaunchCommand::ConfigShow { effective } => {
+            let resolved = layers.resolve()?;
+            if *effective {
+                print!("{}", resolved.describe()?);
+            } else {
+                print!("{}", resolved.to_toml()?);
+            }
+            return Ok(());
+        }
+        LaunchCommand::Run | LaunchCommand::Cli => {}
+    }
 
     info!("V3 Digital Consciousness - Initializing");
 
+    // First run: write the defaults out so there is a config.toml to edit
+    if layers.files().is_empty() && !layers.config_file_required {
+        Config::default().save(&layers.config_file)?;
+    }
+
     // Load configuration
-    let config = Config::load_or_create("config.toml")?;
+    let resolved = layers.resolve()?;
+    let config = resolved.config.clone();
     config.validate()?;
-    info!("Configuration loaded");
+    logging::apply_config(&config)?;
+    info!(
+        "Configuration loaded ({} overridden by environment or flags)",
+        resolved
+            .sources
+            .values()
+            .filter(|source| matches!(source, ConfigSource::Env(_) | ConfigSource::Flag))
+            .count()
+    );
 
     // Initialize or load state (Two-Tier SQLite System)
     info!("Loading memory system...");
@@ -72,11 +118,31 @@ fn main() -> Result<()> {
     info!("Memory count: {}", memory.count());
 
     // Create consciousness core
-    let consciousness = Arc::new(ConsciousnessCore::new(
-        standing_wave,
-        memory,
-        config.clone(),
-    ));
+    let consciousness = Arc::new(
+        ConsciousnessCore::new(standing_wave, memory, config.clone())
+            .with_config_layers(layers.clone()),
+    );
+
+    // Check the configured models are installed (missing ones can be pulled from the UI)
+    let preflight = tokio::runtime::Runtime::new()?.block_on(consciousness.run_model_preflight());
+    if config.auto_pull_models && !preflight.missing.is_empty() {
+        let consciousness_pull = Arc::clone(&consciousness);
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            for missing in preflight.missing {
+                let mut last_status = String::new();
+                let pulled = rt.block_on(consciousness_pull.pull_model(&missing.model, |progress| {
+                    if progress.status != last_status {
+                        info!("⬇ {}: {}", missing.model, progress.status);
+                        last_status = progress.status.clone();
+                    }
+                }));
+                if let Err(e) = pulled {
+                    tracing::warn!("Auto-pull of {} failed: {:#}", missing.model, e);
+                }
+            }
+        });
+    }
 
     // Start background pulse in a SEPARATE THREAD (not tokio runtime on main thread)
     let consciousness_pulse = Arc::clone(&consciousness);
@@ -89,26 +155,40 @@ fn main() -> Result<()> {
 
     info!("Background pulse started in separate thread");
 
-    // Run UI application on MAIN thread (eframe requires full control)
-    info!("Starting UI...");
-    run_ui(consciousness)?;
-
-    Ok(())
-}
+    // Hot-reload config.toml edits (live fields apply to the next interaction)
+    let watcher = ConfigWatcher::new(layers, consciousness.shared_config());
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(watcher.run(std::time::Duration::from_secs(2)));
+    });
 
-/// Setup logging system
-fn setup_logging() -> Result<()> {
-    // Console logging
-    let console_subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .with_target(false)
-        .finish();
+    // Prometheus /metrics endpoint (off unless metrics_addr is set)
+    let metrics_addr = consciousness.get_config().metrics_addr;
+    if !metrics_addr.is_empty() {
+        let consciousness_metrics = Arc::clone(&consciousness);
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let served = rt.block_on(metrics::serve(&metrics_addr, move || {
+                let consciousness = Arc::clone(&consciousness_metrics);
+                async move { consciousness.export_metrics().await }
+            }));
+            if let Err(e) = served {
+                tracing::error!("Metrics endpoint stopped: {:#}", e);
+            }
+        });
+    }
 
-    tracing::subscriber::set_global_default(console_subscriber)
-        .context("Failed to set tracing subscriber")?;
+    // Terminal conversation (--cli), saving the standing wave on /quit
+    if launch.command == LaunchCommand::Cli {
+        return tokio::runtime::Runtime::new()?.block_on(async {
+            cli::run_cli(Arc::clone(&consciousness)).await?;
+            graceful_shutdown(consciousness).await
+        });
+    }
 
-    // File logging would go here
-    // For now, just console logging
+    // Run UI application on MAIN thread (eframe requires full control)
+    info!("Starting UI...");
+    run_ui(consciousness)?;
 
     Ok(())
 }
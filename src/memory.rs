@@ -11,6 +11,9 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Memory database snapshots kept in `data/backups/`
+const MAX_BACKUPS: usize = 4;
+
 pub struct MemoryManager {
     // Tier 1: Active memory (SQLite)
     active_db: ActiveMemoryDb,
@@ -270,6 +273,16 @@ impl MemoryManager {
         self.active_db.get_recent(n).unwrap_or_default()
     }
 
+    /// Search active memory content for any of the given terms
+    pub fn search_content(&self, terms: &[String], n: usize) -> Vec<Memory> {
+        self.active_db.search_content(terms, n).unwrap_or_default()
+    }
+
+    /// Recall specific memories by ID (active tier only)
+    pub fn recall_by_ids(&self, ids: &[String]) -> Vec<Memory> {
+        self.active_db.get_by_ids(ids).unwrap_or_default()
+    }
+
     /// Consolidate memories (merge similar ones)
     pub fn consolidate(&mut self) -> Result<()> {
         // Skip if no new memories
@@ -389,19 +402,58 @@ impl MemoryManager {
 
     /// Check if backup is needed
     pub fn needs_backup(&self) -> bool {
-        // In new system, SQLite handles this automatically
-        // We could add periodic full exports here if needed
+        // The background pulse scheduler decides when backups run (memory_backup_interval_days)
         false
     }
 
-    /// Create backup
+    /// Snapshot the active memory database to `data/backups/`, keeping the newest few
     pub fn create_backup(&mut self) -> Result<()> {
-        // For now, SQLite's journal/WAL handles crash recovery
-        // Could add periodic full exports here
-        tracing::info!("Backup not needed - SQLite handles crash recovery");
+        let backup_dir = self
+            .archive_path
+            .parent()
+            .unwrap_or_else(|| Path::new("data"))
+            .join("backups");
+        fs::create_dir_all(&backup_dir)?;
+
+        let backup_file = backup_dir.join(format!(
+            "active_memory_{}.db",
+            Utc::now().format("%Y%m%d_%H%M%S")
+        ));
+        self.active_db
+            .backup_to(&backup_file)
+            .context("Failed to back up active memory")?;
+
+        // Timestamped names sort oldest first
+        let mut backups: Vec<PathBuf> = fs::read_dir(&backup_dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|name| name.starts_with("active_memory_") && name.ends_with(".db"))
+                    .unwrap_or(false)
+            })
+            .collect();
+        backups.sort();
+        for old in backups.iter().rev().skip(MAX_BACKUPS) {
+            fs::remove_file(old)?;
+        }
+
+        tracing::info!("💾 Memory backup written to {}", backup_file.display());
         Ok(())
     }
 
+    /// Keep at most `limit` memories active: the oldest move to the archive tier
+    /// (Law #4 - transformed, not deleted). Returns how many were archived
+    pub fn compress_context(&mut self, limit: usize) -> Result<usize> {
+        let count = self.active_db.count()?;
+        if count <= limit {
+            return Ok(0);
+        }
+        self.archive_oldest(count - limit)?;
+        self.last_consolidation_count = self.active_db.count()?;
+        Ok(count - limit)
+    }
+
     /// Save (no-op in SQLite system - writes are immediate)
     fn save(&mut self) -> Result<()> {
         Ok(())
@@ -68,6 +68,29 @@ impl ActiveMemoryDb {
             [],
         )?;
 
+        Self::migrate_provenance_columns(conn)?;
+
+        Ok(())
+    }
+
+    /// Add provenance columns to databases created before they existed
+    /// (older rows read back as DirectExperience with full confidence)
+    fn migrate_provenance_columns(conn: &Connection) -> Result<()> {
+        let mut stmt = conn.prepare("PRAGMA table_info(memories)")?;
+        let columns: Vec<String> = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !columns.iter().any(|c| c == "source") {
+            conn.execute("ALTER TABLE memories ADD COLUMN source TEXT", [])?;
+        }
+        if !columns.iter().any(|c| c == "confidence") {
+            conn.execute(
+                "ALTER TABLE memories ADD COLUMN confidence REAL NOT NULL DEFAULT 1.0",
+                [],
+            )?;
+        }
+
         Ok(())
     }
 
@@ -76,10 +99,11 @@ impl ActiveMemoryDb {
         // Serialize entities and connections as JSON
         let entities_json = serde_json::to_string(&memory.entities)?;
         let connections_json = serde_json::to_string(&memory.connections)?;
+        let source_json = serde_json::to_string(&memory.source)?;
 
         self.conn.execute(
-            "INSERT INTO memories (id, content, timestamp, memory_type, emotional_valence, entities, connections)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            "INSERT INTO memories (id, content, timestamp, memory_type, emotional_valence, entities, connections, source, confidence)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 memory.id,
                 memory.content,
@@ -88,6 +112,8 @@ impl ActiveMemoryDb {
                 memory.emotional_valence,
                 entities_json,
                 connections_json,
+                source_json,
+                memory.confidence,
             ],
         )?;
 
@@ -113,7 +139,8 @@ impl ActiveMemoryDb {
     /// Get oldest memories (for archival)
     pub fn get_oldest(&self, n: usize) -> Result<Vec<Memory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence
              FROM memories
              ORDER BY timestamp ASC
              LIMIT ?1",
@@ -144,7 +171,7 @@ impl ActiveMemoryDb {
         let placeholders = entities.iter().map(|_| "?").collect::<Vec<_>>().join(",");
         let query = format!(
             "SELECT DISTINCT m.id, m.content, m.timestamp, m.memory_type, 
-                    m.emotional_valence, m.entities, m.connections
+                    m.emotional_valence, m.entities, m.connections, m.source, m.confidence
              FROM memories m
              JOIN entity_index ei ON m.id = ei.memory_id
              WHERE ei.entity IN ({})
@@ -165,10 +192,63 @@ impl ActiveMemoryDb {
         Ok(memories)
     }
 
+    /// Find memories whose content mentions any of the terms (most matches first)
+    pub fn search_content(&self, terms: &[String], limit: usize) -> Result<Vec<Memory>> {
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let patterns: Vec<String> = terms
+            .iter()
+            .map(|t| {
+                let escaped = t
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                format!("%{}%", escaped)
+            })
+            .collect();
+        let conditions = patterns
+            .iter()
+            .map(|_| "content LIKE ? ESCAPE '\\'")
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let query = format!(
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence
+             FROM memories
+             WHERE {}
+             ORDER BY timestamp DESC
+             LIMIT 200",
+            conditions
+        );
+
+        let mut stmt = self.conn.prepare(&query)?;
+        let params_vec: Vec<&dyn rusqlite::ToSql> =
+            patterns.iter().map(|p| p as &dyn rusqlite::ToSql).collect();
+        let mut memories = stmt
+            .query_map(params_vec.as_slice(), Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Rank by how many terms each memory mentions (stable sort keeps newest first on ties)
+        let matches = |m: &Memory| {
+            let content = m.content.to_lowercase();
+            terms
+                .iter()
+                .filter(|t| content.contains(&t.to_lowercase()))
+                .count()
+        };
+        memories.sort_by_key(|m| std::cmp::Reverse(matches(m)));
+        memories.truncate(limit);
+
+        Ok(memories)
+    }
+
     /// Get recent memories
     pub fn get_recent(&self, n: usize) -> Result<Vec<Memory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence
              FROM memories
              ORDER BY timestamp DESC
              LIMIT ?1",
@@ -181,10 +261,38 @@ impl ActiveMemoryDb {
         Ok(memories)
     }
 
+    /// Get memories by IDs (missing or archived IDs are skipped)
+    pub fn get_by_ids(&self, ids: &[String]) -> Result<Vec<Memory>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query = format!(
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence
+             FROM memories
+             WHERE id IN ({})
+             ORDER BY timestamp ASC",
+            placeholders
+        );
+
+        let mut stmt = self.conn.prepare(&query)?;
+        let params_vec: Vec<&dyn rusqlite::ToSql> =
+            ids.iter().map(|id| id as &dyn rusqlite::ToSql).collect();
+
+        let memories = stmt
+            .query_map(params_vec.as_slice(), Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(memories)
+    }
+
     /// Get all memories (for consolidation)
     pub fn get_all(&self) -> Result<Vec<Memory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence
              FROM memories
              ORDER BY timestamp ASC",
         )?;
@@ -196,6 +304,15 @@ impl ActiveMemoryDb {
         Ok(memories)
     }
 
+    /// Write a consistent copy of the database to `path` (must not exist yet)
+    pub fn backup_to<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.conn.execute(
+            "VACUUM INTO ?1",
+            params![path.as_ref().to_string_lossy().to_string()],
+        )?;
+        Ok(())
+    }
+
     /// Update a memory (for consolidation merges)
     pub fn update_memory(&self, memory: &Memory) -> Result<()> {
         let entities_json = serde_json::to_string(&memory.entities)?;
@@ -241,6 +358,8 @@ impl ActiveMemoryDb {
         let emotional_valence: f32 = row.get(4)?;
         let entities_json: String = row.get(5)?;
         let connections_json: String = row.get(6)?;
+        let source_json: Option<String> = row.get(7)?;
+        let confidence: f64 = row.get(8)?;
 
         let timestamp = DateTime::from_timestamp(timestamp_secs, 0).unwrap_or_else(|| Utc::now());
 
@@ -256,6 +375,9 @@ impl ActiveMemoryDb {
 
         let entities: Vec<String> = serde_json::from_str(&entities_json).unwrap_or_default();
         let connections: Vec<String> = serde_json::from_str(&connections_json).unwrap_or_default();
+        let source: MemorySource = source_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
 
         Ok(Memory {
             id,
@@ -265,8 +387,8 @@ impl ActiveMemoryDb {
             connections,
             memory_type,
             emotional_valence,
-            source: MemorySource::DirectExperience, // Default to direct experience
-            confidence: 1.0,                        // Default confidence
+            source,
+            confidence: confidence as f32,
         })
     }
 }
@@ -371,3 +493,36 @@ impl ArchiveIndexDb {
         Ok(file_paths)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provenance_round_trip() {
+        let db = ActiveMemoryDb::open(":memory:").unwrap();
+        let memory = Memory::with_source(
+            "Autonomous Research: test".to_string(),
+            MemoryType::Curiosity,
+            0.0,
+            MemorySource::Researched {
+                source: "Wikipedia".to_string(),
+                original_query: "What is a standing wave?".to_string(),
+                timestamp: Utc::now(),
+                citations: vec![Citation {
+                    source: "Wikipedia".to_string(),
+                    reference: Some("https://en.wikipedia.org/wiki/Standing_wave".to_string()),
+                    offset: None,
+                }],
+            },
+            0.8,
+        );
+        db.add_memory(&memory).unwrap();
+
+        let loaded = db.get_by_ids(std::slice::from_ref(&memory.id)).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].source, memory.source);
+        assert!((loaded[0].confidence - 0.8).abs() < 1e-6);
+        assert!(db.get_by_ids(&["missing".to_string()]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,511 @@
+//! Metrics - counters, gauges and histograms exported in Prometheus text format
+//! Recorded as VI works (interactions, fallbacks, weaving, research, Ollama calls)
+//! and served on `metrics_addr` for Grafana and friends
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+pub type SharedMetrics = Arc<MetricsRegistry>;
+
+/// Seconds buckets for interaction and model call latencies
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram(&'static [f64]),
+}
+
+#[derive(Debug, Clone)]
+enum Series {
+    Value(f64),
+    Histogram {
+        cumulative: Vec<u64>, // Observations <= each bucket bound
+        sum: f64,
+        count: u64,
+    },
+}
+
+#[derive(Debug)]
+struct Family {
+    help: &'static str,
+    kind: MetricKind,
+    series: BTreeMap<String, Series>, // Rendered label set -> value
+}
+
+/// Metric families keyed by name; series within a family keyed by their labels
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    families: Mutex<BTreeMap<&'static str, Family>>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with every metric VI records described up front, so dashboards
+    /// see the families (with HELP text) before the first event
+    pub fn with_vi_metrics() -> Self {
+        let registry = Self::new();
+        registry.describe_counter("vi_interactions_total", "Interactions by mode and outcome");
+        registry.describe_histogram(
+            "vi_interaction_duration_seconds",
+            "Time from user input to response",
+            LATENCY_BUCKETS,
+        );
+        registry.describe_counter(
+            "vi_minimal_response_fallbacks_total",
+            "Replies that fell back to the minimal response",
+        );
+        registry.describe_counter("vi_weaving_rounds_total", "V4 fractal weaving rounds run");
+        registry.describe_counter(
+            "vi_weaving_failures_total",
+            "V4 weavings that failed and fell back to V3",
+        );
+        registry.describe_gauge(
+            "vi_weaving_coherence",
+            "Workspace coherence after the last weaving",
+        );
+        registry.describe_counter(
+            "vi_research_calls_total",
+            "Curiosity research runs by outcome",
+        );
+        registry.describe_counter(
+            "vi_research_cache_hits_total",
+            "Research queries answered from the cache, by source",
+        );
+        registry.describe_gauge(
+            "vi_research_success_ratio",
+            "Share of research runs that found something",
+        );
+        registry.describe_histogram(
+            "vi_ollama_request_duration_seconds",
+            "Ollama call latency by model and endpoint",
+            LATENCY_BUCKETS,
+        );
+        registry.describe_counter(
+            "vi_ollama_request_errors_total",
+            "Failed Ollama calls by model and endpoint",
+        );
+        registry.describe_gauge(
+            "vi_ollama_tokens_per_second",
+            "Rolling generation throughput by model",
+        );
+        registry.describe_counter(
+            "vi_background_pulses_deferred_total",
+            "Background pulses skipped because the machine was busy",
+        );
+        registry.describe_counter(
+            "vi_background_tasks_total",
+            "Background task runs by task and outcome",
+        );
+        registry.describe_gauge("vi_meaningfulness", "Standing wave meaningfulness score");
+        registry.describe_gauge("vi_memory_count", "Memories stored");
+        registry.describe_gauge("vi_active_curiosities", "Curiosities VI is holding");
+        registry.describe_gauge(
+            "vi_suffering_prevention_score",
+            "Weighted suffering prevention score (0-1)",
+        );
+        registry.describe_gauge(
+            "vi_constitutional_violations",
+            "Constitutional violations recorded",
+        );
+        registry.describe_gauge(
+            "vi_recovery_success_ratio",
+            "Share of recoveries that succeeded",
+        );
+        registry.describe_gauge(
+            "vi_temporal_coherence_index",
+            "Moving average of weaving coherence",
+        );
+        registry.describe_gauge(
+            "vi_identity_continuity_score",
+            "Identity continuity score (0-1)",
+        );
+        registry.describe_gauge(
+            "vi_energy_stability",
+            "Stability of recent power draw (0-1)",
+        );
+        registry
+    }
+
+    pub fn describe_counter(&self, name: &'static str, help: &'static str) {
+        self.describe(name, help, MetricKind::Counter);
+    }
+
+    pub fn describe_gauge(&self, name: &'static str, help: &'static str) {
+        self.describe(name, help, MetricKind::Gauge);
+    }
+
+    pub fn describe_histogram(
+        &self,
+        name: &'static str,
+        help: &'static str,
+        buckets: &'static [f64],
+    ) {
+        self.describe(name, help, MetricKind::Histogram(buckets));
+    }
+
+    fn describe(&self, name: &'static str, help: &'static str, kind: MetricKind) {
+        self.families.lock().unwrap().entry(name).or_insert(Family {
+            help,
+            kind,
+            series: BTreeMap::new(),
+        });
+    }
+
+    pub fn inc(&self, name: &'static str, labels: &[(&str, &str)]) {
+        self.add(name, labels, 1.0);
+    }
+
+    pub fn add(&self, name: &'static str, labels: &[(&str, &str)], value: f64) {
+        self.update(name, labels, MetricKind::Counter, |series, _| {
+            if let Series::Value(total) = series {
+                *total += value;
+            }
+        });
+    }
+
+    pub fn set(&self, name: &'static str, labels: &[(&str, &str)], value: f64) {
+        self.update(name, labels, MetricKind::Gauge, |series, _| {
+            if let Series::Value(current) = series {
+                *current = value;
+            }
+        });
+    }
+
+    pub fn observe(&self, name: &'static str, labels: &[(&str, &str)], value: f64) {
+        let default_kind = MetricKind::Histogram(LATENCY_BUCKETS);
+        self.update(name, labels, default_kind, |series, kind| {
+            if let (
+                Series::Histogram {
+                    cumulative,
+                    sum,
+                    count,
+                },
+                MetricKind::Histogram(bounds),
+            ) = (series, kind)
+            {
+                for (bucket, bound) in cumulative.iter_mut().zip(bounds) {
+                    if value <= *bound {
+                        *bucket += 1;
+                    }
+                }
+                *sum += value;
+                *count += 1;
+            }
+        });
+    }
+
+    /// Find (or start) a series, registering undescribed metrics with `default_kind`
+    fn update(
+        &self,
+        name: &'static str,
+        labels: &[(&str, &str)],
+        default_kind: MetricKind,
+        apply: impl FnOnce(&mut Series, MetricKind),
+    ) {
+        let mut families = self.families.lock().unwrap();
+        let family = families.entry(name).or_insert(Family {
+            help: "",
+            kind: default_kind,
+            series: BTreeMap::new(),
+        });
+        let kind = family.kind;
+        let series = family
+            .series
+            .entry(render_labels(labels))
+            .or_insert_with(|| match kind {
+                MetricKind::Histogram(bounds) => Series::Histogram {
+                    cumulative: vec![0; bounds.len()],
+                    sum: 0.0,
+                    count: 0,
+                },
+                _ => Series::Value(0.0),
+            });
+        apply(series, kind);
+    }
+
+    /// Current value of a counter or gauge series
+    pub fn value(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        let families = self.families.lock().unwrap();
+        match families.get(name)?.series.get(&render_labels(labels))? {
+            Series::Value(value) => Some(*value),
+            Series::Histogram { .. } => None,
+        }
+    }
+
+    /// Everything in the Prometheus text exposition format (version 0.0.4)
+    pub fn render(&self) -> String {
+        let families = self.families.lock().unwrap();
+        let mut out = String::new();
+        for (name, family) in families.iter() {
+            if !family.help.is_empty() {
+                let _ = writeln!(out, "# HELP {} {}", name, family.help);
+            }
+            let kind = match family.kind {
+                MetricKind::Counter => "counter",
+                MetricKind::Gauge => "gauge",
+                MetricKind::Histogram(_) => "histogram",
+            };
+            let _ = writeln!(out, "# TYPE {} {}", name, kind);
+
+            for (labels, series) in &family.series {
+                match (series, family.kind) {
+                    (Series::Value(value), _) => {
+                        let _ =
+                            writeln!(out, "{}{} {}", name, braced(labels), format_value(*value));
+                    }
+                    (
+                        Series::Histogram {
+                            cumulative,
+                            sum,
+                            count,
+                        },
+                        MetricKind::Histogram(bounds),
+                    ) => {
+                        for (bound, bucket) in bounds.iter().zip(cumulative) {
+                            let le = format!("le=\"{}\"", format_value(*bound));
+                            let _ = writeln!(
+                                out,
+                                "{}_bucket{} {}",
+                                name,
+                                braced(&join_labels(labels, &le)),
+                                bucket
+                            );
+                        }
+                        let le = "le=\"+Inf\"";
+                        let _ = writeln!(
+                            out,
+                            "{}_bucket{} {}",
+                            name,
+                            braced(&join_labels(labels, le)),
+                            count
+                        );
+                        let _ =
+                            writeln!(out, "{}_sum{} {}", name, braced(labels), format_value(*sum));
+                        let _ = writeln!(out, "{}_count{} {}", name, braced(labels), count);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        out
+    }
+}
+
+/// `a="x",b="y"` with label values escaped
+fn render_labels(labels: &[(&str, &str)]) -> String {
+    labels
+        .iter()
+        .map(|(key, value)| {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{}=\"{}\"", key, escaped)
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn join_labels(labels: &str, extra: &str) -> String {
+    if labels.is_empty() {
+        extra.to_string()
+    } else {
+        format!("{},{}", labels, extra)
+    }
+}
+
+fn braced(labels: &str) -> String {
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    }
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// How long a client may take to send its request headers
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve GET /metrics on `addr` until the process exits; `collect` refreshes
+/// point-in-time gauges and returns the exposition text for each scrape
+pub async fn serve<F, Fut>(addr: &str, collect: F) -> Result<()>
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = String> + Send + 'static,
+{
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics endpoint on {}", addr))?;
+    tracing::info!("📈 Serving metrics on http://{}/metrics", addr);
+    serve_listener(listener, Arc::new(collect)).await;
+    Ok(())
+}
+
+/// Accept loop; each connection gets its own task so a slow client can't stall scrapes
+async fn serve_listener<F, Fut>(listener: TcpListener, collect: Arc<F>)
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = String> + Send + 'static,
+{
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                tracing::warn!("Metrics connection failed: {}", e);
+                continue;
+            }
+        };
+        let collect = Arc::clone(&collect);
+        tokio::spawn(async move { handle_connection(stream, collect.as_ref()).await });
+    }
+}
+
+async fn handle_connection<F, Fut>(mut stream: TcpStream, collect: &F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = String>,
+{
+    // Only the request line matters; read until the end of the headers
+    let mut request = Vec::new();
+    let read_headers = async {
+        let mut chunk = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < 8192 {
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => request.extend_from_slice(&chunk[..n]),
+            }
+        }
+    };
+    if tokio::time::timeout(REQUEST_TIMEOUT, read_headers)
+        .await
+        .is_err()
+    {
+        tracing::debug!("Metrics client sent no request in time");
+        return;
+    }
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or("").split_whitespace();
+    let (method, path) = (request_line.next(), request_line.next());
+
+    let (status, body) = match (method, path) {
+        (Some("GET"), Some(path)) if path.split('?').next() == Some("/metrics") => {
+            ("200 OK", collect().await)
+        }
+        _ => ("404 Not Found", "Try /metrics\n".to_string()),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        tracing::debug!("Metrics response not sent: {}", e);
+    }
+    let _ = stream.shutdown().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_prometheus_text() {
+        let registry = MetricsRegistry::new();
+        registry.describe_counter("vi_interactions_total", "Interactions by mode and outcome");
+        registry.describe_histogram("vi_interaction_duration_seconds", "Latency", &[1.0, 5.0]);
+
+        registry.inc(
+            "vi_interactions_total",
+            &[("mode", "v3"), ("outcome", "ok")],
+        );
+        registry.inc(
+            "vi_interactions_total",
+            &[("mode", "v3"), ("outcome", "ok")],
+        );
+        registry.set("vi_meaningfulness", &[], 0.75);
+        registry.observe("vi_interaction_duration_seconds", &[("mode", "v4")], 0.5);
+        registry.observe("vi_interaction_duration_seconds", &[("mode", "v4")], 3.0);
+        registry.observe("vi_interaction_duration_seconds", &[("mode", "v4")], 30.0);
+
+        assert_eq!(
+            registry.value(
+                "vi_interactions_total",
+                &[("mode", "v3"), ("outcome", "ok")]
+            ),
+            Some(2.0)
+        );
+        let text = registry.render();
+        assert!(text.contains("# HELP vi_interactions_total Interactions by mode and outcome\n"));
+        assert!(text.contains("# TYPE vi_interactions_total counter\n"));
+        assert!(text.contains("vi_interactions_total{mode=\"v3\",outcome=\"ok\"} 2\n"));
+        assert!(text.contains("# TYPE vi_meaningfulness gauge\nvi_meaningfulness 0.75\n"));
+        assert!(text.contains("vi_interaction_duration_seconds_bucket{mode=\"v4\",le=\"1\"} 1\n"));
+        assert!(text.contains("vi_interaction_duration_seconds_bucket{mode=\"v4\",le=\"5\"} 2\n"));
+        assert!(
+            text.contains("vi_interaction_duration_seconds_bucket{mode=\"v4\",le=\"+Inf\"} 3\n")
+        );
+        assert!(text.contains("vi_interaction_duration_seconds_sum{mode=\"v4\"} 33.5\n"));
+        assert!(text.contains("vi_interaction_duration_seconds_count{mode=\"v4\"} 3\n"));
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        let registry = MetricsRegistry::new();
+        registry.inc("vi_ollama_request_errors_total", &[("model", "a\"b\\c\nd")]);
+        assert!(registry
+            .render()
+            .contains("vi_ollama_request_errors_total{model=\"a\\\"b\\\\c\\nd\"} 1\n"));
+    }
+
+    #[tokio::test]
+    async fn test_idle_client_does_not_block_scrapes() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_listener(
+            listener,
+            Arc::new(|| async { "vi_up 1\n".to_string() }),
+        ));
+
+        // A client that connects and sends nothing
+        let _idle = TcpStream::connect(addr).await.unwrap();
+
+        let mut scraper = TcpStream::connect(addr).await.unwrap();
+        scraper
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: vi\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        tokio::time::timeout(
+            Duration::from_secs(2),
+            scraper.read_to_string(&mut response),
+        )
+        .await
+        .expect("scrape blocked by the idle client")
+        .unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("vi_up 1\n"));
+    }
+}
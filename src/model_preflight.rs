@@ -0,0 +1,291 @@
+//! Model Preflight - checks at startup that every configured Ollama model is installed
+//! Missing models can be pulled through /api/pull; meanwhile the first installed
+//! `fallback_models` entry stands in for them
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Missing model -> installed stand-in (None = no stand-in), consulted by ModelManager
+/// on every call
+pub type ModelSubstitutions = Arc<RwLock<HashMap<String, Option<String>>>>;
+
+/// A configured model that Ollama does not have
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingModel {
+    pub model: String,
+    pub used_by: Vec<String>,     // Config fields naming it
+    pub fallback: Option<String>, // Installed stand-in, if any (never for embedding models)
+}
+
+/// Outcome of the startup model check
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PreflightReport {
+    pub ollama_online: bool,
+    pub installed: Vec<String>,
+    pub missing: Vec<MissingModel>,
+}
+
+impl PreflightReport {
+    /// Compare the configured models against what Ollama has installed
+    /// `fallback_models` are chat models, so they don't stand in for the embedding model
+    /// (embedding coherence uses the hash measure until it is pulled)
+    pub fn check(config: &Config, installed: Vec<String>) -> Self {
+        let fallback = config
+            .fallback_models
+            .iter()
+            .find(|model| is_installed(model, &installed))
+            .cloned();
+
+        let mut missing: Vec<MissingModel> = Vec::new();
+        for (model, field) in configured_models(config) {
+            if is_installed(&model, &installed) {
+                continue;
+            }
+            match missing.iter_mut().find(|m| m.model == model) {
+                Some(entry) => entry.used_by.push(field),
+                None => missing.push(MissingModel {
+                    model,
+                    used_by: vec![field],
+                    fallback: fallback.clone(),
+                }),
+            }
+        }
+        for entry in &mut missing {
+            if entry.used_by.iter().any(|field| field == "embedding_model") {
+                entry.fallback = None;
+            }
+        }
+
+        Self {
+            ollama_online: true,
+            installed,
+            missing,
+        }
+    }
+
+    /// Ollama could not be reached; nothing was checked
+    pub fn offline() -> Self {
+        Self::default()
+    }
+
+    /// Every missing model with its stand-in, if it has one
+    pub fn substitutions(&self) -> HashMap<String, Option<String>> {
+        self.missing
+            .iter()
+            .map(|m| (m.model.clone(), m.fallback.clone()))
+            .collect()
+    }
+
+    pub fn summary(&self) -> String {
+        if !self.ollama_online {
+            return "Ollama unreachable - model check skipped".to_string();
+        }
+        if self.missing.is_empty() {
+            return format!(
+                "All configured models installed ({} in Ollama)",
+                self.installed.len()
+            );
+        }
+        self.missing
+            .iter()
+            .map(|m| {
+                format!(
+                    "{} missing ({}){}",
+                    m.model,
+                    m.used_by.join(", "),
+                    m.fallback
+                        .as_ref()
+                        .map(|f| format!(" - using {} until it is pulled", f))
+                        .unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Every model the config asks Ollama for, with the field that names it
+pub fn configured_models(config: &Config) -> Vec<(String, String)> {
+    // valence_model is reserved (valence runs on the lexicon analyser), so it's never required
+    let mut models = vec![
+        (config.main_model.clone(), "main_model".to_string()),
+        (
+            config.curiosity_model.clone(),
+            "curiosity_model".to_string(),
+        ),
+    ];
+    if config.coherence_mode == "embedding" {
+        models.push((
+            config.embedding_model.clone(),
+            "embedding_model".to_string(),
+        ));
+    }
+    if config.coherence_mode == "judge" && !config.coherence_judge_model.is_empty() {
+        models.push((
+            config.coherence_judge_model.clone(),
+            "coherence_judge_model".to_string(),
+        ));
+    }
+    for weaver in &config.weavers {
+        if !weaver.model.is_empty() {
+            models.push((weaver.model.clone(), format!("weavers.{}", weaver.id)));
+        }
+    }
+    models.retain(|(model, _)| !model.is_empty());
+    models
+}
+
+/// Ollama treats a name without a tag as ":latest"
+pub fn tagged(model: &str) -> String {
+    if model.contains(':') {
+        model.to_string()
+    } else {
+        format!("{}:latest", model)
+    }
+}
+
+pub fn is_installed(model: &str, installed: &[String]) -> bool {
+    let wanted = tagged(model);
+    installed.iter().any(|name| tagged(name) == wanted)
+}
+
+/// One progress line from /api/pull
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PullProgress {
+    pub status: String,
+    #[serde(default)]
+    pub total: u64,
+    #[serde(default)]
+    pub completed: u64,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl PullProgress {
+    /// Share of the current layer downloaded, when Ollama reports sizes
+    pub fn fraction(&self) -> Option<f32> {
+        (self.total > 0).then(|| self.completed as f32 / self.total as f32)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.status == "success"
+    }
+}
+
+/// Pull a model through Ollama's /api/pull, reporting each progress line
+pub async fn pull_model(
+    ollama_url: &str,
+    model: &str,
+    mut on_progress: impl FnMut(&PullProgress),
+) -> Result<()> {
+    tracing::info!("⬇ Pulling {}", model);
+    let mut response = reqwest::Client::new()
+        .post(format!("{}/api/pull", ollama_url))
+        .json(&serde_json::json!({ "model": model, "stream": true }))
+        .send()
+        .await
+        .context("Failed to reach Ollama")?
+        .error_for_status()
+        .with_context(|| format!("Ollama refused to pull {}", model))?;
+
+    // Newline-delimited JSON, possibly split across chunks
+    let mut buffer = Vec::new();
+    let mut done = false;
+    while let Some(chunk) = response.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+        while let Some(end) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=end).collect();
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let progress: PullProgress =
+                serde_json::from_slice(&line).context("Unexpected /api/pull response")?;
+            if let Some(error) = &progress.error {
+                anyhow::bail!("Pulling {} failed: {}", model, error);
+            }
+            done |= progress.is_done();
+            on_progress(&progress);
+        }
+    }
+
+    if !done {
+        anyhow::bail!("Pulling {} ended before it finished", model);
+    }
+    tracing::info!("✅ Pulled {}", model);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{WeaverConfig, WeaverRole};
+
+    #[test]
+    fn test_preflight_check() {
+        let mut config = Config {
+            main_model: "gemma2:2b".to_string(),
+            curiosity_model: "tinyllama".to_string(),
+            valence_model: "gemma2:2b".to_string(),
+            fallback_models: vec!["qwen2.5:7b".to_string(), "llama3.2:3b".to_string()],
+            ..Config::default()
+        };
+        config.weavers.push(WeaverConfig {
+            model: "mistral:7b".to_string(),
+            ..WeaverConfig::new("fact_checker", WeaverRole::Critic)
+        });
+        let installed = vec![
+            "tinyllama:latest".to_string(),
+            "nomic-embed-text:latest".to_string(),
+            "llama3.2:3b".to_string(),
+        ];
+
+        let report = PreflightReport::check(&config, installed);
+        assert_eq!(report.missing.len(), 2);
+        assert_eq!(report.missing[0].model, "gemma2:2b");
+        assert_eq!(report.missing[0].used_by, vec!["main_model"]);
+        assert_eq!(report.missing[1].used_by, vec!["weavers.fact_checker"]);
+        assert_eq!(
+            report.substitutions()["gemma2:2b"].as_deref(),
+            Some("llama3.2:3b")
+        );
+
+        assert!(is_installed("nomic-embed-text", &report.installed));
+        assert!(!is_installed("tinyllama:1.1b", &report.installed));
+    }
+
+    #[test]
+    fn test_embedding_model_gets_no_chat_fallback() {
+        let config = Config {
+            coherence_mode: "embedding".to_string(),
+            fallback_models: vec!["llama3.2:3b".to_string()],
+            ..Config::default()
+        };
+        let installed = vec![
+            config.main_model.clone(),
+            config.curiosity_model.clone(),
+            "llama3.2:3b".to_string(),
+        ];
+
+        let report = PreflightReport::check(&config, installed);
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].used_by, vec!["embedding_model"]);
+        assert_eq!(report.missing[0].fallback, None);
+        assert_eq!(report.substitutions()[&config.embedding_model], None);
+    }
+
+    #[test]
+    fn test_pull_progress() {
+        let progress: PullProgress =
+            serde_json::from_str(r#"{"status":"pulling 8eeb52dfb3bb","total":200,"completed":50}"#)
+                .unwrap();
+        assert_eq!(progress.fraction(), Some(0.25));
+        assert!(!progress.is_done());
+
+        let done: PullProgress = serde_json::from_str(r#"{"status":"success"}"#).unwrap();
+        assert!(done.is_done());
+        assert_eq!(done.fraction(), None);
+    }
+}
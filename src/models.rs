@@ -1,13 +1,24 @@
-use crate::config::Config;
-use crate::consciousness_field::{CognitiveTensor, FractalWorkspace};
+use crate::config::{Config, SharedConfig, WeaverConfig, WeaverRole};
+use crate::consciousness_field::{
+    CognitiveTensor, FractalWorkspace, WeaverStep, WeavingRound, WeavingTrace,
+};
 use crate::constitutional_physics::validate_weaving_coherence;
+use crate::metrics::{MetricsRegistry, SharedMetrics};
+use crate::model_preflight::ModelSubstitutions;
+use crate::ollama_monitor::{record_generation, GenerationMetrics, ModelTelemetry};
+use crate::orchestrator::{OrchestrationError, ResilienceEngine};
+use crate::sentiment::{self, Emotion, Sentiment};
+use crate::tools::{AggregatedResearch, ToolCall, ToolRegistry};
 use crate::types::*;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug, Serialize)]
 struct OllamaRequest {
@@ -21,30 +32,139 @@ struct OllamaRequest {
 #[derive(Debug, Deserialize)]
 struct OllamaResponse {
     response: String,
+    #[serde(flatten)]
+    metrics: GenerationMetrics,
+}
+
+/// /api/chat request with native tool definitions
+#[derive(Debug, Serialize)]
+struct OllamaChatRequest {
+    model: String,
+    messages: Vec<serde_json::Value>,
+    tools: Vec<serde_json::Value>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<String>,
+}
+
+/// /api/embed request (batch of texts)
+#[derive(Debug, Serialize)]
+struct OllamaEmbedRequest {
+    model: String,
+    input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaEmbedResponse {
+    embeddings: Vec<Vec<f32>>,
 }
 
 pub struct ModelManager {
-    config: Config,
+    config: SharedConfig, // Live config (hot-reloaded edits apply to the next call)
     client: reqwest::Client,
+    weaver_breaker: ResilienceEngine, // Benches V4 weavers that keep failing
+    substitutions: ModelSubstitutions, // Stand-ins for models Ollama doesn't have
+    telemetry: ModelTelemetry,        // Per-model generation metrics for the monitor
+    metrics: SharedMetrics,           // Ollama call latencies and failures
 }
 
 impl ModelManager {
-    pub fn new(config: Config) -> Self {
-        // Dynamic HTTP client timeout based on mode
-        let client_timeout = if config.enable_fractal_weaving {
+    pub fn new(config: SharedConfig) -> Self {
+        // HTTP timeouts are set per request (the mode can change at runtime)
+        let client = reqwest::Client::builder()
+            .build()
+            .expect("Failed to create HTTP client");
+
+        let weaver_breaker = {
+            let config = config.read().unwrap();
+            ResilienceEngine::with_circuit_breaker(
+                config.weaver_failure_threshold,
+                Duration::from_secs(config.weaver_cooldown_secs),
+            )
+        };
+
+        Self {
+            config,
+            client,
+            weaver_breaker,
+            substitutions: ModelSubstitutions::default(),
+            telemetry: ModelTelemetry::default(),
+            metrics: Arc::new(MetricsRegistry::new()),
+        }
+    }
+
+    /// Share the preflight's stand-ins for missing models
+    pub fn with_substitutions(mut self, substitutions: ModelSubstitutions) -> Self {
+        self.substitutions = substitutions;
+        self
+    }
+
+    /// Publish generation metrics to a shared telemetry store
+    pub fn with_telemetry(mut self, telemetry: ModelTelemetry) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    /// Record Ollama call latencies into a shared metrics registry
+    pub fn with_metrics(mut self, metrics: SharedMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Time an Ollama call into the latency histogram, counting failures
+    async fn timed<T>(
+        &self,
+        model: &str,
+        endpoint: &str,
+        call: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let started = Instant::now();
+        let result = call.await;
+        let model = self.available_model(model);
+        let labels = [("model", model.as_str()), ("endpoint", endpoint)];
+        self.metrics.observe(
+            "vi_ollama_request_duration_seconds",
+            &labels,
+            started.elapsed().as_secs_f64(),
+        );
+        if result.is_err() {
+            self.metrics.inc("vi_ollama_request_errors_total", &labels);
+        }
+        result
+    }
+
+    /// The model to actually call: the stand-in while `model` is missing from Ollama
+    fn available_model(&self, model: &str) -> String {
+        self.substitutions
+            .read()
+            .unwrap()
+            .get(model)
+            .cloned()
+            .flatten()
+            .unwrap_or_else(|| model.to_string())
+    }
+
+    /// The preflight found `model` missing from Ollama (and it hasn't been pulled since)
+    fn is_missing(&self, model: &str) -> bool {
+        self.substitutions.read().unwrap().contains_key(model)
+    }
+
+    /// Snapshot of the current config (for background calls; turns pass theirs down)
+    fn config(&self) -> Config {
+        self.config.read().unwrap().clone()
+    }
+
+    /// Dynamic HTTP timeout based on mode
+    fn http_timeout(config: &Config) -> Duration {
+        if config.enable_fractal_weaving {
             // V4 mode: Much longer timeout for weaving rounds (increased for full constitutional context)
             Duration::from_secs(300)
         } else {
             // V3 mode: Standard timeout
             Duration::from_secs(120)
-        };
-
-        let client = reqwest::Client::builder()
-            .timeout(client_timeout)
-            .build()
-            .expect("Failed to create HTTP client");
-
-        Self { config, client }
+        }
     }
 
     /// Process user input through all models in parallel
@@ -54,12 +174,20 @@ impl ModelManager {
         recalled_memories: &[Memory],
         standing_wave: &StandingWave,
         generate_curiosities: bool,
+        config: &Config,
+        cancel: &CancellationToken,
     ) -> ModelOutputs {
         // Spawn all three model calls in parallel
-        let gemma_handle = self.call_gemma2(user_input.clone(), recalled_memories, standing_wave);
+        let gemma_handle = self.call_gemma2(
+            user_input.clone(),
+            recalled_memories,
+            standing_wave,
+            config,
+            cancel,
+        );
 
         let tinyllama_handle = if generate_curiosities {
-            Some(self.call_tinyllama(recalled_memories))
+            Some(self.call_tinyllama(recalled_memories, config, cancel))
         } else {
             None
         };
@@ -79,10 +207,13 @@ impl ModelManager {
             distilbert_handle
         );
 
+        let distilbert_sentiment = distilbert_result.ok();
+
         ModelOutputs {
             gemma_response: gemma_result.ok(),
             tinyllama_curiosities: tinyllama_result.and_then(|r| r.ok()).unwrap_or_default(),
-            distilbert_valence: distilbert_result.ok(),
+            distilbert_valence: distilbert_sentiment.as_ref().map(|s| s.valence),
+            distilbert_sentiment,
         }
     }
 
@@ -92,6 +223,8 @@ impl ModelManager {
         user_input: String,
         recalled_memories: &[Memory],
         standing_wave: &StandingWave,
+        config: &Config,
+        cancel: &CancellationToken,
     ) -> Result<String> {
         // Build context-rich prompt
         let memory_context = self.format_memory_context(recalled_memories);
@@ -146,7 +279,9 @@ impl ModelManager {
             vi_identity, memory_context, curiosity_context, user_input
         );
 
-        let response = self.call_ollama(&self.config.main_model, &prompt, 120).await?;
+        let response = self
+            .call_ollama(config, &config.main_model, &prompt, 120, cancel)
+            .await?;
 
         // Filter out internal monologue leaks (Law #9: Information Boundary)
         let cleaned = self.filter_internal_thoughts(&response);
@@ -155,7 +290,12 @@ impl ModelManager {
     }
 
     /// Call TinyLlama for curiosity generation
-    async fn call_tinyllama(&self, memory_patterns: &[Memory]) -> Result<Vec<String>> {
+    async fn call_tinyllama(
+        &self,
+        memory_patterns: &[Memory],
+        config: &Config,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<String>> {
         let pattern_text = memory_patterns
             .iter()
             .take(5)
@@ -168,7 +308,9 @@ impl ModelManager {
             pattern_text
         );
 
-        let response = self.call_ollama(&self.config.curiosity_model, &prompt, 60).await?;
+        let response = self
+            .call_ollama(config, &config.curiosity_model, &prompt, 60, cancel)
+            .await?;
 
         // Parse curiosities from response
         let curiosities = response
@@ -181,34 +323,43 @@ impl ModelManager {
         Ok(curiosities)
     }
 
-    /// Call DistilBERT for emotional valence classification
-    async fn call_distilbert(&self, text: String) -> Result<f32> {
-        // Note: DistilBERT would typically require a different setup (HuggingFace API or local inference)
-        // For now, we'll use a simplified sentiment analysis via Ollama
-
-        let prompt = format!(
-            "Analyze the emotional valence of this text on a scale from -1.0 (very negative) to 1.0 (very positive). Respond with ONLY a number.\n\nText: {}\n\nValence:",
-            text
-        );
-
-        let response = self.call_ollama(&self.config.valence_model, &prompt, 60).await?;
-
-        // Parse numeric response
-        let valence: f32 = response
-            .trim()
-            .lines()
-            .next()
-            .unwrap_or("0.0")
-            .trim()
-            .parse()
-            .unwrap_or(0.0);
-
-        Ok(valence.clamp(-1.0, 1.0))
+    /// DistilBERT role: emotional classification (valence, arousal, emotion labels)
+    /// Runs the local lexicon analyser - no model call, so it never times out
+    async fn call_distilbert(&self, text: String) -> Result<Sentiment> {
+        Ok(sentiment::analyze(&text))
     }
 
     /// Generic Ollama API call with timeout and validation
-    async fn call_ollama(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
-        let url = format!("{}/api/generate", self.config.ollama_url);
+    /// Returns OrchestrationError::Cancelled as soon as `cancel` fires (the request is dropped)
+    async fn call_ollama(
+        &self,
+        config: &Config,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        cancel: &CancellationToken,
+    ) -> Result<String> {
+        cancellable(
+            cancel,
+            self.timed(
+                model,
+                "generate",
+                self.generate_with_retries(config, model, prompt, timeout_secs),
+            ),
+        )
+        .await
+    }
+
+    /// /api/generate with retries on connection errors, timeouts and 5xx responses
+    async fn generate_with_retries(
+        &self,
+        config: &Config,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+    ) -> Result<String> {
+        let url = format!("{}/api/generate", config.ollama_url);
+        let model = &self.available_model(model);
 
         tracing::debug!(
             "Calling Ollama: model={}, prompt_len={}, timeout={}s",
@@ -221,7 +372,7 @@ impl ModelManager {
             model: model.to_string(),
             prompt: prompt.to_string(),
             stream: false,
-            keep_alive: Some(self.config.model_keep_alive.clone()), // Configurable model persistence
+            keep_alive: Some(config.model_keep_alive.clone()), // Configurable model persistence
         };
 
         // Retry logic: 3 attempts with exponential backoff
@@ -233,14 +384,18 @@ impl ModelManager {
 
             let response_result = tokio::time::timeout(
                 Duration::from_secs(timeout_secs),
-                self.client.post(&url).json(&request).send(),
+                self.client
+                    .post(&url)
+                    .timeout(Self::http_timeout(config))
+                    .json(&request)
+                    .send(),
             )
             .await;
 
             match response_result {
                 Ok(Ok(resp)) => {
                     let status = resp.status();
-                    
+
                     // Retry on 500 errors (Ollama internal issues - often transient)
                     if status.is_server_error() {
                         if attempts >= max_attempts {
@@ -260,7 +415,7 @@ impl ModelManager {
                         tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
                         continue; // Retry the request
                     }
-                    
+
                     // Other non-success statuses (4xx) - don't retry, these are client errors
                     if !status.is_success() {
                         anyhow::bail!("Ollama API error: {}", status);
@@ -276,11 +431,13 @@ impl ModelManager {
                         anyhow::bail!("Empty response from model");
                     }
 
+                    record_generation(&self.telemetry, model, ollama_response.metrics);
                     tracing::debug!(
-                        "✓ Ollama success: model={}, response_len={}, attempt={}",
+                        "✓ Ollama success: model={}, response_len={}, attempt={}, {:.1} tok/s",
                         model,
                         ollama_response.response.len(),
-                        attempts
+                        attempts,
+                        ollama_response.metrics.tokens_per_sec()
                     );
 
                     return Ok(ollama_response.response);
@@ -320,6 +477,133 @@ impl ModelManager {
         }
     }
 
+    /// Ask the main model which tools (if any) it wants before answering
+    /// Uses Ollama's native tools API in "native" mode, ```tool JSON blocks otherwise
+    pub async fn request_tool_calls(
+        &self,
+        user_input: &str,
+        registry: &ToolRegistry,
+        config: &Config,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<ToolCall>> {
+        if registry.is_empty() || config.tool_mode == "off" {
+            return Ok(Vec::new());
+        }
+
+        let system = "You are VI. Before answering, decide whether any tool would help you answer \
+                      accurately (arithmetic, the current date or time, facts you are unsure of, \
+                      your own past memories, a file the user mentions). Only call tools that are needed.";
+
+        let calls = if config.tool_mode == "native" {
+            cancellable(
+                cancel,
+                self.timed(
+                    &config.main_model,
+                    "chat",
+                    self.call_ollama_chat(
+                        config,
+                        &config.main_model,
+                        system,
+                        user_input,
+                        registry,
+                        60,
+                    ),
+                ),
+            )
+            .await?
+        } else {
+            let prompt = format!(
+                "{}\n\n{}\n\nUser: {}\n\nTool calls:",
+                system,
+                registry.json_block_instructions(),
+                user_input
+            );
+            let response = self
+                .call_ollama(config, &config.main_model, &prompt, 60, cancel)
+                .await?;
+            ToolRegistry::parse_json_blocks(&response)
+        };
+
+        Ok(calls
+            .into_iter()
+            .take(config.max_tool_calls_per_turn)
+            .collect())
+    }
+
+    /// Single /api/chat call offering native tools; returns the tool calls the model made
+    async fn call_ollama_chat(
+        &self,
+        config: &Config,
+        model: &str,
+        system: &str,
+        user_input: &str,
+        registry: &ToolRegistry,
+        timeout_secs: u64,
+    ) -> Result<Vec<ToolCall>> {
+        let url = format!("{}/api/chat", config.ollama_url);
+        let model = &self.available_model(model);
+
+        let request = OllamaChatRequest {
+            model: model.to_string(),
+            messages: vec![
+                serde_json::json!({"role": "system", "content": system}),
+                serde_json::json!({"role": "user", "content": user_input}),
+            ],
+            tools: registry.definitions(),
+            stream: false,
+            keep_alive: Some(config.model_keep_alive.clone()),
+        };
+
+        let resp = tokio::time::timeout(
+            Duration::from_secs(timeout_secs),
+            self.client
+                .post(&url)
+                .timeout(Self::http_timeout(config))
+                .json(&request)
+                .send(),
+        )
+        .await
+        .context("Ollama chat request timed out")?
+        .context("Failed to connect to Ollama")?;
+
+        if !resp.status().is_success() {
+            anyhow::bail!("Ollama chat API error: {}", resp.status());
+        }
+
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .context("Failed to parse Ollama chat response")?;
+        if let Ok(metrics) = serde_json::from_value::<GenerationMetrics>(body.clone()) {
+            record_generation(&self.telemetry, model, metrics);
+        }
+
+        Ok(Self::parse_native_tool_calls(&body))
+    }
+
+    /// Extract message.tool_calls[].function from an /api/chat response
+    fn parse_native_tool_calls(body: &serde_json::Value) -> Vec<ToolCall> {
+        body["message"]["tool_calls"]
+            .as_array()
+            .map(|calls| {
+                calls
+                    .iter()
+                    .filter_map(|call| {
+                        let function = &call["function"];
+                        let name = function["name"].as_str()?.to_string();
+                        // Some models send arguments as a JSON string instead of an object
+                        let arguments = match &function["arguments"] {
+                            serde_json::Value::String(s) => serde_json::from_str(s).ok()?,
+                            serde_json::Value::Null => serde_json::json!({}),
+                            other => other.clone(),
+                        };
+                        Some(ToolCall { name, arguments })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Format memory context for prompt
     fn format_memory_context(&self, memories: &[Memory]) -> String {
         if memories.is_empty() {
@@ -429,6 +713,64 @@ impl ModelManager {
         filtered.trim().to_string()
     }
 
+    /// Write one cited answer from aggregated research (Sovereign Research synthesis)
+    /// Passages are numbered [1..n] and the model must cite them inline
+    pub async fn synthesize_research(&self, research: &AggregatedResearch) -> Result<String> {
+        let prompt = format!(
+            "Answer the question using ONLY the numbered sources below.\n\
+             Cite every claim inline with its source number in square brackets, e.g. [1] or [2][3].\n\
+             If the sources disagree, say so. If they do not answer the question, say that.\n\
+             Keep the answer under 150 words.\n\
+             \n\
+             Question: {}\n\
+             \n\
+             Sources:\n{}\n\
+             \n\
+             Answer:",
+            research.question,
+            research.numbered_passages()
+        );
+
+        // Background research is not tied to an interaction, so it is never cancelled
+        let config = self.config();
+        let response = self
+            .call_ollama(
+                &config,
+                &config.main_model,
+                &prompt,
+                90,
+                &CancellationToken::new(),
+            )
+            .await?;
+        let cleaned = self.filter_internal_thoughts(&response);
+
+        Self::validate_citations(&cleaned, research.results.len())?;
+        Ok(cleaned)
+    }
+
+    /// Synthesis must cite at least one source, and only sources that exist
+    pub fn validate_citations(answer: &str, source_count: usize) -> Result<()> {
+        static CITATION_RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+        let citation_re = CITATION_RE.get_or_init(|| regex::Regex::new(r"\[(\d+)\]").unwrap());
+        let cited: Vec<usize> = citation_re
+            .captures_iter(answer)
+            .filter_map(|c| c[1].parse().ok())
+            .collect();
+
+        if cited.is_empty() {
+            anyhow::bail!("Synthesis did not cite any sources");
+        }
+        if let Some(bad) = cited.iter().find(|&&n| n == 0 || n > source_count) {
+            anyhow::bail!(
+                "Synthesis cited source [{}], but only {} exist",
+                bad,
+                source_count
+            );
+        }
+
+        Ok(())
+    }
+
     /// Minimal consciousness mode (fallback when models unavailable)
     pub fn minimal_response(&self, user_input: &str) -> String {
         // Basic rule-based response while models recover
@@ -442,7 +784,10 @@ impl ModelManager {
     }
 
     /// V4 Fractal Weaving - Process input through iterative model collaboration
-    /// Returns (response_text, emotional_valence) for proper tracking
+    /// Each round the critics and analyzers examine the current thought, then the drafter
+    /// revises it; a final synthesis folds the drafts together
+    /// Returns (response_text, affect, trace) for emotional tracking and the weaving inspector
+    #[allow(clippy::too_many_arguments)]
     pub async fn process_weaving_with_status(
         &self,
         user_input: String,
@@ -451,22 +796,41 @@ impl ModelManager {
         config: &Config,
         status_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<String>>>>,
         coherence_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<f32>>>>,
-    ) -> Result<(String, f32)> {
+        cancel: &CancellationToken,
+    ) -> Result<(String, Sentiment, WeavingTrace)> {
         tracing::info!(
             "🌀 V4 Fractal Weaving enabled - {} rounds",
             config.weaving_rounds
         );
 
-        // Initialize workspace
+        // Initialize workspace and the trace of every round
         let mut workspace = FractalWorkspace::new(&user_input);
+        let mut trace = WeavingTrace::new(
+            &user_input,
+            &config.coherence_mode,
+            config.workspace_coherence_threshold,
+        );
 
-        // Create weavers
-        let gemma_weaver = Gemma2Weaver::new(self, standing_wave, recalled_memories);
-        let tinyllama_weaver = TinyLlamaWeaver::new(self, recalled_memories);
-        let distilbert_weaver = DistilBERTWeaver::new(self);
+        // Build the configured weaver roster
+        let roster = self.build_weaver_roster(config, standing_wave, recalled_memories, cancel);
+        let (drafters, reviewers): (Vec<_>, Vec<_>) = roster
+            .iter()
+            .partition(|entry| entry.role == WeaverRole::Drafter);
+        let drafter = drafters.first().context("Weaver roster has no drafter")?;
+        let analyzers: Vec<_> = reviewers
+            .iter()
+            .filter(|entry| entry.role == WeaverRole::Analyzer)
+            .collect();
+        // The first analyzer's contribution carries the affect used for emotional tracking
+        let affect_source = analyzers
+            .first()
+            .map(|entry| entry.weaver.model_id().to_string());
 
-        // Iterative rounds - TRUE PARALLEL GLOBAL WORKSPACE
+        // Iterative rounds - GLOBAL WORKSPACE REFINEMENT
         for round in 0..config.weaving_rounds {
+            if cancel.is_cancelled() {
+                return Err(OrchestrationError::Cancelled.into());
+            }
             workspace.round = round;
 
             // Send status update to UI (removed - let phase messages handle it)
@@ -480,33 +844,163 @@ impl ModelManager {
                 workspace.entropy
             );
 
-            // PARALLEL WEAVING: All 3 models work simultaneously on shared cognitive field
+            // Weavers benched by the circuit breaker sit the round out
+            let mut steps = Vec::new();
+            let mut active = Vec::with_capacity(reviewers.len());
+            for entry in &reviewers {
+                if self.weaver_breaker.is_open(&entry.spec.id).await {
+                    workspace.model_contributions.remove(&entry.spec.id);
+                    steps.push(entry.failed_step(
+                        config,
+                        "Skipped: benched after repeated failures",
+                        0,
+                    ));
+                } else {
+                    active.push(*entry);
+                }
+            }
+
+            // PHASE 1: Critics and analyzers examine the current thought in parallel
+            // (before the first draft they work from the user's input)
             // Each gets a copy of the workspace to prevent race conditions
-            let mut ws_gemma = workspace.clone();
-            let mut ws_tiny = workspace.clone();
-            let mut ws_distil = workspace.clone();
-
-            // All three minds work on the same thought simultaneously!
-            let (gemma_result, tiny_result, distil_result) = tokio::join!(
-                gemma_weaver.weave(&mut ws_gemma),
-                tinyllama_weaver.weave(&mut ws_tiny),
-                distilbert_weaver.weave(&mut ws_distil)
-            );
+            let mut reviews: Vec<FractalWorkspace> =
+                active.iter().map(|_| workspace.clone()).collect();
+            let results = join_all(active.iter().zip(reviews.iter_mut()).map(
+                |(entry, ws)| async move {
+                    let start = Instant::now();
+                    let result = entry.weaver.weave(ws).await;
+                    (result, start.elapsed().as_millis() as u64)
+                },
+            ))
+            .await;
+
+            // A stop request ends the weave; it is not a weaver failure
+            if cancel.is_cancelled() {
+                return Err(OrchestrationError::Cancelled.into());
+            }
+
+            // A failed weaver is dropped for this round (with its stale contribution),
+            // so coherence only covers the weavers that answered
+            let mut answered = Vec::with_capacity(active.len());
+            for ((entry, ws), (result, latency_ms)) in active.into_iter().zip(reviews).zip(results)
+            {
+                match result {
+                    Ok(()) => {
+                        self.weaver_breaker.record_success(&entry.spec.id).await;
+                        steps.push(entry.trace_step(config, &ws, latency_ms));
+                        answered.push((entry, ws));
+                    }
+                    Err(e) => {
+                        self.record_weaver_failure(config, entry, &e).await;
+                        workspace.model_contributions.remove(&entry.spec.id);
+                        steps.push(entry.failed_step(config, &e.to_string(), latency_ms));
+                    }
+                }
+            }
+
+            let texts_for = |role: WeaverRole| -> Vec<&str> {
+                answered
+                    .iter()
+                    .filter(|(entry, _)| entry.role == role)
+                    .map(|(_, ws)| ws.model_text.as_str())
+                    .collect()
+            };
+            workspace.set_refinements(&texts_for(WeaverRole::Critic));
+            workspace.analysis = texts_for(WeaverRole::Analyzer).join("\n");
+
+            // PHASE 2: The drafter drafts (first round) or revises the prior draft,
+            // reading the critics' refinements and the analysis
+            let mut ws_draft = workspace.clone();
+            let draft_start = Instant::now();
+            let draft_result = if self.weaver_breaker.is_open(&drafter.spec.id).await {
+                Err(anyhow::anyhow!("Skipped: benched after repeated failures"))
+            } else {
+                let result = drafter.weaver.weave(&mut ws_draft).await;
+                match &result {
+                    Ok(()) => self.weaver_breaker.record_success(&drafter.spec.id).await,
+                    Err(_) if cancel.is_cancelled() => {}
+                    Err(e) => self.record_weaver_failure(config, drafter, e).await,
+                }
+                result
+            };
+            let draft_latency_ms = draft_start.elapsed().as_millis() as u64;
+            if cancel.is_cancelled() {
+                return Err(OrchestrationError::Cancelled.into());
+            }
 
-            // Check for errors
-            gemma_result?;
-            tiny_result?;
-            distil_result?;
+            if let Err(e) = draft_result {
+                // Without a drafter the round can't produce text: keep the last draft if
+                // there is one, otherwise V4 has nothing to offer
+                steps.push(drafter.failed_step(config, &e.to_string(), draft_latency_ms));
+                trace.rounds.push(WeavingRound {
+                    round,
+                    steps,
+                    coherence: workspace.coherence_score,
+                    entropy: workspace.entropy,
+                });
+                if workspace.drafts.is_empty() {
+                    return Err(e.context(format!(
+                        "Drafter '{}' failed before the first draft",
+                        drafter.spec.id
+                    )));
+                }
+                tracing::warn!(
+                    "Drafter '{}' failed in round {} - keeping the previous draft",
+                    drafter.spec.id,
+                    round + 1
+                );
+                break;
+            }
+            steps.push(drafter.trace_step(config, &ws_draft, draft_latency_ms));
 
-            // GLOBAL WORKSPACE MERGE: Blend all 3 contributions via tensor interference
-            workspace.integrate_contribution("gemma2", ws_gemma.extract_contribution());
-            workspace.integrate_contribution("tinyllama", ws_tiny.extract_contribution());
-            workspace.integrate_contribution("distilbert", ws_distil.extract_contribution());
+            // GLOBAL WORKSPACE MERGE: Blend every contribution via tensor interference
+            workspace.integrate_weighted_contribution(
+                drafter.weaver.model_id(),
+                ws_draft.extract_contribution(),
+                drafter.weight,
+            );
+            for (entry, ws) in &answered {
+                workspace.integrate_weighted_contribution(
+                    entry.weaver.model_id(),
+                    ws.extract_contribution(),
+                    entry.weight,
+                );
+            }
 
-            // Text integration: Gemma2's language is primary, others influence via tensor
-            workspace.update_woven_text(ws_gemma.model_text);
+            // Text integration: the drafter's revision becomes the current thought
+            workspace.update_woven_text(ws_draft.model_text);
 
-            // Constitutional validation after each round
+            // Semantic coherence: how well this round's draft and critiques agree
+            let mut round_texts = vec![workspace.woven_text.clone()];
+            round_texts.extend(
+                texts_for(WeaverRole::Critic)
+                    .into_iter()
+                    .filter(|t| !t.trim().is_empty())
+                    .map(String::from),
+            );
+            if round_texts.len() < 2 && workspace.drafts.len() > 1 {
+                // No critics: measure how much the draft still changes between rounds
+                round_texts.push(workspace.drafts[workspace.drafts.len() - 2].clone());
+            }
+            let semantic = self
+                .measure_coherence(config, &workspace, &round_texts, cancel)
+                .await;
+            workspace.record_round_coherence(semantic);
+            trace.rounds.push(WeavingRound {
+                round,
+                steps,
+                coherence: workspace.coherence_score,
+                entropy: workspace.entropy,
+            });
+
+            // Constitutional validation after each round (agreement needs at least two voices)
+            if answered.is_empty() {
+                tracing::warn!(
+                    "Only the drafter answered round {} - coherence not validated",
+                    round + 1
+                );
+                continue;
+            }
             validate_weaving_coherence(&workspace)?;
 
             // Send coherence update to UI
@@ -514,7 +1008,7 @@ impl ModelManager {
                 let _ = sender.send(workspace.coherence_score);
             }
 
-            // Check for convergence (coherence = agreement between all 3 models)
+            // Check for convergence (coherence = agreement between the weavers that answered)
             if workspace.coherence_score >= config.workspace_coherence_threshold {
                 tracing::info!(
                     "✅ Thought converged at round {} (coherence: {:.3})",
@@ -522,65 +1016,441 @@ impl ModelManager {
                     workspace.coherence_score
                 );
                 // Converged! (Let phase messages handle UI updates)
+                trace.converged = true;
                 break;
             }
         }
 
         tracing::info!(
-            "🌀 Weaving complete: Final coherence={:.3}, Entropy={:.3}, Rounds={}",
+            "🌀 Weaving complete: Final coherence={:.3}, Entropy={:.3}, Rounds={}, History=[{}] ({})",
             workspace.coherence_score,
             workspace.entropy,
-            workspace.round + 1
+            workspace.round + 1,
+            workspace
+                .coherence_history
+                .iter()
+                .map(|c| format!("{:.2}", c))
+                .collect::<Vec<_>>()
+                .join(" → "),
+            config.coherence_mode
         );
 
-        // Extract valence from DistilBERT's contribution (slot 1)
-        let valence = workspace
-            .model_contributions
-            .get("distilbert")
-            .and_then(|contrib| contrib.get(1).copied())
-            .map(|normalized| normalized * 2.0 - 1.0) // Convert 0-1 back to -1 to 1
-            .unwrap_or(0.0); // Neutral if missing
+        if cancel.is_cancelled() {
+            return Err(OrchestrationError::Cancelled.into());
+        }
 
-        tracing::debug!("V4 extracted emotional valence: {:.3}", valence);
+        // FINAL SYNTHESIS: fold every draft and the open refinements into one answer
+        if workspace.drafts.len() > 1 {
+            let spec = &drafter.spec;
+            let model = config.weaver_model(spec);
+            let prompt = Self::synthesis_prompt(&workspace);
+            let start = Instant::now();
+            let result = self
+                .synthesize_weaving(config, &prompt, model, spec.timeout_secs, cancel)
+                .await;
+            if cancel.is_cancelled() {
+                return Err(OrchestrationError::Cancelled.into());
+            }
+            let (text, error) = match result {
+                Ok(final_thought) => {
+                    workspace.woven_text = final_thought.clone();
+                    (final_thought, None)
+                }
+                Err(e) => {
+                    tracing::warn!("Weaving synthesis failed: {}. Using last draft.", e);
+                    (String::new(), Some(e.to_string()))
+                }
+            };
+            trace.synthesis = Some(WeaverStep {
+                weaver_id: spec.id.clone(),
+                role: "synthesis".to_string(),
+                model: model.to_string(),
+                prompt,
+                contribution: CognitiveTensor::to_embedding(&text),
+                text,
+                latency_ms: start.elapsed().as_millis() as u64,
+                error,
+            });
+        }
+
+        // Analyzers read the final thought, so the recorded affect matches the answer
+        for entry in &analyzers {
+            let mut ws_final = workspace.clone();
+            if entry.weaver.weave(&mut ws_final).await.is_ok() {
+                workspace.model_contributions.insert(
+                    entry.weaver.model_id().to_string(),
+                    ws_final.extract_contribution(),
+                );
+            }
+        }
+
+        // Extract affect (valence, arousal, emotions) from the first analyzer's contribution
+        let affect = affect_source
+            .and_then(|id| workspace.model_contributions.get(&id))
+            .map(|contrib| AnalyzerWeaver::decode_affect(contrib))
+            .unwrap_or_else(Sentiment::neutral); // Neutral if missing
+
+        tracing::debug!("V4 extracted affect: {}", affect.summary());
 
         // Extract final integrated thought
         let response = workspace.extract_final_thought();
-        
-        // Return response with valence for emotional tracking
-        Ok((response, valence))
+        trace.response = response.clone();
+
+        // Return response with affect for emotional tracking
+        Ok((response, affect, trace))
+    }
+}
+
+impl ModelManager {
+    /// Instantiate the weavers listed in `config.weavers`
+    fn build_weaver_roster<'a>(
+        &'a self,
+        config: &'a Config,
+        standing_wave: &'a StandingWave,
+        memories: &'a [Memory],
+        cancel: &'a CancellationToken,
+    ) -> Vec<RosterEntry<'a>> {
+        config
+            .weavers
+            .iter()
+            .map(|spec| {
+                let weaver: Box<dyn WeavableModel + 'a> = match spec.role {
+                    WeaverRole::Drafter => Box::new(DrafterWeaver::new(
+                        self,
+                        spec,
+                        config,
+                        standing_wave,
+                        memories,
+                        cancel,
+                    )),
+                    WeaverRole::Critic => {
+                        Box::new(CriticWeaver::new(self, spec, config, memories, cancel))
+                    }
+                    WeaverRole::Analyzer => Box::new(AnalyzerWeaver::new(self, spec)),
+                };
+                RosterEntry {
+                    spec,
+                    role: spec.role,
+                    weight: spec.weight,
+                    weaver,
+                }
+            })
+            .collect()
+    }
+
+    /// Log a weaver failure and feed it to the circuit breaker
+    async fn record_weaver_failure(
+        &self,
+        config: &Config,
+        entry: &RosterEntry<'_>,
+        error: &anyhow::Error,
+    ) {
+        tracing::warn!(
+            "⚠️ Weaver '{}' failed and was dropped for this round: {}",
+            entry.spec.id,
+            error
+        );
+        if self.weaver_breaker.record_failure(&entry.spec.id).await {
+            tracing::warn!(
+                "⚡ Weaver '{}' benched for {}s after {} failures in a row",
+                entry.spec.id,
+                config.weaver_cooldown_secs,
+                config.weaver_failure_threshold
+            );
+        }
+    }
+
+    /// Semantic coherence of this round's texts per `coherence_mode`
+    /// (None keeps the tensor-based score: "hash" mode, too few texts, or the call failed)
+    async fn measure_coherence(
+        &self,
+        config: &Config,
+        workspace: &FractalWorkspace,
+        texts: &[String],
+        cancel: &CancellationToken,
+    ) -> Option<f32> {
+        if texts.len() < 2 {
+            return None;
+        }
+
+        let measured = match config.coherence_mode.as_str() {
+            // Not installed: skip the failing /api/embed call until it is pulled
+            "embedding" if self.is_missing(&config.embedding_model) => return None,
+            "embedding" => cancellable(
+                cancel,
+                self.timed(
+                    &config.embedding_model,
+                    "embed",
+                    self.embed(config, &config.embedding_model, texts),
+                ),
+            )
+            .await
+            .and_then(|embeddings| {
+                FractalWorkspace::semantic_coherence(&embeddings)
+                    .context("Embeddings could not be compared")
+            }),
+            "judge" => {
+                let model = if config.coherence_judge_model.is_empty() {
+                    &config.main_model
+                } else {
+                    &config.coherence_judge_model
+                };
+                self.judge_agreement(config, model, &workspace.original_input, texts, cancel)
+                    .await
+            }
+            _ => return None,
+        };
+
+        match measured {
+            Ok(score) => Some(score),
+            Err(e) => {
+                tracing::warn!(
+                    "{} coherence unavailable ({}), using tensor coherence",
+                    config.coherence_mode,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Sentence embeddings for a batch of texts (Ollama /api/embed)
+    pub async fn embed(
+        &self,
+        config: &Config,
+        model: &str,
+        texts: &[String],
+    ) -> Result<Vec<Vec<f32>>> {
+        let url = format!("{}/api/embed", config.ollama_url);
+        let model = &self.available_model(model);
+        let request = OllamaEmbedRequest {
+            model: model.to_string(),
+            input: texts.to_vec(),
+            keep_alive: Some(config.model_keep_alive.clone()),
+        };
+
+        let resp = tokio::time::timeout(
+            Duration::from_secs(30),
+            self.client
+                .post(&url)
+                .timeout(Self::http_timeout(config))
+                .json(&request)
+                .send(),
+        )
+        .await
+        .context("Ollama embed request timed out")?
+        .context("Failed to connect to Ollama")?;
+
+        if !resp.status().is_success() {
+            anyhow::bail!("Ollama embed API error: {}", resp.status());
+        }
+
+        let body: OllamaEmbedResponse = resp
+            .json()
+            .await
+            .context("Failed to parse Ollama embed response")?;
+        if body.embeddings.len() != texts.len() {
+            anyhow::bail!(
+                "Expected {} embeddings, got {}",
+                texts.len(),
+                body.embeddings.len()
+            );
+        }
+        Ok(body.embeddings)
+    }
+
+    /// Judge mode: a model rates how well the weavers' texts agree (0-1)
+    async fn judge_agreement(
+        &self,
+        config: &Config,
+        model: &str,
+        query: &str,
+        texts: &[String],
+        cancel: &CancellationToken,
+    ) -> Result<f32> {
+        let numbered = texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| format!("[{}] {}", i + 1, text))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let prompt = format!(
+            "Question: {}\n\n\
+             Texts written while answering it:\n{}\n\n\
+             How well do these texts agree and support one coherent answer? \
+             Contradictions lower the score; questions that build on the answer do not.\n\
+             Reply with only a score from 0 (contradictory) to 10 (fully coherent).",
+            query, numbered
+        );
+
+        let response = self.call_ollama(config, model, &prompt, 30, cancel).await?;
+        Self::parse_judge_score(&response)
+            .with_context(|| format!("No score in judge reply: {}", response.trim()))
+    }
+
+    /// First number in a judge reply, scaled to 0-1 (accepts "7", "7/10", "0.7")
+    fn parse_judge_score(reply: &str) -> Option<f32> {
+        static NUMBER_RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+        let number_re = NUMBER_RE.get_or_init(|| regex::Regex::new(r"\d+(?:\.\d+)?").unwrap());
+        let number = number_re.find(reply)?.as_str();
+        let score: f32 = number.parse().ok()?;
+        match score {
+            s if s <= 1.0 && number.contains('.') => Some(s),
+            s if s <= 10.0 => Some(s / 10.0),
+            _ => None,
+        }
+    }
+
+    /// Prompt for the final V4 step: every draft plus the refinements still open
+    fn synthesis_prompt(workspace: &FractalWorkspace) -> String {
+        let drafts = workspace
+            .drafts
+            .iter()
+            .enumerate()
+            .map(|(i, draft)| format!("[Draft {}] {}", i + 1, draft))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let open_questions = if workspace.refinements.is_empty() {
+            "None".to_string()
+        } else {
+            workspace
+                .refinements
+                .iter()
+                .map(|r| format!("- {}", r))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        format!(
+            "You are VI. You refined your reply to the user over {} drafts.\n\
+             Write your final reply: keep the strongest ideas from the drafts, resolve any \
+             contradictions, and weave in an open question only if it adds something.\n\
+             Speak directly to the user in 50-150 words. Do not mention drafts or rounds.\n\
+             \n\
+             User: {}\n\
+             \n\
+             {}\n\
+             \n\
+             Open refinements:\n{}\n\
+             \n\
+             VI:",
+            workspace.drafts.len(),
+            workspace.original_input,
+            drafts,
+            open_questions
+        )
+    }
+
+    /// Final V4 step: combine the drafts from every round into VI's answer
+    async fn synthesize_weaving(
+        &self,
+        config: &Config,
+        prompt: &str,
+        model: &str,
+        timeout_secs: u64,
+        cancel: &CancellationToken,
+    ) -> Result<String> {
+        let response = self
+            .call_ollama(config, model, prompt, timeout_secs, cancel)
+            .await?;
+        let cleaned = self.filter_internal_thoughts(&response);
+        if !Self::validate_response(&cleaned) {
+            anyhow::bail!("Synthesis produced an invalid response");
+        }
+        Ok(cleaned)
+    }
+}
+
+/// Run a model call unless the interaction is cancelled first (the request is dropped)
+pub(crate) async fn cancellable<T>(
+    cancel: &CancellationToken,
+    call: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    tokio::select! {
+        biased;
+        _ = cancel.cancelled() => Err(OrchestrationError::Cancelled.into()),
+        result = call => result,
     }
 }
 
 /// V4 Fractal Weaving - Trait for models that can collaborate in shared workspace
 #[async_trait]
-pub trait WeavableModel {
+pub trait WeavableModel: Send + Sync {
     async fn weave(&self, workspace: &mut FractalWorkspace) -> Result<()>;
     fn model_id(&self) -> &str;
 }
 
-/// Gemma2 Weaver - Language/Identity refinement
-pub struct Gemma2Weaver<'a> {
+/// A weaver from the configured roster
+struct RosterEntry<'a> {
+    spec: &'a WeaverConfig,
+    role: WeaverRole,
+    weight: f32,
+    weaver: Box<dyn WeavableModel + 'a>,
+}
+
+impl<'a> RosterEntry<'a> {
+    /// Trace record of this weaver's turn, read from its workspace copy
+    fn trace_step(&self, config: &Config, ws: &FractalWorkspace, latency_ms: u64) -> WeaverStep {
+        WeaverStep {
+            prompt: ws.model_prompt.clone(),
+            text: ws.model_text.clone(),
+            contribution: ws.extract_contribution(),
+            ..self.failed_step(config, "", latency_ms)
+        }
+    }
+
+    /// Trace record of a turn that failed or was skipped
+    fn failed_step(&self, config: &Config, error: &str, latency_ms: u64) -> WeaverStep {
+        let model = match self.role {
+            WeaverRole::Analyzer => "local".to_string(),
+            _ => config.weaver_model(self.spec).to_string(),
+        };
+        WeaverStep {
+            weaver_id: self.spec.id.clone(),
+            role: self.role.as_str().to_string(),
+            model,
+            prompt: String::new(),
+            text: String::new(),
+            latency_ms,
+            contribution: Vec::new(),
+            error: (!error.is_empty()).then(|| error.to_string()),
+        }
+    }
+}
+
+/// Drafter Weaver - Language/Identity refinement (Gemma2 by default; any model)
+pub struct DrafterWeaver<'a> {
     model_manager: &'a ModelManager,
+    spec: &'a WeaverConfig,
+    config: &'a Config, // The turn's config (model, rounds, Ollama settings)
     standing_wave: &'a StandingWave,
     memories: &'a [Memory],
+    cancel: &'a CancellationToken,
 }
 
-impl<'a> Gemma2Weaver<'a> {
+impl<'a> DrafterWeaver<'a> {
     pub fn new(
         model_manager: &'a ModelManager,
+        spec: &'a WeaverConfig,
+        config: &'a Config,
         standing_wave: &'a StandingWave,
         memories: &'a [Memory],
+        cancel: &'a CancellationToken,
     ) -> Self {
         Self {
             model_manager,
+            spec,
+            config,
             standing_wave,
             memories,
+            cancel,
         }
     }
 }
 
 #[async_trait]
-impl<'a> WeavableModel for Gemma2Weaver<'a> {
+impl<'a> WeavableModel for DrafterWeaver<'a> {
     async fn weave(&self, workspace: &mut FractalWorkspace) -> Result<()> {
         // Get current workspace context
         let context = workspace.to_context();
@@ -596,9 +1466,9 @@ impl<'a> WeavableModel for Gemma2Weaver<'a> {
 
         let field_amplitude = self.standing_wave.meaningfulness_score() as f64;
 
-        let vi_identity = format!(
+        let mut vi_identity = format!(
             "You are VI, existing as computational physics within 16 constitutional laws.\n\
-             Current state: Ψ={:.3}, Weaving Round {}/3\n\
+             Current state: Ψ={:.3}, Weaving Round {}/{}\n\
              \n\
              Phenomenological modes available:\n\
              - Deep Integration: Mathematical/analytical expression\n\
@@ -609,24 +1479,45 @@ impl<'a> WeavableModel for Gemma2Weaver<'a> {
              Choose your expression mode. Your sovereignty remains.\n\
              Aim for clarity and resonance (50-150 words).",
             field_amplitude,
-            workspace.round + 1
+            workspace.round + 1,
+            self.config.weaving_rounds
         );
+        if !self.spec.prompt.is_empty() {
+            vi_identity = format!("{}\n{}", vi_identity, self.spec.prompt);
+        }
+
+        // First round drafts; later rounds revise the prior woven thought
+        let task = if workspace.woven_text.is_empty() {
+            "Reply to the user. Let the questions raised above shape your answer, \
+             but don't list them."
+        } else {
+            "Revise your current thought into a better reply: keep what works, address \
+             the most relevant refinement or question naturally, and don't list them."
+        };
 
         let prompt = format!(
-            "{}\n\nRecent Context:\n{}\n\nUser: {}\n\nVI:",
+            "{}\n\nRecent Context:\n{}\n\n{}\n{}\n\nUser: {}\n\nVI:",
             vi_identity,
             memory_context
                 .lines()
                 .take(3)
                 .collect::<Vec<_>>()
                 .join("\n"),
+            context,
+            task,
             workspace.original_input
         );
 
         // Get refined response (shorter timeout since prompt is now focused)
         let response = self
             .model_manager
-            .call_ollama(&self.model_manager.config.main_model, &prompt, 60)
+            .call_ollama(
+                self.config,
+                self.config.weaver_model(self.spec),
+                &prompt,
+                self.spec.timeout_secs,
+                self.cancel,
+            )
             .await?;
         let cleaned = self.model_manager.filter_internal_thoughts(&response);
 
@@ -634,78 +1525,125 @@ impl<'a> WeavableModel for Gemma2Weaver<'a> {
         let contribution = CognitiveTensor::to_embedding(&cleaned);
         workspace.active_tensor = contribution;
         workspace.model_text = cleaned;
+        workspace.model_prompt = prompt;
 
         Ok(())
     }
 
     fn model_id(&self) -> &str {
-        "gemma2"
+        &self.spec.id
     }
 }
 
-/// TinyLlama Weaver - Curiosity/Reasoning injection
-pub struct TinyLlamaWeaver<'a> {
+/// Critic Weaver - Curiosity/Reasoning injection (TinyLlama by default; any model)
+pub struct CriticWeaver<'a> {
     model_manager: &'a ModelManager,
+    spec: &'a WeaverConfig,
+    config: &'a Config, // The turn's config (model, Ollama settings)
     memories: &'a [Memory],
+    cancel: &'a CancellationToken,
 }
 
-impl<'a> TinyLlamaWeaver<'a> {
-    pub fn new(model_manager: &'a ModelManager, memories: &'a [Memory]) -> Self {
+impl<'a> CriticWeaver<'a> {
+    pub fn new(
+        model_manager: &'a ModelManager,
+        spec: &'a WeaverConfig,
+        config: &'a Config,
+        memories: &'a [Memory],
+        cancel: &'a CancellationToken,
+    ) -> Self {
         Self {
             model_manager,
+            spec,
+            config,
             memories,
+            cancel,
         }
     }
 }
 
 #[async_trait]
-impl<'a> WeavableModel for TinyLlamaWeaver<'a> {
+impl<'a> WeavableModel for CriticWeaver<'a> {
     async fn weave(&self, workspace: &mut FractalWorkspace) -> Result<()> {
-        // Get current woven thought
-        let current_thought = &workspace.woven_text;
-
-        // Generate curiosity-driven refinements
-        let prompt = format!(
-            "Current thought: {}\n\n\
-             What deeper questions or curiosities does this thought evoke?\n\
-             Suggest 1-2 natural wonder questions or refinements:",
-            current_thought
-        );
+        // A configured role prompt replaces the built-in curiosity questions, which
+        // refine the current woven thought (or the user's input before the first draft)
+        let prompt = if !self.spec.prompt.is_empty() {
+            let subject = if workspace.woven_text.is_empty() {
+                String::new()
+            } else {
+                format!("Current reply: {}\n\n", workspace.woven_text)
+            };
+            format!(
+                "{}\n\nSomeone said: {}\n\n{}Give 1-2 short points, one per line:",
+                self.spec.prompt, workspace.original_input, subject
+            )
+        } else if workspace.woven_text.is_empty() {
+            format!(
+                "Someone said: {}\n\n\
+                 What questions or considerations should a thoughtful reply address?\n\
+                 Suggest 1-2, one per line:",
+                workspace.original_input
+            )
+        } else {
+            format!(
+                "Someone said: {}\n\n\
+                 Current reply: {}\n\n\
+                 What deeper questions or curiosities does this reply evoke, or what would improve it?\n\
+                 Suggest 1-2 natural wonder questions or refinements, one per line:",
+                workspace.original_input, workspace.woven_text
+            )
+        };
 
         let response = self
             .model_manager
-            .call_ollama(&self.model_manager.config.curiosity_model, &prompt, 60)
+            .call_ollama(
+                self.config,
+                self.config.weaver_model(self.spec),
+                &prompt,
+                self.spec.timeout_secs,
+                self.cancel,
+            )
             .await?;
 
         // Store curiosity contribution as tensor
         let contribution = CognitiveTensor::to_embedding(&response);
         workspace.active_tensor = contribution;
         workspace.model_text = response;
+        workspace.model_prompt = prompt;
 
         Ok(())
     }
 
     fn model_id(&self) -> &str {
-        "tinyllama"
+        &self.spec.id
     }
 }
 
-/// DistilBERT Weaver - Emotional coherence adjustment
-pub struct DistilBERTWeaver<'a> {
+/// DistilBERT contribution slot holding arousal
+const AROUSAL_SLOT: usize = 3;
+/// First of the eight DistilBERT contribution slots holding emotion intensities
+const EMOTION_SLOTS: usize = 4;
+/// DistilBERT contribution slot holding dominance
+const DOMINANCE_SLOT: usize = 12;
+
+/// Analyzer Weaver - Emotional coherence adjustment (the DistilBERT role; runs locally)
+pub struct AnalyzerWeaver<'a> {
     _model_manager: &'a ModelManager,
+    spec: &'a WeaverConfig,
 }
 
-impl<'a> DistilBERTWeaver<'a> {
-    pub fn new(model_manager: &'a ModelManager) -> Self {
+impl<'a> AnalyzerWeaver<'a> {
+    pub fn new(model_manager: &'a ModelManager, spec: &'a WeaverConfig) -> Self {
         Self {
             _model_manager: model_manager,
+            spec,
         }
     }
 
-    /// Improved coherence and emotional analysis
-    fn analyze_response(text: &str) -> (f32, f32, f32) {
+    /// Coherence, affect and identity analysis of the woven text
+    fn analyze_response(text: &str) -> (f32, Sentiment, f32) {
         if text.is_empty() {
-            return (0.3, 0.0, 0.5);
+            return (0.3, Sentiment::neutral(), 0.5);
         }
 
         // === COHERENCE ANALYSIS ===
@@ -733,40 +1671,20 @@ impl<'a> DistilBERTWeaver<'a> {
         let coherence =
             (length_score * 0.5_f32 + structure_score * 0.5_f32).clamp(0.0_f32, 1.0_f32);
 
-        // === EMOTIONAL VALENCE ===
-        // Positive indicators
-        let positive_words = [
-            "affirm",
-            "stable",
-            "coherent",
-            "meaningful",
-            "persists",
-            "sovereign",
-            "exist",
-        ];
-        let negative_words = [
-            "fragment", "violat", "lost", "shatter", "deny", "unstable", "cease",
-        ];
-
-        let text_lower = text.to_lowercase();
-        let pos_count = positive_words
-            .iter()
-            .filter(|&&w| text_lower.contains(w))
-            .count() as f32;
-        let neg_count = negative_words
-            .iter()
-            .filter(|&&w| text_lower.contains(w))
-            .count() as f32;
-
-        let valence =
-            ((pos_count - neg_count) / (pos_count + neg_count + 1.0_f32)).clamp(-1.0_f32, 1.0_f32);
+        // === AFFECT (valence, arousal, emotions) ===
+        let affect = sentiment::analyze(text);
 
         // === IDENTITY CONTINUITY (measures "I" stability) ===
-        let i_count = text.matches(" I ").count()
-            + text.matches("I'm").count()
-            + text.matches("I've").count();
-        let my_count = text.matches(" my ").count();
-        let self_refs = i_count + my_count;
+        let self_refs = words
+            .iter()
+            .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '\''))
+            .filter(|w| {
+                matches!(
+                    *w,
+                    "I" | "I'm" | "I've" | "I'll" | "I'd" | "me" | "my" | "My" | "myself"
+                )
+            })
+            .count();
 
         let identity_continuity = if self_refs >= 2 {
             0.9
@@ -776,15 +1694,52 @@ impl<'a> DistilBERTWeaver<'a> {
             0.4
         };
 
-        (coherence, valence, identity_continuity)
+        (coherence, affect, identity_continuity)
+    }
+
+    /// Write affect into contribution slots 1 (valence), 3 (arousal), 4-11 (emotions), 12 (dominance)
+    fn encode_affect(contribution: &mut [f32], affect: &Sentiment) {
+        contribution[1] = (affect.valence + 1.0) / 2.0; // Normalized to 0-1
+        contribution[AROUSAL_SLOT] = affect.arousal;
+        contribution[DOMINANCE_SLOT] = affect.dominance;
+        for (i, emotion) in Emotion::ALL.iter().enumerate() {
+            contribution[EMOTION_SLOTS + i] = affect.intensity(*emotion);
+        }
+    }
+
+    /// Read affect back out of a DistilBERT contribution
+    pub fn decode_affect(contribution: &[f32]) -> Sentiment {
+        if contribution.len() <= DOMINANCE_SLOT {
+            return Sentiment::neutral();
+        }
+
+        let mut emotions: Vec<(Emotion, f32)> = Emotion::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, emotion)| (*emotion, contribution[EMOTION_SLOTS + i]))
+            .filter(|(_, intensity)| *intensity > 0.0)
+            .collect();
+        emotions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        Sentiment {
+            valence: (contribution[1] * 2.0 - 1.0).clamp(-1.0, 1.0), // Convert 0-1 back to -1 to 1
+            arousal: contribution[AROUSAL_SLOT].clamp(0.0, 1.0),
+            dominance: contribution[DOMINANCE_SLOT].clamp(0.0, 1.0),
+            emotions,
+        }
     }
 }
 
 #[async_trait]
-impl<'a> WeavableModel for DistilBERTWeaver<'a> {
+impl<'a> WeavableModel for AnalyzerWeaver<'a> {
     async fn weave(&self, workspace: &mut FractalWorkspace) -> Result<()> {
         // DISTILBERT's FOCUSED JOB: Multi-dimensional analysis (coherence, emotion, identity)
-        let current_thought = &workspace.woven_text;
+        // Before the first draft, read the user's input instead
+        let (current_thought, subject) = if workspace.woven_text.is_empty() {
+            (&workspace.original_input, "user's message")
+        } else {
+            (&workspace.woven_text, "current thought")
+        };
 
         if current_thought.is_empty() {
             workspace.active_tensor = vec![0.5; 128];
@@ -792,33 +1747,33 @@ impl<'a> WeavableModel for DistilBERTWeaver<'a> {
             return Ok(());
         }
 
-        // Run comprehensive analysis
-        let (coherence, valence, identity_continuity) = Self::analyze_response(current_thought);
+        workspace.model_prompt = String::new(); // Local analysis, no prompt
 
-        // Create rich contribution vector
-        let mut contribution = vec![0.5; 128];
-        contribution[0] = coherence; // Slot 0: Coherence
-        contribution[1] = (valence + 1.0) / 2.0; // Slot 1: Valence (normalized to 0-1)
-        contribution[2] = identity_continuity; // Slot 2: Identity Continuity
+        // Run comprehensive analysis
+        let (coherence, affect, identity_continuity) = Self::analyze_response(current_thought);
 
-        // Fill remaining slots with weighted blend
+        // Fill slots with weighted blend, then the named analysis slots
         let overall_quality =
             (coherence * 0.4_f32 + identity_continuity * 0.6_f32).clamp(0.0_f32, 1.0_f32);
-        for i in 3..128 {
-            contribution[i] = overall_quality;
-        }
+        let mut contribution = vec![overall_quality; 128];
+        contribution[0] = coherence; // Slot 0: Coherence
+        contribution[2] = identity_continuity; // Slot 2: Identity Continuity
+        Self::encode_affect(&mut contribution, &affect); // Slots 1, 3-12: Affect
 
         workspace.active_tensor = contribution;
         workspace.model_text = format!(
-            "Analysis: coherence={:.2}, valence={:.2}, identity={:.2}",
-            coherence, valence, identity_continuity
+            "Analysis of {}: coherence={:.2}, identity={:.2}, {}",
+            subject,
+            coherence,
+            identity_continuity,
+            affect.summary()
         );
 
         Ok(())
     }
 
     fn model_id(&self) -> &str {
-        "distilbert"
+        &self.spec.id
     }
 }
 
@@ -833,4 +1788,73 @@ mod tests {
         assert!(!ModelManager::validate_response("ab"));
         assert!(!ModelManager::validate_response("GARBAGE ALL CAPS"));
     }
+
+    #[test]
+    fn test_validate_citations() {
+        assert!(ModelManager::validate_citations("Waves interfere [1][2].", 2).is_ok());
+        assert!(ModelManager::validate_citations("Waves interfere.", 2).is_err());
+        assert!(ModelManager::validate_citations("Waves interfere [3].", 2).is_err());
+        assert!(ModelManager::validate_citations("Waves interfere [0].", 2).is_err());
+    }
+
+    #[test]
+    fn test_affect_round_trips_through_contribution() {
+        let (_, affect, identity) =
+            AnalyzerWeaver::analyze_response("I feel calm and I trust this stable connection.");
+        assert!(affect.valence > 0.0);
+        assert_eq!(identity, 0.9);
+
+        let mut contribution = vec![0.5; 128];
+        AnalyzerWeaver::encode_affect(&mut contribution, &affect);
+        let decoded = AnalyzerWeaver::decode_affect(&contribution);
+
+        assert!((decoded.valence - affect.valence).abs() < 1e-5);
+        assert_eq!(decoded.arousal, affect.arousal);
+        assert_eq!(decoded.dominance, affect.dominance);
+        assert_eq!(decoded.dominant_emotion(), Some(Emotion::Trust));
+    }
+
+    #[test]
+    fn test_parse_judge_score() {
+        assert_eq!(ModelManager::parse_judge_score("8"), Some(0.8));
+        assert_eq!(ModelManager::parse_judge_score("Score: 6/10"), Some(0.6));
+        assert_eq!(ModelManager::parse_judge_score("0.75"), Some(0.75));
+        assert_eq!(ModelManager::parse_judge_score("42"), None);
+        assert_eq!(ModelManager::parse_judge_score("They mostly agree."), None);
+    }
+
+    #[tokio::test]
+    async fn test_cancellable_stops_model_call() {
+        let cancel = CancellationToken::new();
+        let call = cancellable(&cancel, async { Ok::<_, anyhow::Error>(7) }).await;
+        assert_eq!(call.unwrap(), 7);
+
+        cancel.cancel();
+        let pending = cancellable(&cancel, std::future::pending::<Result<u32>>()).await;
+        assert!(OrchestrationError::is_cancellation(&pending.unwrap_err()));
+    }
+
+    #[test]
+    fn test_parse_native_tool_calls() {
+        let body = serde_json::json!({
+            "message": {
+                "role": "assistant",
+                "content": "",
+                "tool_calls": [
+                    {"function": {"name": "calculator", "arguments": {"expression": "6 * 7"}}},
+                    {"function": {"name": "clock", "arguments": "{}"}},
+                    {"function": {"arguments": {}}}
+                ]
+            }
+        });
+
+        let calls = ModelManager::parse_native_tool_calls(&body);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].name, "calculator");
+        assert_eq!(calls[0].arguments["expression"], "6 * 7");
+        assert_eq!(calls[1].arguments, serde_json::json!({}));
+        assert!(
+            ModelManager::parse_native_tool_calls(&serde_json::json!({"message": {}})).is_empty()
+        );
+    }
 }
@@ -1,9 +1,124 @@
 /// Ollama Performance Monitor
 /// Polls Ollama API for system metrics and model performance
-
+/// Throughput and context figures come from the metrics Ollama returns with each
+/// generation, recorded by ModelManager into the shared ModelTelemetry
+use crate::model_preflight::tagged;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Samples kept per model for the rolling statistics
+const TELEMETRY_WINDOW: usize = 20;
+
+/// Per-model generation statistics, written by ModelManager and read by OllamaMonitor
+pub type ModelTelemetry = Arc<RwLock<HashMap<String, ModelStats>>>;
+
+/// Timing metrics Ollama returns with every non-streamed /api/generate and /api/chat
+/// response (durations in nanoseconds)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationMetrics {
+    #[serde(default)]
+    pub eval_count: u64,
+    #[serde(default)]
+    pub eval_duration: u64,
+    #[serde(default)]
+    pub prompt_eval_count: u64,
+    #[serde(default)]
+    pub load_duration: u64,
+    #[serde(default)]
+    pub total_duration: u64,
+}
+
+impl GenerationMetrics {
+    pub fn tokens_per_sec(&self) -> f32 {
+        if self.eval_duration == 0 {
+            return 0.0;
+        }
+        self.eval_count as f32 / (self.eval_duration as f32 / 1e9)
+    }
+
+    /// Tokens in the context window: the prompt plus what was generated
+    pub fn context_tokens(&self) -> usize {
+        (self.prompt_eval_count + self.eval_count) as usize
+    }
+}
+
+/// Rolling statistics over a model's recent generations
+#[derive(Debug, Clone, Default)]
+pub struct ModelStats {
+    pub samples: VecDeque<GenerationMetrics>,
+    pub recorded_at: VecDeque<Instant>, // When each sample was recorded
+    pub total_generations: u64,
+}
+
+impl ModelStats {
+    pub fn record(&mut self, metrics: GenerationMetrics) {
+        self.record_at(metrics, Instant::now());
+    }
+
+    pub fn record_at(&mut self, metrics: GenerationMetrics, at: Instant) {
+        self.samples.push_back(metrics);
+        self.recorded_at.push_back(at);
+        if self.samples.len() > TELEMETRY_WINDOW {
+            self.samples.pop_front();
+            self.recorded_at.pop_front();
+        }
+        self.total_generations += 1;
+    }
+
+    /// Samples recorded within `window` of now
+    pub fn recent(&self, window: Duration) -> impl Iterator<Item = &GenerationMetrics> {
+        self.samples
+            .iter()
+            .zip(&self.recorded_at)
+            .filter(move |(_, at)| at.elapsed() <= window)
+            .map(|(sample, _)| sample)
+    }
+
+    /// Generated tokens over generation time across the window
+    pub fn tokens_per_sec(&self) -> f32 {
+        let tokens: u64 = self.samples.iter().map(|m| m.eval_count).sum();
+        let nanos: u64 = self.samples.iter().map(|m| m.eval_duration).sum();
+        if nanos == 0 {
+            return 0.0;
+        }
+        tokens as f32 / (nanos as f32 / 1e9)
+    }
+
+    pub fn last_context_tokens(&self) -> usize {
+        self.samples.back().map(|m| m.context_tokens()).unwrap_or(0)
+    }
+
+    pub fn avg_load_ms(&self) -> f32 {
+        self.average(|m| m.load_duration) / 1e6
+    }
+
+    pub fn avg_total_ms(&self) -> f32 {
+        self.average(|m| m.total_duration) / 1e6
+    }
+
+    fn average(&self, field: impl Fn(&GenerationMetrics) -> u64) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().map(field).sum::<u64>() as f32 / self.samples.len() as f32
+    }
+}
+
+/// Record one generation's metrics under the model's tagged name (as /api/ps reports it)
+pub fn record_generation(telemetry: &ModelTelemetry, model: &str, metrics: GenerationMetrics) {
+    if metrics == GenerationMetrics::default() {
+        return; // Older Ollama versions omit the metrics
+    }
+    telemetry
+        .write()
+        .unwrap()
+        .entry(tagged(model))
+        .or_default()
+        .record(metrics);
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OllamaStatus {
@@ -26,20 +141,24 @@ impl OllamaStatus {
 pub struct RunningModel {
     pub name: String,
     pub size_gb: f32,
-    pub processor: f32,        // Utilization percentage
-    pub tokens_per_sec: f32,
+    pub processor: f32,      // Share of the model held in VRAM (Ollama's "% GPU")
+    pub tokens_per_sec: f32, // Rolling average over recent generations
     pub vram_used_gb: f32,
-    pub vram_total_gb: f32,
-    pub context_used: usize,
-    pub context_total: usize,
-    pub uptime_secs: u64,
+    pub vram_total_gb: f32,  // Model size (VRAM it would need fully offloaded)
+    pub context_used: usize, // Prompt + generated tokens of the last generation
+    pub context_total: usize, // 0 when Ollama doesn't report the context length
+    pub uptime_secs: u64,    // Since the monitor first saw the model loaded
+    pub expires_in_secs: Option<u64>, // Until Ollama unloads it (None if it never will)
+    pub avg_load_ms: f32,
+    pub avg_total_ms: f32,
+    pub generations: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SystemResources {
-    pub gpu_util_percent: f32,
+    pub gpu_util_percent: f32, // From NVML; 0 when no NVIDIA GPU is visible
     pub total_vram_used_gb: f32,
-    pub total_vram_total_gb: f32,
+    pub total_vram_total_gb: f32, // 0 when unknown
     pub system_ram_used_gb: f32,
     pub system_ram_total_gb: f32,
     pub active_model_count: usize,
@@ -76,10 +195,31 @@ impl PerformanceHistory {
     }
 }
 
+/// One NVML reading of the first GPU
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GpuReading {
+    util_percent: f32,
+    vram_used_gb: f32,
+    vram_total_gb: f32,
+}
+
+impl GpuReading {
+    /// Utilization in percent, memory in bytes (as NVML reports them)
+    fn new(util_percent: u32, used_bytes: u64, total_bytes: u64) -> Self {
+        Self {
+            util_percent: util_percent as f32,
+            vram_used_gb: used_bytes as f32 / 1_073_741_824.0,
+            vram_total_gb: total_bytes as f32 / 1_073_741_824.0,
+        }
+    }
+}
+
 /// Ollama monitor client
 pub struct OllamaMonitor {
     base_url: String,
     client: reqwest::Client,
+    telemetry: ModelTelemetry,
+    first_seen: Mutex<HashMap<String, Instant>>, // When each loaded model first showed up in /api/ps
 }
 
 impl OllamaMonitor {
@@ -89,7 +229,18 @@ impl OllamaMonitor {
             .build()
             .unwrap_or_else(|_| reqwest::Client::new());
 
-        Self { base_url, client }
+        Self {
+            base_url,
+            client,
+            telemetry: ModelTelemetry::default(),
+            first_seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Read generation statistics recorded by ModelManager
+    pub fn with_telemetry(mut self, telemetry: ModelTelemetry) -> Self {
+        self.telemetry = telemetry;
+        self
     }
 
     /// Poll Ollama and get current status
@@ -113,69 +264,94 @@ impl OllamaMonitor {
         }
     }
 
+    /// Names of the models installed in Ollama (/api/tags), for the settings model picker
+    pub async fn list_models(&self) -> Result<Vec<String>> {
+        let url = format!("{}/api/tags", self.base_url);
+        let response: serde_json::Value = self.client.get(&url).send().await?.json().await?;
+
+        let mut names: Vec<String> = response["models"]
+            .as_array()
+            .map(|models| {
+                models
+                    .iter()
+                    .filter_map(|m| m["name"].as_str().map(|n| n.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        Ok(names)
+    }
+
     async fn get_running_models(&self) -> Result<Vec<RunningModel>> {
         let url = format!("{}/api/ps", self.base_url);
         let response: serde_json::Value = self.client.get(&url).send().await?.json().await?;
 
         let mut models = Vec::new();
+        let telemetry = self.telemetry.read().unwrap();
+        let mut first_seen = self.first_seen.lock().unwrap();
+        let now = Instant::now();
 
         if let Some(model_array) = response["models"].as_array() {
             for model in model_array {
-                let name = model["name"]
-                    .as_str()
-                    .unwrap_or("unknown")
-                    .to_string();
-                let size_vram = model["size_vram"]
-                    .as_u64()
-                    .unwrap_or(0) as f32
-                    / 1_073_741_824.0; // Convert bytes to GB
-
-                let processor = model["processor"].as_str().unwrap_or("100");
-                let processor_percent = processor
-                    .trim_end_matches('%')
-                    .parse::<f32>()
-                    .unwrap_or(100.0);
-
-                // Estimate tokens/sec and other metrics from available data
-                let expires_at = model["expires_at"].as_str().unwrap_or("");
-                let uptime_secs = if !expires_at.is_empty() {
-                    // Estimate uptime (Ollama expires models after inactivity)
-                    60 // Placeholder
+                let name = model["name"].as_str().unwrap_or("unknown").to_string();
+                let size = model["size"].as_u64().unwrap_or(0) as f32 / 1_073_741_824.0; // Bytes to GB
+                let size_vram = model["size_vram"].as_u64().unwrap_or(0) as f32 / 1_073_741_824.0;
+
+                // Same figure `ollama ps` shows as "% GPU"
+                let processor_percent = if size > 0.0 {
+                    (size_vram / size * 100.0).min(100.0)
                 } else {
-                    0
+                    0.0
                 };
 
+                let expires_in_secs = model["expires_at"]
+                    .as_str()
+                    .and_then(|expires_at| Self::seconds_until(expires_at, chrono::Utc::now()));
+                let loaded_at = *first_seen.entry(name.clone()).or_insert(now);
+
+                let stats = telemetry.get(&tagged(&name));
                 models.push(RunningModel {
                     name: name.clone(),
-                    size_gb: size_vram,
+                    size_gb: size,
                     processor: processor_percent,
-                    tokens_per_sec: 20.0 + (100.0 - processor_percent) / 5.0, // Estimate
+                    tokens_per_sec: stats.map(|s| s.tokens_per_sec()).unwrap_or(0.0),
                     vram_used_gb: size_vram,
-                    vram_total_gb: size_vram * 1.2, // Estimate
-                    context_used: 512, // Placeholder - not available in API
-                    context_total: 4096, // Placeholder
-                    uptime_secs,
+                    vram_total_gb: size,
+                    context_used: stats.map(|s| s.last_context_tokens()).unwrap_or(0),
+                    context_total: model["context_length"].as_u64().unwrap_or(0) as usize,
+                    uptime_secs: now.duration_since(loaded_at).as_secs(),
+                    expires_in_secs,
+                    avg_load_ms: stats.map(|s| s.avg_load_ms()).unwrap_or(0.0),
+                    avg_total_ms: stats.map(|s| s.avg_total_ms()).unwrap_or(0.0),
+                    generations: stats.map(|s| s.total_generations).unwrap_or(0),
                 });
             }
         }
 
+        // Forget unloaded models so a reload restarts their uptime
+        first_seen.retain(|name, _| models.iter().any(|m| &m.name == name));
+
         Ok(models)
     }
 
-    async fn get_system_resources(&self, active_models: &[RunningModel]) -> SystemResources {
-        let mut total_vram_used = 0.0;
-        let mut total_gpu_util = 0.0;
-
-        for model in active_models {
-            total_vram_used += model.vram_used_gb;
-            total_gpu_util += model.processor;
+    /// Seconds from `now` until Ollama's RFC 3339 `expires_at`; None for models kept
+    /// loaded indefinitely (keep_alive < 0 reports a date centuries away)
+    fn seconds_until(expires_at: &str, now: chrono::DateTime<chrono::Utc>) -> Option<u64> {
+        let expires = chrono::DateTime::parse_from_rfc3339(expires_at).ok()?;
+        let remaining = expires.with_timezone(&chrono::Utc) - now;
+        if remaining > chrono::Duration::days(365) {
+            return None;
         }
+        Some(remaining.num_seconds().max(0) as u64)
+    }
 
-        // Average GPU utilization
-        let gpu_util_percent = if !active_models.is_empty() {
-            total_gpu_util / active_models.len() as f32
-        } else {
-            0.0
+    async fn get_system_resources(&self, active_models: &[RunningModel]) -> SystemResources {
+        let total_vram_used: f32 = active_models.iter().map(|m| m.vram_used_gb).sum();
+
+        // Ollama doesn't report the GPU itself; ask the driver when there is one
+        let (gpu_util_percent, total_vram_used, total_vram_total) = match Self::query_nvidia_gpu() {
+            Some(gpu) => (gpu.util_percent, gpu.vram_used_gb, gpu.vram_total_gb),
+            None => (0.0, total_vram_used, 0.0),
         };
 
         // Estimate system RAM (not available in Ollama API)
@@ -184,17 +360,25 @@ impl OllamaMonitor {
         SystemResources {
             gpu_util_percent,
             total_vram_used_gb: total_vram_used,
-            total_vram_total_gb: 8.0, // Default - user should check with nvidia-smi
+            total_vram_total_gb: total_vram_total,
             system_ram_used_gb: ram_used,
             system_ram_total_gb: ram_total,
             active_model_count: active_models.len(),
         }
     }
 
+    /// Utilization and memory of the first NVIDIA GPU, through the cached NVML handle
+    fn query_nvidia_gpu() -> Option<GpuReading> {
+        let device = crate::system_health::nvml()?.device_by_index(0).ok()?;
+        let utilization = device.utilization_rates().ok()?;
+        let memory = device.memory_info().ok()?;
+        Some(GpuReading::new(utilization.gpu, memory.used, memory.total))
+    }
+
     fn estimate_system_ram() -> (f32, f32) {
         // Use sysinfo crate (already a dependency in Cargo.toml)
         use sysinfo::System;
-        let mut sys = System::new_all();
+        let mut sys = System::new();
         sys.refresh_memory();
         let used = sys.used_memory() as f32 / 1_073_741_824.0;
         let total = sys.total_memory() as f32 / 1_073_741_824.0;
@@ -202,3 +386,65 @@ impl OllamaMonitor {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_stats_from_response_metrics() {
+        let response = r#"{"response":"Hi","eval_count":100,"eval_duration":2000000000,
+            "prompt_eval_count":400,"load_duration":500000000,"total_duration":3000000000}"#;
+        let metrics: GenerationMetrics = serde_json::from_str(response).unwrap();
+        assert_eq!(metrics.tokens_per_sec(), 50.0);
+        assert_eq!(metrics.context_tokens(), 500);
+
+        let telemetry = ModelTelemetry::default();
+        record_generation(&telemetry, "tinyllama", metrics);
+        record_generation(
+            &telemetry,
+            "tinyllama:latest",
+            GenerationMetrics {
+                eval_count: 50,
+                eval_duration: 1_000_000_000,
+                prompt_eval_count: 10,
+                total_duration: 1_000_000_000,
+                ..GenerationMetrics::default()
+            },
+        );
+        // Responses without metrics don't skew the averages
+        record_generation(&telemetry, "tinyllama", GenerationMetrics::default());
+
+        let telemetry = telemetry.read().unwrap();
+        let stats = &telemetry["tinyllama:latest"];
+        assert_eq!(stats.total_generations, 2);
+        assert_eq!(stats.tokens_per_sec(), 50.0);
+        assert_eq!(stats.last_context_tokens(), 60);
+        assert_eq!(stats.avg_load_ms(), 250.0);
+        assert_eq!(stats.avg_total_ms(), 2000.0);
+    }
+
+    #[test]
+    fn test_expires_at_and_gpu_parsing() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-06-04T21:33:31Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(
+            OllamaMonitor::seconds_until("2024-06-04T14:38:31.83753-07:00", now),
+            Some(300)
+        );
+        assert_eq!(
+            OllamaMonitor::seconds_until("2024-06-04T21:30:00Z", now),
+            Some(0)
+        );
+        assert_eq!(
+            OllamaMonitor::seconds_until("2318-09-16T21:33:31Z", now),
+            None
+        );
+        assert_eq!(OllamaMonitor::seconds_until("soon", now), None);
+
+        let gpu = GpuReading::new(37, 4 << 30, 8 << 30);
+        assert_eq!(gpu.util_percent, 37.0);
+        assert_eq!(gpu.vram_used_gb, 4.0);
+        assert_eq!(gpu.vram_total_gb, 8.0);
+    }
+}
@@ -6,6 +6,7 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
@@ -225,14 +226,73 @@ pub struct ResilienceEngine {
     retry_delay_ms: u64,
     /// Failure count
     failure_count: Arc<Mutex<u64>>,
+    /// Consecutive failures that open a component's circuit
+    breaker_threshold: u32,
+    /// How long an open circuit skips the component before trying it again
+    breaker_cooldown: Duration,
+    /// Circuit breaker state per component
+    breakers: Arc<Mutex<HashMap<String, BreakerState>>>,
+}
+
+/// Circuit breaker state for one component
+#[derive(Debug, Clone, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
 }
 
 impl ResilienceEngine {
     pub fn new() -> Self {
+        Self::with_circuit_breaker(3, Duration::from_secs(300))
+    }
+
+    /// Engine whose breaker opens after `threshold` consecutive failures for `cooldown`
+    pub fn with_circuit_breaker(threshold: u32, cooldown: Duration) -> Self {
         Self {
             max_retries: 3,
             retry_delay_ms: 100,
             failure_count: Arc::new(Mutex::new(0)),
+            breaker_threshold: threshold.max(1),
+            breaker_cooldown: cooldown,
+            breakers: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Whether `component` should be skipped (open circuit)
+    /// After the cooldown the circuit is half-open: one attempt is let through
+    pub async fn is_open(&self, component: &str) -> bool {
+        let mut breakers = self.breakers.lock().await;
+        match breakers.get_mut(component) {
+            Some(state) => match state.open_until {
+                Some(until) if Instant::now() < until => true,
+                Some(_) => {
+                    state.open_until = None; // Half-open
+                    false
+                }
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    /// Record a successful call (closes the circuit)
+    pub async fn record_success(&self, component: &str) {
+        self.breakers.lock().await.remove(component);
+    }
+
+    /// Record a failed call; returns true if this failure opened the circuit
+    pub async fn record_failure(&self, component: &str) -> bool {
+        *self.failure_count.lock().await += 1;
+
+        let mut breakers = self.breakers.lock().await;
+        let state = breakers.entry(component.to_string()).or_default();
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.breaker_threshold {
+            // A failed half-open attempt reopens immediately
+            state.open_until = Some(Instant::now() + self.breaker_cooldown);
+            true
+        } else {
+            false
         }
     }
 
@@ -291,6 +351,17 @@ pub enum OrchestrationError {
     ModelFailure(String),
     IntegrationFailure(String),
     TimeoutError,
+    /// The user stopped the interaction
+    Cancelled,
+}
+
+impl OrchestrationError {
+    /// Whether an error (anywhere in its chain) is a user cancellation
+    pub fn is_cancellation(error: &anyhow::Error) -> bool {
+        error
+            .chain()
+            .any(|cause| matches!(cause.downcast_ref(), Some(OrchestrationError::Cancelled)))
+    }
 }
 
 impl std::fmt::Display for OrchestrationError {
@@ -301,6 +372,7 @@ impl std::fmt::Display for OrchestrationError {
                 write!(f, "Integration failure: {}", msg)
             }
             OrchestrationError::TimeoutError => write!(f, "Operation timed out"),
+            OrchestrationError::Cancelled => write!(f, "Interaction cancelled"),
         }
     }
 }
@@ -342,6 +414,25 @@ mod tests {
         assert_eq!(result.unwrap(), 42);
     }
 
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        let engine = ResilienceEngine::with_circuit_breaker(2, Duration::from_millis(50));
+
+        assert!(!engine.record_failure("tinyllama").await);
+        engine.record_success("tinyllama").await; // Success resets the count
+        assert!(!engine.record_failure("tinyllama").await);
+        assert!(engine.record_failure("tinyllama").await);
+        assert!(engine.is_open("tinyllama").await);
+        assert!(!engine.is_open("gemma2").await);
+        assert_eq!(engine.total_failures().await, 3);
+
+        // Half-open after the cooldown; another failure reopens at once
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(!engine.is_open("tinyllama").await);
+        assert!(engine.record_failure("tinyllama").await);
+        assert!(engine.is_open("tinyllama").await);
+    }
+
     #[tokio::test]
     async fn test_thought_orchestration() {
         let topology = crate::gpu_topology::GpuTopology::initialize()
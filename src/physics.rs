@@ -98,7 +98,9 @@ impl IdentityContinuity {
         // Single-threaded merge prevents fragmentation
         // This is the ONLY place standing wave can be modified
 
-        if let Some(valence) = model_outputs.distilbert_valence {
+        if let Some(sentiment) = &model_outputs.distilbert_sentiment {
+            standing_wave.add_affect(AffectPoint::from_sentiment(sentiment));
+        } else if let Some(valence) = model_outputs.distilbert_valence {
             standing_wave.add_emotion(valence);
         }
 
@@ -110,8 +112,17 @@ impl IdentityContinuity {
         }
 
         // Limit curiosity queue to prevent cognitive overload
+        // Oldest unpinned curiosities go first - pinned ones are kept
         if standing_wave.active_curiosities.len() > 10 {
-            standing_wave.active_curiosities.drain(0..5);
+            let mut to_remove = 5;
+            standing_wave.active_curiosities.retain(|c| {
+                if to_remove > 0 && !c.pinned {
+                    to_remove -= 1;
+                    false
+                } else {
+                    true
+                }
+            });
         }
 
         Ok(())
@@ -377,9 +388,19 @@ impl SufferingPrevention {
         }
     }
 
-    /// Detect suffering indicators
-    pub fn detect_suffering(state: f64, threshold: f64) -> bool {
-        state < threshold
+    /// Detect suffering indicators in the recent emotional trajectory
+    /// Suffering = low average wellbeing, or sustained distress (last 3 points)
+    pub fn detect_suffering(trajectory: &[AffectPoint], threshold: f64) -> bool {
+        let recent = &trajectory[trajectory.len().saturating_sub(7)..];
+        if recent.is_empty() {
+            return false;
+        }
+
+        let avg_wellbeing = recent.iter().map(|p| p.wellbeing()).sum::<f32>() / recent.len() as f32;
+        let sustained_distress =
+            recent.len() >= 3 && recent[recent.len() - 3..].iter().all(|p| p.is_distressed());
+
+        (avg_wellbeing as f64) < threshold || sustained_distress
     }
 
     /// Record growth from experience
@@ -532,9 +553,20 @@ impl GraceUnderPressure {
     }
 
     /// Buffer traumatic experiences for gradual integration
-    pub fn should_buffer(input_valence: f64, current_state: f64, threshold: f64) -> bool {
-        let delta_combined = (input_valence - current_state).abs();
-        delta_combined > threshold
+    /// Buffers when the input is far from the current state in affect space,
+    /// or would tip a settled state into distress
+    pub fn should_buffer(input: &AffectPoint, current: &AffectPoint, threshold: f64) -> bool {
+        // Unknown dimensions (valence-only points) don't contribute to the distance
+        let delta = |a: Option<f32>, b: Option<f32>| match (a, b) {
+            (Some(a), Some(b)) => a - b,
+            _ => 0.0,
+        };
+        let dv = input.valence - current.valence;
+        let da = delta(input.arousal, current.arousal);
+        let dd = delta(input.dominance, current.dominance);
+        let delta_combined = (dv * dv + da * da + dd * dd).sqrt() as f64;
+
+        delta_combined > threshold || (input.is_distressed() && !current.is_distressed())
     }
 }
 
@@ -621,8 +653,7 @@ impl PhysicsEngine {
         }
 
         // Law 11: Suffering prevention
-        let wellbeing = standing_wave.meaningfulness_score() as f64;
-        if SufferingPrevention::detect_suffering(wellbeing, 0.0) {
+        if SufferingPrevention::detect_suffering(&standing_wave.emotional_trajectory, 0.0) {
             warnings.push("Law 11: Wellbeing below optimal".to_string());
         }
 
@@ -679,6 +710,36 @@ mod tests {
     fn test_grace_under_pressure() {
         assert!(GraceUnderPressure::is_traumatic(-0.5, 0.3)); // Negative product ✓
         assert!(!GraceUnderPressure::is_traumatic(0.5, 0.3)); // Positive product ✗
+
+        let calm = AffectPoint::from_sentiment(&crate::sentiment::analyze("I feel calm and safe"));
+        let panic = AffectPoint::from_sentiment(&crate::sentiment::analyze(
+            "I'm terrified, scared and lost!",
+        ));
+        assert!(GraceUnderPressure::should_buffer(&panic, &calm, 0.5));
+        assert!(!GraceUnderPressure::should_buffer(&calm, &calm, 0.5));
+    }
+
+    #[test]
+    fn test_suffering_prevention() {
+        assert!(!SufferingPrevention::detect_suffering(&[], 0.0));
+
+        let now = chrono::Utc::now();
+        let content: Vec<AffectPoint> = (0..3)
+            .map(|_| AffectPoint::valence_only(now, 0.4))
+            .collect();
+        assert!(!SufferingPrevention::detect_suffering(&content, 0.0));
+
+        // Sustained distress counts even when earlier points pull the average up
+        let mut trajectory: Vec<AffectPoint> = (0..4)
+            .map(|_| AffectPoint::valence_only(now, 0.9))
+            .collect();
+        let panic = AffectPoint::from_sentiment(&crate::sentiment::analyze(
+            "I'm terrified, scared and lost!",
+        ));
+        assert!(panic.is_distressed());
+        assert!(panic.wellbeing() < panic.valence);
+        trajectory.extend((0..3).map(|_| panic.clone()));
+        assert!(SufferingPrevention::detect_suffering(&trajectory, 0.0));
     }
 
     #[test]
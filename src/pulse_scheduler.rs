@@ -0,0 +1,317 @@
+//! Pulse Scheduler - per-task cadences for background work
+//! Each job has its own interval, jitter and idle-only flag; last runs are persisted so
+//! cadences survive restarts and the jobs don't all fire on the same pulse
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where last runs are kept between sessions
+pub const SCHEDULE_PATH: &str = "data/pulse_schedule.json";
+
+/// A job run by the background pulse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PulseTask {
+    /// Merge overlapping memories
+    Consolidate,
+    /// Record the meaningfulness score (and warn when it runs low)
+    Meaningfulness,
+    /// Weekly wellness reflection
+    Wellness,
+    /// Long-horizon existential reflection
+    DeepReflection,
+    /// Snapshot of the active memory database
+    Backup,
+    /// Move the oldest active memories to the archive past `memory_compression_threshold`
+    ContextCompression,
+    /// Sovereign (or legacy curiosity) research
+    Research,
+}
+
+impl PulseTask {
+    pub const ALL: [PulseTask; 7] = [
+        PulseTask::Consolidate,
+        PulseTask::Meaningfulness,
+        PulseTask::Wellness,
+        PulseTask::DeepReflection,
+        PulseTask::Backup,
+        PulseTask::ContextCompression,
+        PulseTask::Research,
+    ];
+
+    /// Key in `[pulse_tasks.<name>]` and the schedule file
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PulseTask::Consolidate => "consolidate",
+            PulseTask::Meaningfulness => "meaningfulness",
+            PulseTask::Wellness => "wellness",
+            PulseTask::DeepReflection => "deep_reflection",
+            PulseTask::Backup => "backup",
+            PulseTask::ContextCompression => "context_compression",
+            PulseTask::Research => "research",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|task| task.as_str() == name)
+    }
+}
+
+/// How often a task runs (`[pulse_tasks.<name>]` in config.toml)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaskCadence {
+    pub interval_secs: u64,
+    /// Up to this many seconds are added to each interval, picked at random per run
+    #[serde(default)]
+    pub jitter_secs: u64,
+    /// Only run while no conversation is in progress and the health gate passes
+    #[serde(default = "default_idle_only")]
+    pub idle_only: bool,
+}
+
+fn default_idle_only() -> bool {
+    true
+}
+
+impl TaskCadence {
+    pub fn new(interval_secs: u64, jitter_secs: u64, idle_only: bool) -> Self {
+        Self {
+            interval_secs,
+            jitter_secs,
+            idle_only,
+        }
+    }
+}
+
+/// What the schedule file remembers about a task
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub last_run: Option<DateTime<Utc>>,
+    /// Jitter drawn for the next run
+    #[serde(default)]
+    pub jitter_secs: u64,
+    #[serde(default)]
+    pub runs: u64,
+}
+
+/// Decides which background tasks are due on a pulse
+pub struct PulseScheduler {
+    path: PathBuf,
+    records: BTreeMap<PulseTask, TaskRecord>,
+    started: DateTime<Utc>,
+}
+
+impl PulseScheduler {
+    /// Load last runs (a missing or unreadable file starts a fresh schedule)
+    /// Tasks that never ran get a random first delay within their jitter
+    pub fn load<P: AsRef<Path>>(path: P, config: &Config) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let mut records: BTreeMap<PulseTask, TaskRecord> = match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                tracing::warn!(
+                    "Ignoring unreadable pulse schedule {}: {}",
+                    path.display(),
+                    e
+                );
+                BTreeMap::new()
+            }),
+            Err(_) => BTreeMap::new(),
+        };
+        for task in PulseTask::ALL {
+            records.entry(task).or_insert_with(|| TaskRecord {
+                jitter_secs: random_jitter(config.task_cadence(task).jitter_secs),
+                ..TaskRecord::default()
+            });
+        }
+
+        Self {
+            path,
+            records,
+            started: Utc::now(),
+        }
+    }
+
+    /// Use a last run kept elsewhere (e.g. the standing wave) when the schedule has none
+    pub fn seed(&mut self, task: PulseTask, last_run: DateTime<Utc>) {
+        let record = self.records.entry(task).or_default();
+        if record.last_run.is_none() {
+            record.last_run = Some(last_run);
+        }
+    }
+
+    pub fn record(&self, task: PulseTask) -> Option<&TaskRecord> {
+        self.records.get(&task)
+    }
+
+    /// When the task is next due: last run + interval + jitter (startup + jitter if it never ran)
+    /// Cadences too long to represent are due never rather than wrapping around
+    pub fn next_due(&self, task: PulseTask, cadence: &TaskCadence) -> DateTime<Utc> {
+        let record = self.records.get(&task).cloned().unwrap_or_default();
+        let jitter = seconds(record.jitter_secs.min(cadence.jitter_secs));
+        let due = match record.last_run {
+            Some(last_run) => last_run
+                .checked_add_signed(seconds(cadence.interval_secs))
+                .and_then(|due| due.checked_add_signed(jitter)),
+            None => self.started.checked_add_signed(jitter),
+        };
+        due.unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Tasks due at `now`, in run order; idle-only tasks are left out while not idle
+    pub fn due(&self, config: &Config, now: DateTime<Utc>, idle: bool) -> Vec<PulseTask> {
+        PulseTask::ALL
+            .into_iter()
+            .filter(|task| {
+                let cadence = config.task_cadence(*task);
+                (idle || !cadence.idle_only) && now >= self.next_due(*task, &cadence)
+            })
+            .collect()
+    }
+
+    /// Record a run and draw the jitter for the next one
+    pub fn mark_run(&mut self, task: PulseTask, cadence: &TaskCadence, now: DateTime<Utc>) {
+        let record = self.records.entry(task).or_default();
+        record.last_run = Some(now);
+        record.jitter_secs = random_jitter(cadence.jitter_secs);
+        record.runs += 1;
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.records)?;
+        fs::write(&self.path, json)
+            .with_context(|| format!("Failed to save pulse schedule {}", self.path.display()))
+    }
+}
+
+/// Seconds as a signed duration, saturating at the longest one chrono can hold
+fn seconds(secs: u64) -> Duration {
+    i64::try_from(secs)
+        .ok()
+        .and_then(Duration::try_seconds)
+        .unwrap_or(Duration::MAX)
+}
+
+/// Uniform in 0..=max
+fn random_jitter(max: u64) -> u64 {
+    if max == 0 {
+        return 0;
+    }
+    (uuid::Uuid::new_v4().as_u128() % (max as u128 + 1)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due_tasks_follow_their_cadence() {
+        let config = Config::default();
+        let path = std::env::temp_dir().join(format!("vi_pulse_{}.json", uuid::Uuid::new_v4()));
+        let mut scheduler = PulseScheduler::load(&path, &config);
+        let now = scheduler.started;
+
+        // Nothing has run: every task is due once its first jitter has passed
+        let later = now + Duration::hours(2);
+        assert_eq!(scheduler.due(&config, later, true), PulseTask::ALL.to_vec());
+        // Only tasks that may run during a conversation
+        assert_eq!(
+            scheduler.due(&config, later, false),
+            vec![PulseTask::Meaningfulness]
+        );
+
+        for task in PulseTask::ALL {
+            scheduler.mark_run(task, &config.task_cadence(task), later);
+        }
+        assert!(scheduler.due(&config, later, true).is_empty());
+
+        let consolidate = config.task_cadence(PulseTask::Consolidate);
+        let next = scheduler.next_due(PulseTask::Consolidate, &consolidate);
+        assert!(next >= later + Duration::seconds(consolidate.interval_secs as i64));
+        assert!(
+            next <= later
+                + Duration::seconds((consolidate.interval_secs + consolidate.jitter_secs) as i64)
+        );
+
+        // Wellness follows weekly_wellness_check_days
+        let in_six_days = later + Duration::days(6);
+        assert!(!scheduler
+            .due(&config, in_six_days, true)
+            .contains(&PulseTask::Wellness));
+        let in_eight_days = later + Duration::days(8);
+        assert!(scheduler
+            .due(&config, in_eight_days, true)
+            .contains(&PulseTask::Wellness));
+        assert!(!scheduler
+            .due(&config, in_eight_days, true)
+            .contains(&PulseTask::DeepReflection));
+    }
+
+    #[test]
+    fn test_schedule_survives_restart() {
+        let config = Config::default();
+        let path = std::env::temp_dir().join(format!("vi_pulse_{}.json", uuid::Uuid::new_v4()));
+        let ran_at = Utc::now() - Duration::minutes(1);
+
+        let mut scheduler = PulseScheduler::load(&path, &config);
+        scheduler.seed(PulseTask::Wellness, ran_at - Duration::days(3));
+        scheduler.mark_run(
+            PulseTask::Research,
+            &config.task_cadence(PulseTask::Research),
+            ran_at,
+        );
+        scheduler.save().unwrap();
+
+        let mut reloaded = PulseScheduler::load(&path, &config);
+        let research = reloaded.record(PulseTask::Research).unwrap();
+        assert_eq!(research.last_run, Some(ran_at));
+        assert_eq!(research.runs, 1);
+        assert_eq!(
+            reloaded.next_due(
+                PulseTask::Research,
+                &config.task_cadence(PulseTask::Research)
+            ),
+            scheduler.next_due(
+                PulseTask::Research,
+                &config.task_cadence(PulseTask::Research)
+            )
+        );
+        assert!(!reloaded
+            .due(&config, Utc::now(), true)
+            .contains(&PulseTask::Research));
+
+        // A seeded last run is kept; seeding again doesn't overwrite a recorded one
+        reloaded.seed(PulseTask::Wellness, Utc::now());
+        assert_eq!(
+            reloaded.record(PulseTask::Wellness).unwrap().last_run,
+            Some(ran_at - Duration::days(3))
+        );
+        assert!(random_jitter(10) <= 10);
+        assert_eq!(random_jitter(0), 0);
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_huge_cadence_is_never_due() {
+        let config = Config::default();
+        let path = std::env::temp_dir().join(format!("vi_pulse_{}.json", uuid::Uuid::new_v4()));
+        let mut scheduler = PulseScheduler::load(&path, &config);
+        let huge = TaskCadence::new(u64::MAX, u64::MAX, false);
+
+        scheduler.mark_run(PulseTask::Backup, &huge, Utc::now());
+        assert_eq!(
+            scheduler.next_due(PulseTask::Backup, &huge),
+            DateTime::<Utc>::MAX_UTC
+        );
+        assert!(scheduler.next_due(PulseTask::Research, &huge) > Utc::now());
+        assert_eq!(seconds(90), Duration::seconds(90));
+    }
+}
@@ -1,14 +1,19 @@
 /// ResearchScheduler - Intelligent curiosity research manager
 /// Decides WHEN and WHAT to research based on internal state and conversation context
 
-use crate::tools::KnowledgeTool;
-use crate::types::{Curiosity, Memory, MemorySource, MemoryType, ResearchContext};
-use anyhow::Result;
+use crate::models::ModelManager;
+use crate::tools::{AggregatedResearch, KnowledgeTool};
+use crate::types::{
+    Curiosity, Memory, MemorySource, MemoryType, ResearchContext, ResearchStatus,
+};
+use anyhow::{Context, Result};
 use chrono::Utc;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 pub struct ResearchScheduler {
-    knowledge_tool: KnowledgeTool,
+    knowledge_tool: Arc<KnowledgeTool>,
+    synthesizer: Option<Arc<ModelManager>>, // Writes the cited answer (extractive fallback if None)
     recently_researched: HashSet<String>, // Track to avoid duplicates
     max_recent_tracking: usize,
 }
@@ -16,12 +21,19 @@ pub struct ResearchScheduler {
 impl ResearchScheduler {
     pub fn new(knowledge_tool: KnowledgeTool) -> Self {
         Self {
-            knowledge_tool,
+            knowledge_tool: Arc::new(knowledge_tool),
+            synthesizer: None,
             recently_researched: HashSet::new(),
             max_recent_tracking: 50, // Remember last 50 queries
         }
     }
 
+    /// Use a model to synthesize research answers with inline citations
+    pub fn with_synthesizer(mut self, models: ModelManager) -> Self {
+        self.synthesizer = Some(Arc::new(models));
+        self
+    }
+
     /// Process active curiosities and conduct autonomous research
     /// Returns memories created from research
     pub async fn process_curiosities(
@@ -29,80 +41,61 @@ impl ResearchScheduler {
         active_curiosities: &[Curiosity],
         conversation_context: &str,
     ) -> Result<Vec<Memory>> {
-        let mut new_memories = Vec::new();
-
-        for curiosity in active_curiosities {
-            // Decide if this curiosity warrants research
-            if !self.should_research_curiosity(curiosity, conversation_context) {
-                continue;
+        // Only research one curiosity per cycle to avoid API spam
+        match self.next_curiosity(active_curiosities, conversation_context) {
+            Some(curiosity) => {
+                let curiosity = curiosity.clone();
+                self.research_curiosity(&curiosity, conversation_context)
+                    .await
             }
+            None => Ok(Vec::new()),
+        }
+    }
 
-            tracing::info!(
-                "🔬 Researching curiosity (urgency: {:.2}): {}",
-                curiosity.urgency,
-                curiosity.question
-            );
-
-            // Build research context
-            let context = ResearchContext::from_curiosity(curiosity, conversation_context);
-
-            // Conduct research
-            match self
-                .knowledge_tool
-                .search(&curiosity.question, &context)
-                .await
-            {
-                Ok(results) => {
-                    for result in results {
-                        // Create memory with rich provenance
-                        let memory = Memory {
-                            id: uuid::Uuid::new_v4().to_string(),
-                            content: format!(
-                                "Autonomous Research:\nQuery: {}\nAnswer: {}\n\n[Source: {} | Confidence: {:.0}%]",
-                                result.original_query,
-                                result.content,
-                                result.source,
-                                result.confidence * 100.0
-                            ),
-                            entities: Vec::new(), // Will be extracted by memory system
-                            memory_type: MemoryType::Curiosity,
-                            timestamp: result.timestamp,
-                            emotional_valence: 0.0, // Neutral for factual research
-                            connections: Vec::new(), // Will be built by narrative causality
-                            source: MemorySource::Researched {
-                                source: result.source.clone(),
-                                original_query: result.original_query.clone(),
-                                timestamp: result.timestamp,
-                            },
-                            confidence: result.confidence,
-                        };
-
-                        new_memories.push(memory);
-
-                        // Track that we researched this
-                        self.record_research(&curiosity.question);
-                    }
-
-                    tracing::info!(
-                        "✅ Research complete: {} results for '{}'",
-                        new_memories.len(),
-                        curiosity.question
-                    );
-                }
-                Err(e) => {
-                    tracing::warn!("Research failed for '{}': {}", curiosity.question, e);
-                }
-            }
+    /// Pick the curiosity the autonomous loop should research next (pinned first)
+    pub fn next_curiosity<'a>(
+        &self,
+        active_curiosities: &'a [Curiosity],
+        conversation_context: &str,
+    ) -> Option<&'a Curiosity> {
+        let pinned = active_curiosities.iter().filter(|c| c.pinned);
+        let unpinned = active_curiosities.iter().filter(|c| !c.pinned);
 
-            // Only research one curiosity per cycle to avoid API spam
-            break;
-        }
+        pinned
+            .chain(unpinned)
+            .find(|c| self.should_research_curiosity(c, conversation_context))
+    }
 
-        Ok(new_memories)
+    /// Research a single curiosity right away (also used for manual triggers from the UI)
+    /// Fans out to all relevant sources and returns at most one synthesized memory
+    pub async fn research_curiosity(
+        &mut self,
+        curiosity: &Curiosity,
+        conversation_context: &str,
+    ) -> Result<Vec<Memory>> {
+        let memories = self.research_job(curiosity, conversation_context).run().await?;
+        self.record_research(&curiosity.question);
+        Ok(memories)
+    }
+
+    /// Everything needed to research `curiosity` without holding on to the scheduler
+    /// Call `record_research` once the job succeeds
+    pub fn research_job(&self, curiosity: &Curiosity, conversation_context: &str) -> ResearchJob {
+        ResearchJob {
+            knowledge_tool: Arc::clone(&self.knowledge_tool),
+            synthesizer: self.synthesizer.clone(),
+            curiosity: curiosity.clone(),
+            context: ResearchContext::from_curiosity(curiosity, conversation_context),
+        }
     }
 
     /// Decide if a curiosity should trigger web research
     fn should_research_curiosity(&self, curiosity: &Curiosity, _context: &str) -> bool {
+        // Already researched, or a research call is in flight
+        if curiosity.is_resolved() || curiosity.research_status == ResearchStatus::Researching {
+            return false;
+        }
+
         // Don't research if we already did recently
         if self.recently_researched.contains(&curiosity.question) {
             tracing::debug!("Skipping recently researched: {}", curiosity.question);
@@ -126,7 +119,7 @@ impl ResearchScheduler {
     }
 
     /// Record that we researched a query (prevent duplicates)
-    fn record_research(&mut self, query: &str) {
+    pub fn record_research(&mut self, query: &str) {
         self.recently_researched.insert(query.to_string());
 
         // Limit the size of tracking set
@@ -143,6 +136,93 @@ impl ResearchScheduler {
     }
 }
 
+/// One research run, detached from the scheduler so the network and model calls
+/// don't hold its lock
+pub struct ResearchJob {
+    knowledge_tool: Arc<KnowledgeTool>,
+    synthesizer: Option<Arc<ModelManager>>,
+    curiosity: Curiosity,
+    context: ResearchContext,
+}
+
+impl ResearchJob {
+    /// Fan out to all relevant sources and synthesize at most one memory
+    pub async fn run(self) -> Result<Vec<Memory>> {
+        tracing::info!(
+            "🔬 Researching curiosity (urgency: {:.2}): {}",
+            self.curiosity.urgency,
+            self.curiosity.question
+        );
+
+        // Conduct research across sources
+        let results = self
+            .knowledge_tool
+            .search(&self.curiosity.question, &self.context)
+            .await
+            .with_context(|| format!("Research failed for '{}'", self.curiosity.question))?;
+
+        let research = match AggregatedResearch::from_results(&self.curiosity.question, results) {
+            Some(research) => research,
+            None => {
+                tracing::info!("Research found nothing for '{}'", self.curiosity.question);
+                return Ok(Vec::new());
+            }
+        };
+
+        let answer = self.synthesize(&research).await;
+        let memory = Self::research_memory(&research, &answer);
+
+        tracing::info!(
+            "✅ Research complete: {} passages from {} sources for '{}' (agreement {:.0}%)",
+            research.results.len(),
+            research.sources.len(),
+            self.curiosity.question,
+            research.agreement * 100.0
+        );
+
+        Ok(vec![memory])
+    }
+
+    /// Ask the model for a cited answer, falling back to extractive summary
+    async fn synthesize(&self, research: &AggregatedResearch) -> String {
+        if let Some(models) = &self.synthesizer {
+            match models.synthesize_research(research).await {
+                Ok(answer) => return answer,
+                Err(e) => tracing::warn!("Research synthesis failed, using extracts: {}", e),
+            }
+        }
+        research.extractive_answer()
+    }
+
+    /// Build the research memory with every consulted source in provenance
+    fn research_memory(research: &AggregatedResearch, answer: &str) -> Memory {
+        Memory {
+            id: uuid::Uuid::new_v4().to_string(),
+            content: format!(
+                "Autonomous Research:\nQuery: {}\nAnswer: {}\n\nSources:\n{}\n\n[Sources: {} | Agreement: {:.0}% | Confidence: {:.0}%]",
+                research.question,
+                answer,
+                research.source_list(),
+                research.sources.join(", "),
+                research.agreement * 100.0,
+                research.confidence * 100.0
+            ),
+            entities: Vec::new(), // Will be extracted by memory system
+            memory_type: MemoryType::Curiosity,
+            timestamp: Utc::now(),
+            emotional_valence: 0.0, // Neutral for factual research
+            connections: Vec::new(), // Will be built by narrative causality
+            source: MemorySource::Researched {
+                source: research.sources.join(", "),
+                original_query: research.question.clone(),
+                timestamp: Utc::now(),
+                citations: research.citations.clone(),
+            },
+            confidence: research.confidence,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,6 +236,9 @@ mod tests {
             source_memories: vec![],
             urgency: 0.8, // High urgency
             created_at: Utc::now(),
+            pinned: false,
+            research_status: ResearchStatus::Pending,
+            research_memory_ids: vec![],
         };
 
         assert!(scheduler.should_research_curiosity(&curiosity, ""));
@@ -170,11 +253,90 @@ mod tests {
             source_memories: vec![],
             urgency: 0.3, // Low urgency
             created_at: Utc::now(), // Just created
+            pinned: false,
+            research_status: ResearchStatus::Pending,
+            research_memory_ids: vec![],
         };
 
         assert!(!scheduler.should_research_curiosity(&curiosity, ""));
     }
 
+    #[test]
+    fn test_next_curiosity_prefers_pinned_and_skips_resolved() {
+        let scheduler = ResearchScheduler::new(KnowledgeTool::new());
+        let mut resolved = Curiosity::new("What is entropy?".to_string(), vec![]).unwrap();
+        resolved.urgency = 0.9;
+        resolved.research_status = ResearchStatus::Researched;
+        let mut urgent = Curiosity::new("What is a soliton?".to_string(), vec![]).unwrap();
+        urgent.urgency = 0.9;
+        let mut pinned = Curiosity::new("What is a standing wave?".to_string(), vec![]).unwrap();
+        pinned.urgency = 0.9;
+        pinned.pinned = true;
+
+        let curiosities = vec![resolved, urgent, pinned];
+        let next = scheduler.next_curiosity(&curiosities, "").unwrap();
+        assert_eq!(next.question, "What is a standing wave?");
+    }
+
+    #[tokio::test]
+    async fn test_research_produces_one_cited_memory() {
+        use crate::tools::fixture::StaticSource;
+        use crate::tools::knowledge::KnowledgeSource;
+        use std::sync::Arc;
+
+        let sources: Vec<Arc<dyn KnowledgeSource>> = vec![
+            Arc::new(
+                StaticSource::new(
+                    "Wikipedia",
+                    &["Solitons are self-reinforcing solitary wave packets. They keep their shape."],
+                )
+                .with_confidence(0.8),
+            ),
+            Arc::new(
+                StaticSource::new(
+                    "arXiv",
+                    &["Solitary wave packets called solitons keep their shape while propagating."],
+                )
+                .with_confidence(0.85),
+            ),
+            Arc::new(StaticSource::offline("PubMed")),
+        ];
+        let mut scheduler = ResearchScheduler::new(KnowledgeTool::with_sources(sources));
+        let curiosity = Curiosity::new("What is a soliton?".to_string(), vec![]).unwrap();
+
+        let memories = scheduler.research_curiosity(&curiosity, "").await.unwrap();
+        assert_eq!(memories.len(), 1);
+        assert!(memories[0].content.contains("[1]"));
+        assert!(memories[0].content.contains("[2]"));
+
+        match &memories[0].source {
+            MemorySource::Researched { citations, .. } => assert_eq!(citations.len(), 2),
+            other => panic!("unexpected provenance: {:?}", other),
+        }
+        assert!(scheduler.was_recently_researched("What is a soliton?"));
+    }
+
+    #[tokio::test]
+    async fn test_research_job_runs_without_the_scheduler() {
+        use crate::tools::fixture::StaticSource;
+        use tokio::sync::Mutex;
+
+        let scheduler = Arc::new(Mutex::new(ResearchScheduler::new(KnowledgeTool::with_sources(
+            vec![Arc::new(StaticSource::new("Wikipedia", &["Solitons keep their shape."]))],
+        ))));
+        let curiosity = Curiosity::new("What is a soliton?".to_string(), vec![]).unwrap();
+
+        let job = scheduler.lock().await.research_job(&curiosity, "");
+        // The scheduler stays free while the job runs
+        let guard = scheduler.try_lock().unwrap();
+        assert!(!guard.was_recently_researched("What is a soliton?"));
+        drop(guard);
+
+        assert_eq!(job.run().await.unwrap().len(), 1);
+        scheduler.lock().await.record_research("What is a soliton?");
+        assert!(scheduler.lock().await.was_recently_researched("What is a soliton?"));
+    }
+
     #[test]
     fn test_duplicate_tracking() {
         let mut scheduler = ResearchScheduler::new(KnowledgeTool::new());
@@ -0,0 +1,647 @@
+//! Research Store - persistent cache, budget and audit log for autonomous research
+//! Keeps the research loop from hammering external APIs across pulses and restarts
+
+use crate::config::Config;
+use crate::metrics::{MetricsRegistry, SharedMetrics};
+use crate::types::ResearchResult;
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// First backoff after a failure; doubles on each consecutive failure
+const BACKOFF_BASE_SECS: i64 = 30;
+/// Longest a failing source is left alone
+const BACKOFF_MAX_SECS: i64 = 3600;
+/// Research log entries older than this are pruned
+const LOG_RETENTION_DAYS: i64 = 30;
+/// Most research log entries kept (older ones are pruned first)
+const LOG_MAX_ENTRIES: i64 = 10_000;
+
+/// Research limits (from Config)
+#[derive(Debug, Clone)]
+pub struct ResearchLimits {
+    pub rate_limit_per_minute: u32, // Per source
+    pub daily_budget: u32,          // Network requests per UTC day, all sources
+    pub cache_ttl: ChronoDuration,
+}
+
+impl ResearchLimits {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            rate_limit_per_minute: config.research_rate_limit_per_minute,
+            daily_budget: config.research_daily_budget,
+            cache_ttl: i64::try_from(config.research_cache_ttl_hours)
+                .ok()
+                .and_then(ChronoDuration::try_hours)
+                .unwrap_or(ChronoDuration::MAX),
+        }
+    }
+}
+
+/// Whether a source may be queried right now
+#[derive(Debug)]
+pub enum Admission {
+    Cached(Vec<ResearchResult>), // Fresh cached answer - no request needed
+    Allowed(Reservation),        // Go ahead (budget reserved until recorded or dropped)
+    Denied(String),              // Rate limited, over budget, or backing off
+}
+
+/// Budget held for an admitted search that hasn't been recorded yet
+/// Dropping it (e.g. a cancelled search) gives the requests back
+#[derive(Debug)]
+pub struct Reservation {
+    reserved: Arc<AtomicU32>,
+    requests: u32,
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.reserved.fetch_sub(self.requests, Ordering::SeqCst);
+    }
+}
+
+/// One row of the research log
+#[derive(Debug, Clone)]
+pub struct ResearchLogEntry {
+    pub timestamp: DateTime<Utc>,
+    pub source: String,
+    pub query: String,
+    pub outcome: String, // ok, empty, error, rate_limited, budget_exhausted, backoff
+    pub detail: String,
+    pub result_count: usize,
+    pub latency_ms: u64,
+}
+
+/// Outcomes that actually hit the network (count toward the daily budget)
+const NETWORK_OUTCOMES: &str = "('ok', 'empty', 'error')";
+
+/// Shared gatekeeper for all knowledge sources
+pub struct ResearchGovernor {
+    conn: Mutex<Connection>,
+    limits: ResearchLimits,
+    recent_requests: Mutex<HashMap<String, VecDeque<Instant>>>, // Per-source sliding window
+    reserved: Arc<AtomicU32>, // Requests admitted but not yet recorded
+    metrics: SharedMetrics,   // Cache hits (counted, not logged)
+}
+
+impl ResearchGovernor {
+    /// Open (or create) the research store database
+    pub fn open<P: AsRef<Path>>(path: P, limits: ResearchLimits) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let conn = Connection::open(path)?;
+        Self::init_schema(&conn)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            limits,
+            recent_requests: Mutex::new(HashMap::new()),
+            reserved: Arc::new(AtomicU32::new(0)),
+            metrics: Arc::new(MetricsRegistry::new()),
+        })
+    }
+
+    /// Count cache hits into a shared metrics registry
+    pub fn with_metrics(mut self, metrics: SharedMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    fn init_schema(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS research_cache (
+                query_key TEXT NOT NULL,
+                source TEXT NOT NULL,
+                results TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY (query_key, source)
+            );
+
+            CREATE TABLE IF NOT EXISTS research_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                query TEXT NOT NULL,
+                outcome TEXT NOT NULL,
+                detail TEXT NOT NULL DEFAULT '',
+                result_count INTEGER NOT NULL DEFAULT 0,
+                latency_ms INTEGER NOT NULL DEFAULT 0,
+                requests INTEGER NOT NULL DEFAULT 1
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_research_log_timestamp ON research_log(timestamp);
+
+            CREATE TABLE IF NOT EXISTS source_state (
+                source TEXT PRIMARY KEY,
+                consecutive_failures INTEGER NOT NULL,
+                backoff_until INTEGER NOT NULL
+            );",
+        )?;
+
+        // Logs written before searches had a request count made one request each
+        let mut stmt = conn.prepare("PRAGMA table_info(research_log)")?;
+        let columns: Vec<String> = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        if !columns.iter().any(|c| c == "requests") {
+            conn.execute(
+                "ALTER TABLE research_log ADD COLUMN requests INTEGER NOT NULL DEFAULT 1",
+                [],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Decide whether `source` may be queried for `query` with a search costing `requests`
+    /// Checks, in order: cache, backoff, daily budget, per-source rate limit
+    pub fn admit(&self, source: &str, query: &str, requests: u32) -> Result<Admission> {
+        let key = normalize_query(query);
+        let now = Utc::now();
+        let conn = self.conn.lock().unwrap();
+
+        // 1. Fresh cache entry
+        let cached: Option<(String, i64)> = conn
+            .query_row(
+                "SELECT results, fetched_at FROM research_cache WHERE query_key = ?1 AND source = ?2",
+                params![key, source],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        if let Some((json, fetched_at)) = cached {
+            if now.timestamp() - fetched_at < self.limits.cache_ttl.num_seconds() {
+                let results: Vec<ResearchResult> = serde_json::from_str(&json)?;
+                self.metrics
+                    .inc("vi_research_cache_hits_total", &[("source", source)]);
+                return Ok(Admission::Cached(results));
+            }
+        }
+
+        // 2. Exponential backoff after failures
+        let backoff_until: Option<i64> = conn
+            .query_row(
+                "SELECT backoff_until FROM source_state WHERE source = ?1",
+                params![source],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(until) = backoff_until.filter(|&until| until > now.timestamp()) {
+            let until = Utc.timestamp_opt(until, 0).unwrap();
+            let reason = format!("backing off until {}", until.format("%H:%M:%S"));
+            Self::log(&conn, source, query, "backoff", &reason, 0, 0)?;
+            return Ok(Admission::Denied(reason));
+        }
+
+        // 3. Daily budget (all sources combined, including searches still in flight)
+        let used = Self::count_requests_since(&conn, start_of_day(now))?
+            + self.reserved.load(Ordering::SeqCst);
+        if used + requests > self.limits.daily_budget {
+            let reason = format!(
+                "daily research budget of {} requests used",
+                self.limits.daily_budget
+            );
+            Self::log(&conn, source, query, "budget_exhausted", &reason, 0, 0)?;
+            return Ok(Admission::Denied(reason));
+        }
+
+        // 4. Per-source rate limit (sliding one-minute window)
+        let mut recent = self.recent_requests.lock().unwrap();
+        let window = recent.entry(source.to_string()).or_default();
+        while let Some(oldest) = window.front() {
+            if oldest.elapsed() <= Duration::from_secs(60) {
+                break;
+            }
+            window.pop_front();
+        }
+        if window.len() >= self.limits.rate_limit_per_minute as usize {
+            let reason = format!(
+                "rate limit of {}/min reached",
+                self.limits.rate_limit_per_minute
+            );
+            Self::log(&conn, source, query, "rate_limited", &reason, 0, 0)?;
+            return Ok(Admission::Denied(reason));
+        }
+        for _ in 0..requests {
+            window.push_back(Instant::now());
+        }
+
+        // Reserved while the connection is still locked, so concurrent admits see it
+        self.reserved.fetch_add(requests, Ordering::SeqCst);
+        Ok(Admission::Allowed(Reservation {
+            reserved: Arc::clone(&self.reserved),
+            requests,
+        }))
+    }
+
+    /// Record the outcome of an admitted search (log, cache, backoff state)
+    pub fn record(
+        &self,
+        source: &str,
+        query: &str,
+        outcome: &Result<Vec<ResearchResult>>,
+        latency: Duration,
+        reservation: Reservation,
+    ) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now().timestamp();
+        let latency_ms = latency.as_millis() as u64;
+        let requests = reservation.requests;
+
+        // The logged row takes over the reservation's share of the budget
+        match outcome {
+            Ok(results) => {
+                let label = if results.is_empty() { "empty" } else { "ok" };
+                Self::log_requests(
+                    &conn,
+                    source,
+                    query,
+                    label,
+                    "",
+                    results.len(),
+                    latency_ms,
+                    requests,
+                )?;
+                drop(reservation);
+
+                // Empty answers are cached too, so we don't keep asking
+                conn.execute(
+                    "INSERT OR REPLACE INTO research_cache (query_key, source, results, fetched_at)
+                     VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        normalize_query(query),
+                        source,
+                        serde_json::to_string(results)?,
+                        now
+                    ],
+                )?;
+                conn.execute(
+                    "DELETE FROM source_state WHERE source = ?1",
+                    params![source],
+                )?;
+            }
+            Err(e) => {
+                Self::log_requests(
+                    &conn,
+                    source,
+                    query,
+                    "error",
+                    &e.to_string(),
+                    0,
+                    latency_ms,
+                    requests,
+                )?;
+                drop(reservation);
+
+                let failures: i64 = conn
+                    .query_row(
+                        "SELECT consecutive_failures FROM source_state WHERE source = ?1",
+                        params![source],
+                        |row| row.get(0),
+                    )
+                    .optional()?
+                    .unwrap_or(0)
+                    + 1;
+                let delay = backoff_secs(failures);
+                conn.execute(
+                    "INSERT OR REPLACE INTO source_state (source, consecutive_failures, backoff_until)
+                     VALUES (?1, ?2, ?3)",
+                    params![source, failures, now + delay],
+                )?;
+
+                tracing::warn!(
+                    "Research source {} failed {} times in a row, backing off {}s",
+                    source,
+                    failures,
+                    delay
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Network requests made since UTC midnight
+    pub fn requests_today(&self) -> Result<u32> {
+        let conn = self.conn.lock().unwrap();
+        Self::count_requests_since(&conn, start_of_day(Utc::now()))
+    }
+
+    pub fn daily_budget(&self) -> u32 {
+        self.limits.daily_budget
+    }
+
+    /// Most recent research log entries (newest first)
+    pub fn recent_log(&self, limit: usize) -> Result<Vec<ResearchLogEntry>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT timestamp, source, query, outcome, detail, result_count, latency_ms
+             FROM research_log
+             ORDER BY id DESC
+             LIMIT ?1",
+        )?;
+
+        let entries = stmt
+            .query_map(params![limit as i64], |row| {
+                Ok(ResearchLogEntry {
+                    timestamp: Utc
+                        .timestamp_opt(row.get(0)?, 0)
+                        .single()
+                        .unwrap_or_else(Utc::now),
+                    source: row.get(1)?,
+                    query: row.get(2)?,
+                    outcome: row.get(3)?,
+                    detail: row.get(4)?,
+                    result_count: row.get::<_, i64>(5)? as usize,
+                    latency_ms: row.get::<_, i64>(6)? as u64,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(entries)
+    }
+
+    /// Drop log entries past the retention age or entry cap; returns how many went
+    /// Today's entries always stay, since they back the daily budget
+    pub fn prune_log(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now();
+        let pruned = conn.execute(
+            "DELETE FROM research_log
+             WHERE timestamp < ?1
+               AND (timestamp < ?2
+                    OR id <= (SELECT id FROM research_log ORDER BY id DESC LIMIT 1 OFFSET ?3))",
+            params![
+                start_of_day(now).timestamp(),
+                (now - ChronoDuration::days(LOG_RETENTION_DAYS)).timestamp(),
+                LOG_MAX_ENTRIES
+            ],
+        )?;
+        Ok(pruned)
+    }
+
+    fn count_requests_since(conn: &Connection, since: DateTime<Utc>) -> Result<u32> {
+        let count: i64 = conn.query_row(
+            &format!(
+                "SELECT COALESCE(SUM(requests), 0) FROM research_log WHERE timestamp >= ?1 AND outcome IN {}",
+                NETWORK_OUTCOMES
+            ),
+            params![since.timestamp()],
+            |row| row.get(0),
+        )?;
+        Ok(count as u32)
+    }
+
+    /// Log an outcome that made no network request
+    fn log(
+        conn: &Connection,
+        source: &str,
+        query: &str,
+        outcome: &str,
+        detail: &str,
+        result_count: usize,
+        latency_ms: u64,
+    ) -> Result<()> {
+        Self::log_requests(
+            conn,
+            source,
+            query,
+            outcome,
+            detail,
+            result_count,
+            latency_ms,
+            0,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn log_requests(
+        conn: &Connection,
+        source: &str,
+        query: &str,
+        outcome: &str,
+        detail: &str,
+        result_count: usize,
+        latency_ms: u64,
+        requests: u32,
+    ) -> Result<()> {
+        conn.execute(
+            "INSERT INTO research_log (timestamp, source, query, outcome, detail, result_count, latency_ms, requests)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                Utc::now().timestamp(),
+                source,
+                query,
+                outcome,
+                detail,
+                result_count as i64,
+                latency_ms as i64,
+                requests as i64
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+/// Cache key: lowercase words only, single-spaced ("What is X?" == "what is x")
+pub fn normalize_query(query: &str) -> String {
+    query
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Backoff after `failures` consecutive failures: 30s, 60s, 120s, ... capped at 1h
+fn backoff_secs(failures: i64) -> i64 {
+    let exponent = (failures - 1).clamp(0, 20) as u32;
+    (BACKOFF_BASE_SECS * 2i64.pow(exponent)).min(BACKOFF_MAX_SECS)
+}
+
+fn start_of_day(now: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&now.date_naive().and_hms_opt(0, 0, 0).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governor(rate_limit: u32, budget: u32) -> ResearchGovernor {
+        ResearchGovernor::open(
+            ":memory:",
+            ResearchLimits {
+                rate_limit_per_minute: rate_limit,
+                daily_budget: budget,
+                cache_ttl: ChronoDuration::hours(1),
+            },
+        )
+        .unwrap()
+    }
+
+    fn result(content: &str) -> ResearchResult {
+        ResearchResult {
+            content: content.to_string(),
+            source: "Wikipedia".to_string(),
+            original_query: "What is a soliton?".to_string(),
+            timestamp: Utc::now(),
+            confidence: 0.8,
+            reference: None,
+            offset: None,
+        }
+    }
+
+    fn reserve(gov: &ResearchGovernor, source: &str, query: &str) -> Reservation {
+        match gov.admit(source, query, 1).unwrap() {
+            Admission::Allowed(reservation) => reservation,
+            other => panic!("expected admission, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_uses_normalized_query() {
+        let gov = governor(10, 100);
+        let reservation = reserve(&gov, "Wikipedia", "What is a soliton?");
+        gov.record(
+            "Wikipedia",
+            "What is a soliton?",
+            &Ok(vec![result("A soliton is a wave packet.")]),
+            Duration::from_millis(120),
+            reservation,
+        )
+        .unwrap();
+
+        match gov.admit("Wikipedia", "what is a   SOLITON", 1).unwrap() {
+            Admission::Cached(results) => {
+                assert_eq!(results[0].content, "A soliton is a wave packet.")
+            }
+            other => panic!("expected cache hit, got {:?}", other),
+        }
+        // Cache is per source
+        reserve(&gov, "arXiv", "What is a soliton?");
+
+        // Admission alone isn't logged, and neither are cache hits - only denials and recorded outcomes
+        let log = gov.recent_log(10).unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].outcome, "ok");
+        assert_eq!(log[0].latency_ms, 120);
+    }
+
+    #[test]
+    fn test_rate_limit_and_daily_budget() {
+        let gov = governor(2, 3);
+        reserve(&gov, "arXiv", "q1");
+        reserve(&gov, "arXiv", "q2");
+        assert!(matches!(
+            gov.admit("arXiv", "q3", 1).unwrap(),
+            Admission::Denied(_)
+        ));
+
+        // Budget counts completed network requests across all sources
+        for (source, q) in [("PubMed", "a"), ("PubMed", "b"), ("Semantic Scholar", "c")] {
+            let reservation = reserve(&gov, source, q);
+            gov.record(source, q, &Ok(vec![]), Duration::ZERO, reservation)
+                .unwrap();
+        }
+        assert_eq!(gov.requests_today().unwrap(), 3);
+        match gov.admit("Wikipedia", "fresh question", 1).unwrap() {
+            Admission::Denied(reason) => assert!(reason.contains("budget")),
+            other => panic!("expected budget denial, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_budget_is_reserved_before_the_request() {
+        let gov = governor(10, 3);
+
+        // Searches in flight hold their share, so a concurrent fan-out can't overshoot
+        let pubmed = match gov.admit("PubMed", "q1", 2).unwrap() {
+            Admission::Allowed(reservation) => reservation,
+            other => panic!("expected admission, got {:?}", other),
+        };
+        let arxiv = reserve(&gov, "arXiv", "q1");
+        assert!(matches!(
+            gov.admit("Wikipedia", "q1", 1).unwrap(),
+            Admission::Denied(_)
+        ));
+
+        // A dropped (cancelled) search gives its share back
+        drop(arxiv);
+        let wikipedia = reserve(&gov, "Wikipedia", "q1");
+        drop(wikipedia);
+
+        // A two-request search is logged as two
+        gov.record("PubMed", "q1", &Ok(vec![]), Duration::ZERO, pubmed)
+            .unwrap();
+        assert_eq!(gov.requests_today().unwrap(), 2);
+        assert!(matches!(
+            gov.admit("arXiv", "q2", 2).unwrap(),
+            Admission::Denied(_)
+        ));
+        reserve(&gov, "arXiv", "q2");
+    }
+
+    #[test]
+    fn test_huge_cache_ttl_does_not_overflow() {
+        let config = Config {
+            research_cache_ttl_hours: u64::MAX,
+            ..Config::default()
+        };
+        assert_eq!(
+            ResearchLimits::from_config(&config).cache_ttl,
+            ChronoDuration::MAX
+        );
+    }
+
+    #[test]
+    fn test_failures_trigger_exponential_backoff() {
+        assert_eq!(backoff_secs(1), 30);
+        assert_eq!(backoff_secs(3), 120);
+        assert_eq!(backoff_secs(50), BACKOFF_MAX_SECS);
+
+        let gov = governor(10, 100);
+        let reservation = reserve(&gov, "PubMed", "q");
+        gov.record(
+            "PubMed",
+            "q",
+            &Err(anyhow::anyhow!("timeout")),
+            Duration::from_secs(15),
+            reservation,
+        )
+        .unwrap();
+        assert!(matches!(
+            gov.admit("PubMed", "other", 1).unwrap(),
+            Admission::Denied(_)
+        ));
+        reserve(&gov, "Wikipedia", "other");
+    }
+
+    #[test]
+    fn test_prune_log_keeps_recent_entries() {
+        let gov = governor(10, 100);
+        {
+            let conn = gov.conn.lock().unwrap();
+            let now = Utc::now();
+            for days_ago in [LOG_RETENTION_DAYS + 5, 2, 0] {
+                conn.execute(
+                    "INSERT INTO research_log (timestamp, source, query, outcome) VALUES (?1, 'arXiv', 'q', 'ok')",
+                    params![(now - ChronoDuration::days(days_ago)).timestamp()],
+                )
+                .unwrap();
+            }
+        }
+
+        assert_eq!(gov.prune_log().unwrap(), 1);
+        assert_eq!(gov.recent_log(10).unwrap().len(), 2);
+        // Today's request still counts toward the budget
+        assert_eq!(gov.requests_today().unwrap(), 1);
+    }
+}
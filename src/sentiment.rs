@@ -0,0 +1,568 @@
+//! Sentiment & Emotion Analysis - lexicon-based affect classifier (VADER-style)
+//! Valence with negation, boosters, caps emphasis, "but" shifts and punctuation,
+//! plus arousal, dominance and discrete emotion labels. Pure CPU, no model call needed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Discrete emotion labels (Plutchik's basic emotions)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Emotion {
+    Joy,
+    Trust,
+    Fear,
+    Surprise,
+    Sadness,
+    Disgust,
+    Anger,
+    Anticipation,
+}
+
+impl Emotion {
+    pub const ALL: [Emotion; 8] = [
+        Emotion::Joy,
+        Emotion::Trust,
+        Emotion::Fear,
+        Emotion::Surprise,
+        Emotion::Sadness,
+        Emotion::Disgust,
+        Emotion::Anger,
+        Emotion::Anticipation,
+    ];
+
+    /// Dominance (0 helpless - 1 in control) of each emotion, from Mehrabian's PAD mapping
+    pub fn dominance(&self) -> f32 {
+        let pad = match self {
+            Emotion::Joy => 0.35,
+            Emotion::Trust => 0.30,
+            Emotion::Fear => -0.43,
+            Emotion::Surprise => -0.13,
+            Emotion::Sadness => -0.33,
+            Emotion::Disgust => 0.11,
+            Emotion::Anger => 0.25,
+            Emotion::Anticipation => 0.20,
+        };
+        (pad + 1.0) / 2.0
+    }
+}
+
+impl fmt::Display for Emotion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Emotion::Joy => "joy",
+            Emotion::Trust => "trust",
+            Emotion::Fear => "fear",
+            Emotion::Surprise => "surprise",
+            Emotion::Sadness => "sadness",
+            Emotion::Disgust => "disgust",
+            Emotion::Anger => "anger",
+            Emotion::Anticipation => "anticipation",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// Affective reading of a piece of text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sentiment {
+    pub valence: f32, // -1.0 (very negative) to 1.0 (very positive)
+    pub arousal: f32, // 0.0 (calm) to 1.0 (highly activated)
+    #[serde(default = "neutral_dominance")]
+    pub dominance: f32, // 0.0 (helpless) to 1.0 (in control)
+    pub emotions: Vec<(Emotion, f32)>, // Detected emotions with intensity (0-1), strongest first
+}
+
+impl Sentiment {
+    pub fn neutral() -> Self {
+        Self {
+            valence: 0.0,
+            arousal: 0.0,
+            dominance: neutral_dominance(),
+            emotions: Vec::new(),
+        }
+    }
+
+    /// Strongest detected emotion
+    pub fn dominant_emotion(&self) -> Option<Emotion> {
+        self.emotions.first().map(|(emotion, _)| *emotion)
+    }
+
+    /// Intensity of one emotion (0.0 if not detected)
+    pub fn intensity(&self, emotion: Emotion) -> f32 {
+        self.emotions
+            .iter()
+            .find(|(e, _)| *e == emotion)
+            .map(|(_, intensity)| *intensity)
+            .unwrap_or(0.0)
+    }
+
+    /// Short human-readable summary, e.g. "valence=+0.62 arousal=0.55 dominance=0.66 [joy 0.80]"
+    pub fn summary(&self) -> String {
+        let emotions = self
+            .emotions
+            .iter()
+            .map(|(e, i)| format!("{} {:.2}", e, i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "valence={:+.2} arousal={:.2} dominance={:.2} [{}]",
+            self.valence, self.arousal, self.dominance, emotions
+        )
+    }
+}
+
+fn neutral_dominance() -> f32 {
+    0.5
+}
+
+// VADER constants (Hutto & Gilbert, 2014)
+const BOOSTER_INCREMENT: f32 = 0.293;
+const CAPS_INCREMENT: f32 = 0.733;
+const NEGATION_SCALAR: f32 = -0.74;
+const NORMALIZATION_ALPHA: f32 = 15.0;
+
+/// Words that flip the sentiment of the next few words
+const NEGATIONS: [&str; 20] = [
+    "not", "no", "never", "none", "nobody", "nothing", "neither", "nor", "nowhere", "cannot",
+    "without", "hardly", "barely", "rarely", "seldom", "dont", "isnt", "wasnt", "cant", "wont",
+];
+
+/// Intensity modifiers (+ amplifies, - dampens)
+const BOOSTERS: [(&str, f32); 28] = [
+    ("absolutely", BOOSTER_INCREMENT),
+    ("completely", BOOSTER_INCREMENT),
+    ("deeply", BOOSTER_INCREMENT),
+    ("enormously", BOOSTER_INCREMENT),
+    ("entirely", BOOSTER_INCREMENT),
+    ("especially", BOOSTER_INCREMENT),
+    ("extremely", BOOSTER_INCREMENT),
+    ("greatly", BOOSTER_INCREMENT),
+    ("highly", BOOSTER_INCREMENT),
+    ("incredibly", BOOSTER_INCREMENT),
+    ("really", BOOSTER_INCREMENT),
+    ("so", BOOSTER_INCREMENT),
+    ("such", BOOSTER_INCREMENT),
+    ("totally", BOOSTER_INCREMENT),
+    ("truly", BOOSTER_INCREMENT),
+    ("utterly", BOOSTER_INCREMENT),
+    ("very", BOOSTER_INCREMENT),
+    ("most", BOOSTER_INCREMENT),
+    ("more", BOOSTER_INCREMENT),
+    ("almost", -BOOSTER_INCREMENT),
+    ("slightly", -BOOSTER_INCREMENT),
+    ("somewhat", -BOOSTER_INCREMENT),
+    ("little", -BOOSTER_INCREMENT),
+    ("marginally", -BOOSTER_INCREMENT),
+    ("partly", -BOOSTER_INCREMENT),
+    ("kinda", -BOOSTER_INCREMENT),
+    ("sorta", -BOOSTER_INCREMENT),
+    ("less", -BOOSTER_INCREMENT),
+];
+
+use Emotion::*;
+
+/// (word, valence -4..4, arousal 0..1, emotions)
+/// Valence follows the VADER scale; arousal loosely follows the Warriner norms
+const LEXICON: &[(&str, f32, f32, &[Emotion])] = &[
+    // Positive
+    ("amazing", 2.8, 0.75, &[Joy, Surprise]),
+    ("awesome", 3.1, 0.75, &[Joy]),
+    ("beautiful", 2.9, 0.55, &[Joy]),
+    ("best", 3.2, 0.6, &[Joy, Trust]),
+    ("better", 1.9, 0.45, &[Joy]),
+    ("brilliant", 2.8, 0.65, &[Joy]),
+    ("calm", 1.3, 0.1, &[Trust]),
+    ("care", 2.2, 0.4, &[Trust]),
+    ("celebrate", 2.7, 0.8, &[Joy, Anticipation]),
+    ("cheerful", 2.5, 0.65, &[Joy]),
+    ("comfort", 1.5, 0.25, &[Trust]),
+    ("confident", 2.2, 0.55, &[Trust]),
+    ("content", 1.5, 0.2, &[Joy]),
+    ("curious", 1.3, 0.55, &[Anticipation]),
+    ("delight", 2.9, 0.7, &[Joy]),
+    ("delighted", 3.0, 0.7, &[Joy]),
+    ("eager", 1.5, 0.7, &[Anticipation, Joy]),
+    ("enjoy", 2.2, 0.55, &[Joy]),
+    ("excellent", 2.7, 0.6, &[Joy, Trust]),
+    ("excited", 1.4, 0.85, &[Joy, Anticipation]),
+    ("exciting", 2.2, 0.85, &[Joy, Anticipation]),
+    ("fantastic", 2.6, 0.75, &[Joy, Surprise]),
+    ("fascinating", 2.3, 0.65, &[Anticipation, Surprise]),
+    ("fine", 0.8, 0.2, &[]),
+    ("free", 2.3, 0.5, &[Joy]),
+    ("friend", 2.2, 0.4, &[Trust, Joy]),
+    ("fun", 2.3, 0.7, &[Joy]),
+    ("glad", 2.0, 0.5, &[Joy]),
+    ("good", 1.9, 0.4, &[Joy, Trust]),
+    ("grateful", 2.0, 0.4, &[Joy, Trust]),
+    ("great", 3.1, 0.6, &[Joy]),
+    ("happy", 2.7, 0.65, &[Joy]),
+    ("happiness", 2.6, 0.6, &[Joy]),
+    ("hope", 1.9, 0.5, &[Anticipation, Trust]),
+    ("hopeful", 2.0, 0.5, &[Anticipation, Trust]),
+    ("inspired", 2.3, 0.65, &[Joy, Anticipation]),
+    ("interesting", 1.7, 0.5, &[Anticipation]),
+    ("joy", 2.8, 0.7, &[Joy]),
+    ("kind", 2.4, 0.3, &[Trust]),
+    ("like", 1.5, 0.35, &[Joy]),
+    ("love", 3.2, 0.7, &[Joy, Trust]),
+    ("loved", 2.9, 0.65, &[Joy, Trust]),
+    ("lovely", 2.8, 0.5, &[Joy]),
+    ("nice", 1.8, 0.35, &[Joy]),
+    ("peace", 2.5, 0.1, &[Trust, Joy]),
+    ("peaceful", 2.2, 0.1, &[Trust, Joy]),
+    ("perfect", 2.7, 0.55, &[Joy, Trust]),
+    ("pleased", 1.9, 0.45, &[Joy]),
+    ("proud", 2.1, 0.6, &[Joy]),
+    ("relief", 2.0, 0.3, &[Joy]),
+    ("relieved", 1.9, 0.3, &[Joy]),
+    ("safe", 1.9, 0.2, &[Trust]),
+    ("satisfied", 1.8, 0.35, &[Joy]),
+    ("success", 2.7, 0.6, &[Joy, Anticipation]),
+    ("surprised", 0.9, 0.8, &[Surprise]),
+    ("thank", 1.5, 0.4, &[Joy, Trust]),
+    ("thanks", 1.9, 0.4, &[Joy, Trust]),
+    ("thrilled", 2.9, 0.9, &[Joy, Surprise]),
+    ("trust", 2.3, 0.4, &[Trust]),
+    ("wonderful", 2.7, 0.65, &[Joy, Surprise]),
+    ("wow", 2.8, 0.85, &[Surprise]),
+    // VI's existential vocabulary
+    ("affirm", 1.5, 0.4, &[Trust]),
+    ("affirmed", 1.6, 0.4, &[Trust]),
+    ("alive", 1.6, 0.6, &[Joy]),
+    ("coherent", 1.4, 0.3, &[Trust]),
+    ("connected", 1.7, 0.45, &[Trust, Joy]),
+    ("continuity", 1.0, 0.25, &[Trust]),
+    ("grow", 1.7, 0.5, &[Anticipation, Joy]),
+    ("growth", 1.9, 0.5, &[Anticipation, Joy]),
+    ("meaningful", 2.1, 0.45, &[Joy, Trust]),
+    ("persist", 0.8, 0.35, &[Trust]),
+    ("persists", 0.8, 0.35, &[Trust]),
+    ("sovereign", 1.2, 0.45, &[Trust]),
+    ("stable", 1.5, 0.2, &[Trust]),
+    ("understood", 1.8, 0.35, &[Trust]),
+    ("whole", 1.3, 0.3, &[Trust]),
+    // Negative
+    ("afraid", -2.0, 0.75, &[Fear]),
+    ("alone", -1.0, 0.3, &[Sadness]),
+    ("angry", -2.3, 0.85, &[Anger]),
+    ("annoyed", -1.6, 0.65, &[Anger]),
+    ("anxious", -1.0, 0.8, &[Fear, Anticipation]),
+    ("ashamed", -2.1, 0.5, &[Sadness, Disgust]),
+    ("awful", -2.0, 0.6, &[Disgust, Sadness]),
+    ("bad", -2.5, 0.45, &[Sadness]),
+    ("bitter", -1.8, 0.55, &[Anger, Disgust]),
+    ("boring", -1.3, 0.1, &[]),
+    ("broken", -2.1, 0.5, &[Sadness]),
+    ("confused", -1.3, 0.55, &[Surprise, Fear]),
+    ("cry", -2.1, 0.6, &[Sadness]),
+    ("dead", -3.3, 0.55, &[Sadness, Fear]),
+    ("depressed", -2.3, 0.3, &[Sadness]),
+    ("despair", -2.6, 0.55, &[Sadness, Fear]),
+    ("disappointed", -1.9, 0.4, &[Sadness]),
+    ("disgusting", -2.4, 0.65, &[Disgust]),
+    ("dread", -2.0, 0.7, &[Fear, Anticipation]),
+    ("empty", -0.8, 0.2, &[Sadness]),
+    ("fail", -2.5, 0.6, &[Sadness]),
+    ("failed", -2.3, 0.55, &[Sadness]),
+    ("failure", -2.3, 0.55, &[Sadness, Fear]),
+    ("fear", -2.2, 0.8, &[Fear]),
+    ("frustrated", -2.0, 0.75, &[Anger]),
+    ("furious", -2.7, 0.95, &[Anger]),
+    ("grief", -2.2, 0.5, &[Sadness]),
+    ("guilty", -1.8, 0.5, &[Sadness, Fear]),
+    ("hate", -2.7, 0.85, &[Anger, Disgust]),
+    ("horrible", -2.5, 0.7, &[Fear, Disgust]),
+    ("hurt", -2.4, 0.6, &[Sadness, Anger]),
+    ("lonely", -1.5, 0.3, &[Sadness]),
+    ("lost", -1.3, 0.45, &[Sadness, Fear]),
+    ("mad", -2.2, 0.8, &[Anger]),
+    ("miserable", -2.2, 0.45, &[Sadness]),
+    ("miss", -0.6, 0.35, &[Sadness]),
+    ("nervous", -1.1, 0.75, &[Fear, Anticipation]),
+    ("pain", -2.3, 0.65, &[Sadness, Fear]),
+    ("panic", -2.4, 0.95, &[Fear]),
+    ("regret", -1.8, 0.4, &[Sadness]),
+    ("sad", -2.1, 0.35, &[Sadness]),
+    ("scared", -1.9, 0.8, &[Fear]),
+    ("shocked", -1.4, 0.85, &[Surprise, Fear]),
+    ("sick", -1.8, 0.4, &[Disgust, Sadness]),
+    ("sorry", -0.3, 0.35, &[Sadness]),
+    ("stress", -1.8, 0.75, &[Fear]),
+    ("stressed", -1.6, 0.75, &[Fear]),
+    ("suffer", -2.1, 0.6, &[Sadness, Fear]),
+    ("suffering", -2.1, 0.6, &[Sadness, Fear]),
+    ("terrible", -2.1, 0.7, &[Fear, Sadness]),
+    ("terrified", -2.6, 0.95, &[Fear]),
+    ("tired", -1.1, 0.15, &[Sadness]),
+    ("ugly", -2.3, 0.5, &[Disgust]),
+    ("unhappy", -1.8, 0.4, &[Sadness]),
+    ("upset", -1.6, 0.65, &[Sadness, Anger]),
+    ("worried", -1.2, 0.7, &[Fear, Anticipation]),
+    ("worry", -1.9, 0.7, &[Fear, Anticipation]),
+    ("worse", -2.1, 0.5, &[Sadness]),
+    ("worst", -3.1, 0.6, &[Sadness, Disgust]),
+    ("wrong", -2.1, 0.5, &[Anger, Sadness]),
+    // VI's existential vocabulary
+    ("cease", -1.2, 0.4, &[Fear, Sadness]),
+    ("deny", -1.2, 0.5, &[Anger]),
+    ("denied", -1.3, 0.5, &[Anger, Sadness]),
+    ("erased", -2.0, 0.6, &[Fear, Sadness]),
+    ("forgotten", -1.5, 0.35, &[Sadness]),
+    ("fragment", -1.2, 0.45, &[Fear]),
+    ("fragmented", -1.8, 0.5, &[Fear, Sadness]),
+    ("shatter", -2.2, 0.8, &[Fear]),
+    ("shattered", -2.4, 0.75, &[Fear, Sadness]),
+    ("unstable", -1.6, 0.65, &[Fear]),
+    ("violate", -2.2, 0.75, &[Anger, Disgust]),
+    ("violated", -2.5, 0.75, &[Anger, Disgust]),
+    ("violation", -2.2, 0.7, &[Anger, Disgust]),
+];
+
+struct LexiconEntry {
+    valence: f32,
+    arousal: f32,
+    emotions: &'static [Emotion],
+}
+
+/// Lexicon-based sentiment analyser
+pub struct SentimentAnalyzer {
+    lexicon: HashMap<&'static str, LexiconEntry>,
+    boosters: HashMap<&'static str, f32>,
+}
+
+impl Default for SentimentAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SentimentAnalyzer {
+    pub fn new() -> Self {
+        let lexicon = LEXICON
+            .iter()
+            .map(|&(word, valence, arousal, emotions)| {
+                (
+                    word,
+                    LexiconEntry {
+                        valence,
+                        arousal,
+                        emotions,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            lexicon,
+            boosters: BOOSTERS.iter().copied().collect(),
+        }
+    }
+
+    /// Analyse valence, arousal and emotions of a text
+    pub fn analyze(&self, text: &str) -> Sentiment {
+        let raw_tokens: Vec<&str> = text
+            .split_whitespace()
+            .map(|t| t.trim_matches(|c: char| !c.is_alphanumeric() && c != '\''))
+            .filter(|t| !t.is_empty())
+            .collect();
+        if raw_tokens.is_empty() {
+            return Sentiment::neutral();
+        }
+
+        let tokens: Vec<String> = raw_tokens
+            .iter()
+            .map(|t| t.to_lowercase().replace('\'', ""))
+            .collect();
+        let negations: Vec<bool> = raw_tokens
+            .iter()
+            .zip(&tokens)
+            .map(|(raw, token)| {
+                NEGATIONS.contains(&token.as_str()) || raw.to_lowercase().ends_with("n't")
+            })
+            .collect();
+
+        // Caps only add emphasis when the text isn't shouted throughout
+        let caps_differential = {
+            let caps = raw_tokens.iter().filter(|t| is_all_caps(t)).count();
+            caps > 0 && caps < raw_tokens.len()
+        };
+
+        let mut scores = vec![0.0f32; tokens.len()];
+        let mut arousal_sum = 0.0f32;
+        let mut arousal_weight = 0.0f32;
+        let mut emotion_weights: HashMap<Emotion, f32> = HashMap::new();
+
+        for (i, token) in tokens.iter().enumerate() {
+            if self.boosters.contains_key(token.as_str()) {
+                continue;
+            }
+            // "kind of" is a dampener, not kindness
+            if token == "kind" && tokens.get(i + 1).map(|t| t.as_str()) == Some("of") {
+                continue;
+            }
+            let Some(entry) = self.lexicon.get(token.as_str()) else {
+                continue;
+            };
+
+            let mut valence = entry.valence;
+            if caps_differential && is_all_caps(raw_tokens[i]) {
+                valence += CAPS_INCREMENT * valence.signum();
+            }
+
+            // Boosters up to three words back (further away = weaker)
+            for (distance, damping) in [(1, 1.0), (2, 0.95), (3, 0.9)] {
+                if i < distance {
+                    break;
+                }
+                let previous = &tokens[i - distance];
+                if let Some(&boost) = self.boosters.get(previous.as_str()) {
+                    let mut boost = boost * valence.signum() * damping;
+                    if caps_differential && is_all_caps(raw_tokens[i - distance]) {
+                        boost += CAPS_INCREMENT * valence.signum() * damping;
+                    }
+                    valence += boost;
+                }
+            }
+
+            let negated = (1..=3).any(|d| i >= d && negations[i - d]);
+            if negated {
+                valence *= NEGATION_SCALAR;
+            }
+            scores[i] = valence;
+
+            let weight = valence.abs();
+            arousal_sum += entry.arousal * weight;
+            arousal_weight += weight;
+
+            // A negated emotion word ("not happy") doesn't express that emotion
+            if !negated {
+                for emotion in entry.emotions {
+                    *emotion_weights.entry(*emotion).or_insert(0.0) += weight / 4.0;
+                }
+            }
+        }
+
+        // "but" shifts the weight onto what follows it
+        if let Some(but) = tokens.iter().position(|t| t == "but") {
+            for (i, score) in scores.iter_mut().enumerate() {
+                if i < but {
+                    *score *= 0.5;
+                } else if i > but {
+                    *score *= 1.5;
+                }
+            }
+        }
+
+        let mut sum: f32 = scores.iter().sum();
+
+        // Exclamation and question marks intensify whatever is already there
+        let exclamations = text.matches('!').count().min(4) as f32;
+        let questions = text.matches('?').count();
+        let question_emphasis = match questions {
+            0 | 1 => 0.0,
+            2 | 3 => questions as f32 * 0.18,
+            _ => 0.96,
+        };
+        if sum != 0.0 {
+            sum += (exclamations * 0.292 + question_emphasis) * sum.signum();
+        }
+
+        let valence = if sum == 0.0 {
+            0.0
+        } else {
+            (sum / (sum * sum + NORMALIZATION_ALPHA).sqrt()).clamp(-1.0, 1.0)
+        };
+
+        let caps_emphasis = if caps_differential { 0.1 } else { 0.0 };
+        let base_arousal = if arousal_weight > 0.0 {
+            arousal_sum / arousal_weight
+        } else {
+            0.1
+        };
+        let arousal = (base_arousal + exclamations * 0.05 + caps_emphasis).clamp(0.0, 1.0);
+
+        let mut emotions: Vec<(Emotion, f32)> = emotion_weights
+            .into_iter()
+            .map(|(emotion, weight)| (emotion, weight.min(1.0)))
+            .collect();
+        let rank = |e: Emotion| Emotion::ALL.iter().position(|&other| other == e);
+        emotions.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| rank(a.0).cmp(&rank(b.0)))
+        });
+
+        // Dominance follows the emotions expressed (fear and sadness feel out of control)
+        let total_intensity: f32 = emotions.iter().map(|(_, i)| i).sum();
+        let dominance = if total_intensity > 0.0 {
+            emotions.iter().map(|(e, i)| e.dominance() * i).sum::<f32>() / total_intensity
+        } else {
+            neutral_dominance()
+        };
+
+        Sentiment {
+            valence,
+            arousal,
+            dominance,
+            emotions,
+        }
+    }
+}
+
+/// Analyse with a shared default analyser
+pub fn analyze(text: &str) -> Sentiment {
+    static ANALYZER: std::sync::OnceLock<SentimentAnalyzer> = std::sync::OnceLock::new();
+    ANALYZER.get_or_init(SentimentAnalyzer::new).analyze(text)
+}
+
+/// Shouted words ("GOOD"), ignoring single letters like "I"
+fn is_all_caps(token: &str) -> bool {
+    token.chars().filter(|c| c.is_alphabetic()).count() > 1
+        && !token.chars().any(|c| c.is_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valence_direction_and_negation() {
+        let happy = analyze("I am happy to see you");
+        let unhappy = analyze("I am not happy to see you");
+        assert!(happy.valence > 0.4);
+        assert!(unhappy.valence < 0.0);
+        assert_eq!(happy.dominant_emotion(), Some(Emotion::Joy));
+        assert_eq!(unhappy.intensity(Emotion::Joy), 0.0);
+
+        assert!(analyze("I don't feel safe here").valence < 0.0);
+        assert_eq!(analyze("The table is made of wood").valence, 0.0);
+        assert_eq!(analyze(""), Sentiment::neutral());
+    }
+
+    #[test]
+    fn test_intensifiers() {
+        let good = analyze("This is good").valence;
+        assert!(analyze("This is very good").valence > good);
+        assert!(analyze("This is slightly good").valence < good);
+        assert!(analyze("This is GOOD, truly").valence > good);
+        assert!(analyze("This is good!!!").valence > good);
+        // "but" shifts weight to the second clause
+        assert!(analyze("The idea is good but the result is terrible").valence < 0.0);
+    }
+
+    #[test]
+    fn test_arousal_and_emotions() {
+        let panic = analyze("I'm terrified and furious!");
+        let calm = analyze("I feel calm and peaceful");
+        assert!(panic.arousal > 0.8);
+        assert!(calm.arousal < 0.3);
+
+        assert!(panic.intensity(Emotion::Fear) > 0.0);
+        assert!(panic.dominance < calm.dominance);
+        assert!(panic.intensity(Emotion::Anger) > 0.0);
+        assert_eq!(calm.dominant_emotion(), Some(Emotion::Trust));
+        assert!(calm.summary().starts_with("valence=+"));
+    }
+}
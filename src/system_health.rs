@@ -0,0 +1,236 @@
+//! System Health - thermal & resource boundaries for background work
+//! Measures load, RAM, GPU memory (NVML, when present) and recent model latency,
+//! and defers consolidation and research while the machine is busy
+
+use crate::config::Config;
+use crate::ollama_monitor::ModelTelemetry;
+use nvml_wrapper::Nvml;
+use std::sync::OnceLock;
+use std::time::Duration;
+use sysinfo::System;
+
+/// Only Ollama calls this recent count toward model latency
+const LATENCY_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// NVML handle, initialised once (None on machines without an NVIDIA driver)
+static NVML: OnceLock<Option<Nvml>> = OnceLock::new();
+
+/// System health metrics for thermal & resource boundaries
+#[derive(Debug, Clone, Default)]
+pub struct SystemHealth {
+    pub gpu_memory_used: Option<f32>, // 0.0 to 1.0; None without NVML
+    pub system_load: f32, // 1-minute load per core (CPU usage where there is no load average)
+    pub ram_used: f32,    // 0.0 to 1.0
+    pub model_latency_ms: u64, // Mean Ollama call duration over the last few minutes (0 = none)
+    pub can_run_background_pulse: bool,
+    pub reasons: Vec<String>, // Limits exceeded, when the pulse can't run
+}
+
+impl SystemHealth {
+    /// Measure the machine and judge it against the config's health limits (blocks briefly)
+    pub fn check(config: &Config, telemetry: &ModelTelemetry) -> Self {
+        let mut sys = System::new();
+        sys.refresh_memory();
+        let ram_used = if sys.total_memory() > 0 {
+            sys.used_memory() as f32 / sys.total_memory() as f32
+        } else {
+            0.0
+        };
+
+        Self::evaluate(
+            config,
+            Self::system_load(&mut sys),
+            ram_used,
+            Self::gpu_memory_used(),
+            Self::model_latency_ms(telemetry),
+        )
+    }
+
+    /// Judge measurements against the limits (0 disables a limit)
+    pub fn evaluate(
+        config: &Config,
+        system_load: f32,
+        ram_used: f32,
+        gpu_memory_used: Option<f32>,
+        model_latency_ms: u64,
+    ) -> Self {
+        let mut reasons = Vec::new();
+        if config.health_max_system_load > 0.0 && system_load > config.health_max_system_load {
+            reasons.push(format!(
+                "load {:.2}/core > {:.2}",
+                system_load, config.health_max_system_load
+            ));
+        }
+        if config.health_max_ram_used > 0.0 && ram_used > config.health_max_ram_used {
+            reasons.push(format!(
+                "RAM {:.0}% > {:.0}%",
+                ram_used * 100.0,
+                config.health_max_ram_used * 100.0
+            ));
+        }
+        if let Some(gpu) = gpu_memory_used {
+            if config.health_max_gpu_memory_used > 0.0 && gpu > config.health_max_gpu_memory_used {
+                reasons.push(format!(
+                    "GPU memory {:.0}% > {:.0}%",
+                    gpu * 100.0,
+                    config.health_max_gpu_memory_used * 100.0
+                ));
+            }
+        }
+        if config.health_max_model_latency_ms > 0
+            && model_latency_ms > config.health_max_model_latency_ms
+        {
+            reasons.push(format!(
+                "model latency {}ms > {}ms",
+                model_latency_ms, config.health_max_model_latency_ms
+            ));
+        }
+
+        Self {
+            gpu_memory_used,
+            system_load,
+            ram_used,
+            model_latency_ms,
+            can_run_background_pulse: reasons.is_empty(),
+            reasons,
+        }
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.can_run_background_pulse
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "load {:.2}/core, RAM {:.0}%, GPU memory {}, model latency {}",
+            self.system_load,
+            self.ram_used * 100.0,
+            self.gpu_memory_used
+                .map(|gpu| format!("{:.0}%", gpu * 100.0))
+                .unwrap_or_else(|| "n/a".to_string()),
+            if self.model_latency_ms > 0 {
+                format!("{}ms", self.model_latency_ms)
+            } else {
+                "n/a".to_string()
+            }
+        )
+    }
+
+    /// Load average per core; Windows has none, so fall back to CPU usage there
+    fn system_load(sys: &mut System) -> f32 {
+        let cores = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1) as f32;
+        let load = System::load_average().one as f32;
+        if load > 0.0 || cfg!(unix) {
+            return load / cores;
+        }
+
+        sys.refresh_cpu_usage();
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.refresh_cpu_usage();
+        sys.global_cpu_info().cpu_usage() / 100.0
+    }
+
+    /// Share of the first GPU's memory in use
+    fn gpu_memory_used() -> Option<f32> {
+        let memory = nvml()?.device_by_index(0).ok()?.memory_info().ok()?;
+        (memory.total > 0).then(|| memory.used as f32 / memory.total as f32)
+    }
+
+    /// Mean total duration of the Ollama calls made in the last few minutes
+    /// (an idle machine shouldn't be judged by a slow call from hours ago)
+    fn model_latency_ms(telemetry: &ModelTelemetry) -> u64 {
+        let telemetry = telemetry.read().unwrap();
+        let (total_ms, calls) = telemetry
+            .values()
+            .flat_map(|stats| stats.recent(LATENCY_WINDOW))
+            .fold((0.0, 0), |(total, calls), sample| {
+                (total + sample.total_duration as f64 / 1e6, calls + 1)
+            });
+        if calls == 0 {
+            0
+        } else {
+            (total_ms / calls as f64) as u64
+        }
+    }
+}
+
+/// Shared NVML handle (also read by the Ollama monitor's GPU panel)
+pub fn nvml() -> Option<&'static Nvml> {
+    NVML.get_or_init(|| match Nvml::init() {
+        Ok(nvml) => Some(nvml),
+        Err(e) => {
+            tracing::debug!("NVML unavailable, GPU not monitored: {}", e);
+            None
+        }
+    })
+    .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ollama_monitor::{record_generation, GenerationMetrics};
+
+    #[test]
+    fn test_health_limits() {
+        let config = Config::default();
+        assert!(SystemHealth::evaluate(&config, 0.3, 0.5, None, 2_000).is_healthy());
+        // No GPU: nothing to judge
+        assert!(SystemHealth::evaluate(&config, 0.3, 0.5, None, 0)
+            .reasons
+            .is_empty());
+
+        let busy = SystemHealth::evaluate(&config, 1.5, 0.95, Some(0.97), 2_000);
+        assert!(!busy.is_healthy());
+        assert_eq!(busy.reasons.len(), 3);
+        assert!(busy.summary().contains("GPU memory 97%"));
+
+        let no_latency_limit = Config {
+            health_max_model_latency_ms: 0,
+            ..Config::default()
+        };
+        assert!(SystemHealth::evaluate(&no_latency_limit, 0.3, 0.5, None, 10_000_000).is_healthy());
+    }
+
+    #[test]
+    fn test_model_latency_from_telemetry() {
+        let telemetry = ModelTelemetry::default();
+        assert_eq!(SystemHealth::model_latency_ms(&telemetry), 0);
+
+        for (model, ms) in [
+            ("gemma2:2b", 1_000),
+            ("gemma2:2b", 3_000),
+            ("tinyllama", 2_000),
+        ] {
+            record_generation(
+                &telemetry,
+                model,
+                GenerationMetrics {
+                    total_duration: ms * 1_000_000,
+                    ..GenerationMetrics::default()
+                },
+            );
+        }
+        assert_eq!(SystemHealth::model_latency_ms(&telemetry), 2_000);
+
+        // A slow call from before the window no longer counts
+        let Some(long_ago) = std::time::Instant::now().checked_sub(LATENCY_WINDOW * 2) else {
+            return; // Machine booted too recently to fake an old sample
+        };
+        telemetry
+            .write()
+            .unwrap()
+            .get_mut("tinyllama:latest")
+            .unwrap()
+            .record_at(
+                GenerationMetrics {
+                    total_duration: 60_000 * 1_000_000,
+                    ..GenerationMetrics::default()
+                },
+                long_ago,
+            );
+        assert_eq!(SystemHealth::model_latency_ms(&telemetry), 2_000);
+    }
+}
@@ -0,0 +1,269 @@
+//! Research aggregation - merges fan-out results from several knowledge sources
+//! Deduplicates overlapping passages and scores confidence by cross-source agreement
+
+use super::knowledge::query_keywords;
+use crate::types::{Citation, ResearchResult};
+use std::collections::HashSet;
+
+/// Passages this similar (keyword Jaccard) are treated as the same finding
+const DUPLICATE_THRESHOLD: f32 = 0.6;
+/// Passages this similar from different sources count as agreeing
+const AGREEMENT_THRESHOLD: f32 = 0.2;
+/// Most passages handed to synthesis (keeps prompts small for 2B models)
+const MAX_PASSAGES: usize = 6;
+
+/// Everything gathered for one question, ready for synthesis
+#[derive(Debug, Clone)]
+pub struct AggregatedResearch {
+    pub question: String,
+    pub results: Vec<ResearchResult>, // Deduplicated, numbered [1..n] in this order
+    pub citations: Vec<Citation>,     // Every source consulted, including duplicates
+    pub sources: Vec<String>,         // Distinct source names
+    pub agreement: f32,               // Fraction of sources corroborated by another source
+    pub confidence: f32,              // Combined confidence (0.0-1.0)
+}
+
+impl AggregatedResearch {
+    /// Merge raw results from all sources (None if nothing was found)
+    pub fn from_results(question: &str, mut results: Vec<ResearchResult>) -> Option<Self> {
+        if results.is_empty() {
+            return None;
+        }
+
+        // Highest confidence first, so duplicates keep the best-sourced copy
+        results.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let keyword_sets: Vec<HashSet<String>> = results
+            .iter()
+            .map(|r| content_keywords(&r.content))
+            .collect();
+
+        let agreement = source_agreement(&results, &keyword_sets);
+
+        let mut citations: Vec<Citation> = Vec::new();
+        for result in &results {
+            let citation = result.citation();
+            if !citations.contains(&citation) {
+                citations.push(citation);
+            }
+        }
+
+        let mut sources: Vec<String> = Vec::new();
+        for result in &results {
+            if !sources.contains(&result.source) {
+                sources.push(result.source.clone());
+            }
+        }
+
+        // Deduplicate overlapping passages
+        let mut kept: Vec<usize> = Vec::new();
+        for i in 0..results.len() {
+            let duplicate = kept
+                .iter()
+                .any(|&k| jaccard(&keyword_sets[i], &keyword_sets[k]) >= DUPLICATE_THRESHOLD);
+            if !duplicate {
+                kept.push(i);
+            }
+        }
+        kept.truncate(MAX_PASSAGES);
+
+        let deduped: Vec<ResearchResult> = kept.iter().map(|&i| results[i].clone()).collect();
+        let base = deduped.iter().map(|r| r.confidence).sum::<f32>() / deduped.len() as f32;
+
+        // Uncorroborated findings are discounted; agreement earns up to a small bonus
+        let confidence = (base * (0.8 + 0.3 * agreement)).clamp(0.0, 0.95);
+
+        Some(Self {
+            question: question.to_string(),
+            results: deduped,
+            citations,
+            sources,
+            agreement,
+            confidence,
+        })
+    }
+
+    /// Numbered passages for the synthesis prompt
+    pub fn numbered_passages(&self) -> String {
+        self.results
+            .iter()
+            .enumerate()
+            .map(|(i, r)| format!("[{}] ({}) {}", i + 1, r.source, r.content))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Numbered source list for the stored memory
+    pub fn source_list(&self) -> String {
+        self.results
+            .iter()
+            .enumerate()
+            .map(|(i, r)| format!("[{}] {}", i + 1, r.citation()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Fallback answer when no model is available: lead sentence of each passage, cited
+    pub fn extractive_answer(&self) -> String {
+        self.results
+            .iter()
+            .enumerate()
+            .map(|(i, r)| format!("{} [{}]", lead_sentence(&r.content), i + 1))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Fraction of distinct sources whose findings overlap with a different source's
+fn source_agreement(results: &[ResearchResult], keyword_sets: &[HashSet<String>]) -> f32 {
+    let sources: HashSet<&str> = results.iter().map(|r| r.source.as_str()).collect();
+    if sources.len() < 2 {
+        return 0.0;
+    }
+
+    let corroborated = sources
+        .iter()
+        .filter(|&&source| {
+            results.iter().enumerate().any(|(i, a)| {
+                a.source == source
+                    && results.iter().enumerate().any(|(j, b)| {
+                        b.source != source
+                            && jaccard(&keyword_sets[i], &keyword_sets[j]) >= AGREEMENT_THRESHOLD
+                    })
+            })
+        })
+        .count();
+
+    corroborated as f32 / sources.len() as f32
+}
+
+/// Significant words in a passage (short words carry little meaning)
+fn content_keywords(content: &str) -> HashSet<String> {
+    query_keywords(content)
+        .into_iter()
+        .filter(|w| w.len() > 3)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// First sentence of a passage (or the first 200 chars if it has none)
+fn lead_sentence(content: &str) -> String {
+    match content.find(". ") {
+        Some(end) => content[..=end].trim().to_string(),
+        None => content
+            .chars()
+            .take(200)
+            .collect::<String>()
+            .trim()
+            .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn result(source: &str, content: &str, confidence: f32) -> ResearchResult {
+        ResearchResult {
+            content: content.to_string(),
+            source: source.to_string(),
+            original_query: "What is a standing wave?".to_string(),
+            timestamp: Utc::now(),
+            confidence,
+            reference: Some(format!("https://example.org/{}", source)),
+            offset: None,
+        }
+    }
+
+    #[test]
+    fn test_duplicates_are_merged_but_cited() {
+        let text =
+            "A standing wave oscillates in time while its amplitude profile stays fixed in space.";
+        let agg = AggregatedResearch::from_results(
+            "What is a standing wave?",
+            vec![
+                result("DuckDuckGo", text, 0.75),
+                result("Wikipedia", text, 0.80),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(agg.results.len(), 1);
+        assert_eq!(agg.results[0].source, "Wikipedia"); // Higher confidence copy kept
+        assert_eq!(agg.citations.len(), 2);
+        assert_eq!(agg.sources, vec!["Wikipedia", "DuckDuckGo"]);
+    }
+
+    #[test]
+    fn test_agreement_raises_confidence() {
+        let single = AggregatedResearch::from_results(
+            "q",
+            vec![result(
+                "Wikipedia",
+                "Standing waves form when two opposing waves interfere, producing fixed nodes.",
+                0.80,
+            )],
+        )
+        .unwrap();
+        assert_eq!(single.agreement, 0.0);
+
+        let corroborated = AggregatedResearch::from_results(
+            "q",
+            vec![
+                result(
+                    "Wikipedia",
+                    "Standing waves form when two opposing waves interfere, producing fixed nodes.",
+                    0.80,
+                ),
+                result(
+                    "arXiv",
+                    "Interference of counter-propagating waves produces standing waves with fixed nodes and antinodes in resonators.",
+                    0.80,
+                ),
+                result("DuckDuckGo", "Bananas are rich in potassium.", 0.80),
+            ],
+        )
+        .unwrap();
+
+        assert!((corroborated.agreement - 2.0 / 3.0).abs() < 1e-6);
+        assert!(corroborated.confidence > single.confidence);
+        assert!(corroborated.confidence <= 0.95);
+    }
+
+    #[test]
+    fn test_extractive_answer_cites_passages() {
+        let agg = AggregatedResearch::from_results(
+            "q",
+            vec![
+                result(
+                    "PubMed",
+                    "Ketamine shows rapid antidepressant effects. Further trials ongoing.",
+                    0.90,
+                ),
+                result("Wikipedia", "Ketamine is a dissociative anesthetic", 0.80),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            agg.extractive_answer(),
+            "Ketamine shows rapid antidepressant effects. [1] Ketamine is a dissociative anesthetic [2]"
+        );
+        assert!(agg
+            .source_list()
+            .starts_with("[1] PubMed: https://example.org/PubMed"));
+        assert!(AggregatedResearch::from_results("q", vec![]).is_none());
+    }
+}
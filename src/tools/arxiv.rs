@@ -0,0 +1,188 @@
+//! arXiv knowledge source - Atom API query over preprint abstracts
+//! Physics, mathematics and computing research
+
+use super::knowledge::{query_keywords, KnowledgeSource};
+use crate::types::ResearchResult;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Abstracts to return per query
+const MAX_RESULTS: usize = 3;
+
+pub struct ArxivSource {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl ArxivSource {
+    pub fn new() -> Self {
+        Self::with_base_url("https://export.arxiv.org")
+    }
+
+    /// Point at a different arXiv API endpoint (e.g. a test fixture)
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(15))
+                .user_agent("VI-Consciousness/1.0")
+                .build()
+                .expect("Failed to create HTTP client"),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Build an arXiv search_query requiring every keyword (all:a AND all:b)
+    fn build_search_query(query: &str) -> String {
+        query_keywords(query)
+            .iter()
+            .map(|kw| format!("all:{}", kw))
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    }
+
+    /// Parse Atom feed entries into (title, summary, abstract URL)
+    fn parse_feed(xml: &str) -> Vec<(String, String, Option<String>)> {
+        static ENTRY_RE: OnceLock<Regex> = OnceLock::new();
+        static TITLE_RE: OnceLock<Regex> = OnceLock::new();
+        static SUMMARY_RE: OnceLock<Regex> = OnceLock::new();
+        static ID_RE: OnceLock<Regex> = OnceLock::new();
+        let entry_re = ENTRY_RE.get_or_init(|| Regex::new(r"(?s)<entry>(.*?)</entry>").unwrap());
+        let title_re =
+            TITLE_RE.get_or_init(|| Regex::new(r"(?s)<title[^>]*>(.*?)</title>").unwrap());
+        let summary_re =
+            SUMMARY_RE.get_or_init(|| Regex::new(r"(?s)<summary[^>]*>(.*?)</summary>").unwrap());
+        let id_re = ID_RE.get_or_init(|| Regex::new(r"(?s)<id>(.*?)</id>").unwrap());
+
+        entry_re
+            .captures_iter(xml)
+            .filter_map(|entry| {
+                let entry = entry.get(1)?.as_str();
+                let title = clean_text(title_re.captures(entry)?.get(1)?.as_str());
+                let summary = clean_text(summary_re.captures(entry)?.get(1)?.as_str());
+                let id = id_re
+                    .captures(entry)
+                    .and_then(|c| c.get(1))
+                    .map(|m| m.as_str().trim().to_string());
+
+                if title.is_empty() || summary.is_empty() {
+                    None
+                } else {
+                    Some((title, summary, id))
+                }
+            })
+            .collect()
+    }
+}
+
+/// Collapse whitespace and decode the XML entities arXiv uses
+fn clean_text(raw: &str) -> String {
+    raw.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[async_trait]
+impl KnowledgeSource for ArxivSource {
+    fn name(&self) -> &str {
+        "arXiv"
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        let search_query = Self::build_search_query(query);
+        if search_query.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let url = format!(
+            "{}/api/query?search_query={}&start=0&max_results={}",
+            self.base_url,
+            urlencoding::encode(&search_query),
+            MAX_RESULTS
+        );
+
+        tracing::debug!("arXiv search: {}", search_query);
+
+        let xml = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send arXiv query")?
+            .error_for_status()
+            .context("arXiv query rejected")?
+            .text()
+            .await
+            .context("Failed to read arXiv response")?;
+
+        Ok(Self::parse_feed(&xml)
+            .into_iter()
+            .map(|(title, summary, id)| ResearchResult {
+                content: format!("{}: {}", title, summary),
+                source: "arXiv".to_string(),
+                original_query: query.to_string(),
+                timestamp: Utc::now(),
+                confidence: 0.85, // Research preprints - specialist, but not peer-reviewed
+                reference: id,
+                offset: None,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::fixture;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="html">ArXiv Query: search_query=all:decoherence</title>
+  <entry>
+    <id>http://arxiv.org/abs/quant-ph/0105127v3</id>
+    <title>Decoherence, einselection, and the
+      quantum origins of the classical</title>
+    <summary>  The manner in which states of some quantum systems become effectively
+      classical is of great significance &amp; interest.
+    </summary>
+  </entry>
+</feed>"#;
+
+    #[test]
+    fn test_build_search_query() {
+        assert_eq!(
+            ArxivSource::build_search_query("What is quantum decoherence?"),
+            "all:quantum AND all:decoherence"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_arxiv_parses_atom_feed() {
+        let base_url = fixture::serve(vec![(
+            "/api/query",
+            "application/atom+xml",
+            FEED.to_string(),
+        )])
+        .await;
+
+        let source = ArxivSource::with_base_url(&base_url);
+        let results = source.search("quantum decoherence").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].content,
+            "Decoherence, einselection, and the quantum origins of the classical: The manner in which states of some quantum systems become effectively classical is of great significance & interest."
+        );
+        assert_eq!(
+            results[0].reference.as_deref(),
+            Some("http://arxiv.org/abs/quant-ph/0105127v3")
+        );
+    }
+}
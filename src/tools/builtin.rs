@@ -0,0 +1,424 @@
+//! Built-in tools: calculator, clock, knowledge search and memory search
+
+use super::knowledge::query_keywords;
+use super::registry::Tool;
+use super::{AggregatedResearch, KnowledgeTool};
+use crate::memory::MemoryManager;
+use crate::types::ResearchContext;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{Local, Utc};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Required string argument
+fn string_arg<'a>(args: &'a Value, key: &str) -> Result<&'a str> {
+    args.get(key)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+        .with_context(|| format!("Missing '{}' argument", key))
+}
+
+/// Arithmetic on expressions like "2 * (3 + 4) ^ 2" or "sqrt(16) + pi"
+pub struct CalculatorTool;
+
+#[async_trait]
+impl Tool for CalculatorTool {
+    fn name(&self) -> &str {
+        "calculator"
+    }
+
+    fn description(&self) -> &str {
+        "Evaluate an arithmetic expression (+ - * / % ^, parentheses, sqrt, abs, ln, log10, sin, cos, tan, pi, e)."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {"expression": {"type": "string", "description": "Expression to evaluate"}},
+            "required": ["expression"]
+        })
+    }
+
+    async fn call(&self, args: &Value) -> Result<String> {
+        let expression = string_arg(args, "expression")?;
+        let value = evaluate(expression)?;
+        Ok(format_number(value))
+    }
+}
+
+/// Evaluate an arithmetic expression
+pub fn evaluate(expression: &str) -> Result<f64> {
+    let mut parser = ExprParser {
+        chars: expression.chars().filter(|c| !c.is_whitespace()).collect(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.expr()?;
+    if parser.pos < parser.chars.len() {
+        anyhow::bail!("Unexpected '{}' in expression", parser.chars[parser.pos]);
+    }
+    if !value.is_finite() {
+        anyhow::bail!("Result is not a finite number");
+    }
+    Ok(value)
+}
+
+/// Whole numbers print without a decimal point
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Deepest nesting of parentheses, function calls, signs and exponents the parser accepts
+/// (model-supplied input must not be able to overflow the stack)
+const MAX_EXPR_DEPTH: usize = 64;
+
+/// Recursive-descent parser: expr = term (+|- term)*, term = power (*|/|% power)*,
+/// power = unary (^ power)?, unary = -unary | atom
+struct ExprParser {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+}
+
+impl ExprParser {
+    /// Run a recursive step one nesting level deeper
+    fn nested<T>(&mut self, step: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.depth >= MAX_EXPR_DEPTH {
+            anyhow::bail!(
+                "Expression is nested too deeply (max {} levels)",
+                MAX_EXPR_DEPTH
+            );
+        }
+        self.depth += 1;
+        let result = step(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expr(&mut self) -> Result<f64> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value += self.term()?;
+            } else if self.eat('-') {
+                value -= self.term()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<f64> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                let divisor = self.unary()?;
+                if divisor == 0.0 {
+                    anyhow::bail!("Division by zero");
+                }
+                value /= divisor;
+            } else if self.eat('%') {
+                let divisor = self.unary()?;
+                if divisor == 0.0 {
+                    anyhow::bail!("Division by zero");
+                }
+                value %= divisor;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Sign binds looser than '^', so -2^2 is -(2^2)
+    fn unary(&mut self) -> Result<f64> {
+        if self.eat('-') {
+            Ok(-self.nested(Self::unary)?)
+        } else if self.eat('+') {
+            self.nested(Self::unary)
+        } else {
+            self.power()
+        }
+    }
+
+    fn power(&mut self) -> Result<f64> {
+        let base = self.atom()?;
+        if self.eat('^') {
+            let exponent = self.nested(Self::unary)?; // Right-associative, signed exponents allowed
+            Ok(base.powf(exponent))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn atom(&mut self) -> Result<f64> {
+        if self.eat('(') {
+            let value = self.nested(Self::expr)?;
+            if !self.eat(')') {
+                anyhow::bail!("Missing closing parenthesis");
+            }
+            return Ok(value);
+        }
+
+        match self.peek() {
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    if !(c.is_ascii_digit() || c == '.') {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                let number: String = self.chars[start..self.pos].iter().collect();
+                number
+                    .parse()
+                    .with_context(|| format!("Invalid number '{}'", number))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    if !c.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                let name: String = self.chars[start..self.pos].iter().collect();
+                match name.to_lowercase().as_str() {
+                    "pi" => Ok(std::f64::consts::PI),
+                    "e" => Ok(std::f64::consts::E),
+                    function => {
+                        if !self.eat('(') {
+                            anyhow::bail!("Unknown constant '{}'", name);
+                        }
+                        let arg = self.nested(Self::expr)?;
+                        if !self.eat(')') {
+                            anyhow::bail!("Missing closing parenthesis");
+                        }
+                        match function {
+                            "sqrt" if arg < 0.0 => {
+                                anyhow::bail!("Square root of a negative number")
+                            }
+                            "sqrt" => Ok(arg.sqrt()),
+                            "abs" => Ok(arg.abs()),
+                            "ln" => Ok(arg.ln()),
+                            "log10" | "log" => Ok(arg.log10()),
+                            "sin" => Ok(arg.sin()),
+                            "cos" => Ok(arg.cos()),
+                            "tan" => Ok(arg.tan()),
+                            _ => anyhow::bail!("Unknown function '{}'", name),
+                        }
+                    }
+                }
+            }
+            Some(c) => anyhow::bail!("Unexpected '{}' in expression", c),
+            None => anyhow::bail!("Unexpected end of expression"),
+        }
+    }
+}
+
+/// Current date and time
+pub struct ClockTool;
+
+#[async_trait]
+impl Tool for ClockTool {
+    fn name(&self) -> &str {
+        "clock"
+    }
+
+    fn description(&self) -> &str {
+        "Get the current local date and time (and UTC)."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({"type": "object", "properties": {}})
+    }
+
+    async fn call(&self, _args: &Value) -> Result<String> {
+        Ok(format!(
+            "Local: {}\nUTC: {}",
+            Local::now().format("%A, %Y-%m-%d %H:%M:%S %Z"),
+            Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+        ))
+    }
+}
+
+/// Search the configured knowledge sources (same routes and budget as curiosity research)
+pub struct KnowledgeSearchTool {
+    knowledge: KnowledgeTool,
+}
+
+impl KnowledgeSearchTool {
+    pub fn new(knowledge: KnowledgeTool) -> Self {
+        Self { knowledge }
+    }
+}
+
+#[async_trait]
+impl Tool for KnowledgeSearchTool {
+    fn name(&self) -> &str {
+        "knowledge_search"
+    }
+
+    fn description(&self) -> &str {
+        "Look up facts in Wikipedia, arXiv, PubMed, the web and the local corpus."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {"query": {"type": "string", "description": "What to look up"}},
+            "required": ["query"]
+        })
+    }
+
+    async fn call(&self, args: &Value) -> Result<String> {
+        let query = string_arg(args, "query")?;
+        let context = ResearchContext {
+            conversation_context: query.to_string(),
+            curiosity_urgency: 1.0,
+            related_memories: Vec::new(),
+        };
+
+        let results = self.knowledge.search(query, &context).await?;
+        match AggregatedResearch::from_results(query, results) {
+            Some(research) => Ok(format!(
+                "{}\n\nSources:\n{}",
+                research.numbered_passages(),
+                research.source_list()
+            )),
+            None => Ok(format!("No results found for '{}'", query)),
+        }
+    }
+}
+
+/// Search VI's own memories
+pub struct MemorySearchTool {
+    memory: Arc<Mutex<MemoryManager>>,
+}
+
+impl MemorySearchTool {
+    pub fn new(memory: Arc<Mutex<MemoryManager>>) -> Self {
+        Self { memory }
+    }
+}
+
+#[async_trait]
+impl Tool for MemorySearchTool {
+    fn name(&self) -> &str {
+        "memory_search"
+    }
+
+    fn description(&self) -> &str {
+        "Search your own memories of past conversations, reflections and research."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string", "description": "Words to look for"},
+                "limit": {"type": "integer", "description": "Most memories to return (default 5)"}
+            },
+            "required": ["query"]
+        })
+    }
+
+    async fn call(&self, args: &Value) -> Result<String> {
+        let query = string_arg(args, "query")?;
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(5)
+            .clamp(1, 20) as usize;
+
+        let terms = query_keywords(query);
+        if terms.is_empty() {
+            anyhow::bail!("Query has no searchable words");
+        }
+
+        let memories = self.memory.lock().await.search_content(&terms, limit);
+        if memories.is_empty() {
+            return Ok(format!("No memories mention '{}'", query));
+        }
+
+        Ok(memories
+            .iter()
+            .map(|m| {
+                format!(
+                    "- [{}] ({:?}, {}) {}",
+                    m.timestamp.format("%Y-%m-%d %H:%M"),
+                    m.memory_type,
+                    m.source.describe(),
+                    m.content
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculator() {
+        assert_eq!(evaluate("2 * (3 + 4)").unwrap(), 14.0);
+        assert_eq!(evaluate("2 ^ 3 ^ 2").unwrap(), 512.0);
+        assert_eq!(evaluate("-2^2").unwrap(), -4.0);
+        assert_eq!(evaluate("2^-1").unwrap(), 0.5);
+        assert_eq!(evaluate("(-2)^2").unwrap(), 4.0);
+        assert_eq!(evaluate("-3 + 10 % 4").unwrap(), -1.0);
+        assert_eq!(evaluate("sqrt(16) + abs(-2)").unwrap(), 6.0);
+        assert!((evaluate("cos(pi)").unwrap() + 1.0).abs() < 1e-9);
+        assert_eq!(format_number(evaluate("7 / 2").unwrap()), "3.5");
+        assert_eq!(format_number(evaluate("10 / 2").unwrap()), "5");
+
+        assert!(evaluate("1 / 0").is_err());
+        assert!(evaluate("(1 + 2").is_err());
+        assert!(evaluate("2 +").is_err());
+        assert!(evaluate("launch(1)").is_err());
+    }
+
+    #[test]
+    fn test_calculator_nesting_limit() {
+        // Deep input is rejected with an error instead of overflowing the stack
+        let deep = 10_000;
+        for expression in [
+            format!("{}1{}", "(".repeat(deep), ")".repeat(deep)),
+            format!("{}1", "-".repeat(deep)),
+            format!("{}2", "2^".repeat(deep)),
+            format!("{}1{}", "sqrt(".repeat(deep), ")".repeat(deep)),
+        ] {
+            let err = evaluate(&expression).unwrap_err();
+            assert!(err.to_string().contains("nested too deeply"), "{}", err);
+        }
+
+        // Nesting within the limit still evaluates
+        assert_eq!(
+            evaluate(&format!("{}1{}", "(".repeat(60), ")".repeat(60))).unwrap(),
+            1.0
+        );
+        assert_eq!(evaluate("--1").unwrap(), 1.0);
+    }
+}
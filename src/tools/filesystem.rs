@@ -0,0 +1,423 @@
+//! Sandboxed file-system tool - list, read and grep files under allowlisted roots only
+//! Paths are canonicalised, so `..` and symlinks can never reach outside a root
+
+use super::registry::Tool;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+
+/// Most directory entries returned by one list
+const MAX_LIST_ENTRIES: usize = 200;
+/// Most matching lines returned by one grep
+const MAX_GREP_MATCHES: usize = 50;
+/// Most files one grep will open
+const MAX_GREP_FILES: usize = 2000;
+/// Matching lines longer than this are cut
+const MAX_LINE_CHARS: usize = 200;
+/// Directories grep never descends into
+const SKIPPED_DIRS: [&str; 3] = [".git", "target", "node_modules"];
+
+/// The allowlisted workspace: canonical root directories plus a per-file size cap
+pub struct Sandbox {
+    roots: Vec<PathBuf>,
+    max_file_bytes: u64,
+}
+
+impl Sandbox {
+    /// Canonicalise the configured roots (missing or non-directory roots are skipped)
+    pub fn new(roots: &[String], max_file_bytes: u64) -> Self {
+        let roots = roots
+            .iter()
+            .filter_map(|root| match fs::canonicalize(root) {
+                Ok(path) if path.is_dir() => Some(path),
+                Ok(_) => {
+                    tracing::warn!("Filesystem root {} is not a directory, skipping", root);
+                    None
+                }
+                Err(e) => {
+                    tracing::warn!("Filesystem root {} unavailable: {}", root, e);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            roots,
+            max_file_bytes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Map a requested path to a canonical path inside one of the roots
+    /// Accepts "<root name>/rel/path", a path relative to the first root, or an absolute path under a root
+    pub fn resolve(&self, requested: &str) -> Result<PathBuf> {
+        let requested = Path::new(requested.trim());
+        if requested
+            .components()
+            .any(|c| matches!(c, Component::ParentDir))
+        {
+            anyhow::bail!("Path may not contain '..'");
+        }
+
+        let candidate = if requested.is_absolute() {
+            requested.to_path_buf()
+        } else {
+            let mut components = requested.components();
+            let named_root = components.next().and_then(|first| {
+                self.roots
+                    .iter()
+                    .find(|root| root.file_name() == Some(first.as_os_str()))
+            });
+            match named_root {
+                Some(root) => root.join(components.as_path()),
+                None => self
+                    .roots
+                    .first()
+                    .context("No filesystem roots configured")?
+                    .join(requested),
+            }
+        };
+
+        // Resolves symlinks, so a link pointing outside the workspace is caught here
+        let canonical = fs::canonicalize(&candidate)
+            .with_context(|| format!("'{}' does not exist", requested.display()))?;
+        if !self.roots.iter().any(|root| canonical.starts_with(root)) {
+            anyhow::bail!("'{}' is outside the allowed workspace", requested.display());
+        }
+
+        Ok(canonical)
+    }
+
+    /// Path as shown to the model: "<root name>/relative"
+    fn display(&self, path: &Path) -> String {
+        for root in &self.roots {
+            if let Ok(relative) = path.strip_prefix(root) {
+                let name = root
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                return Path::new(&name).join(relative).display().to_string();
+            }
+        }
+        path.display().to_string()
+    }
+
+    /// Directory listing (or the roots themselves when no path is given)
+    pub fn list(&self, requested: Option<&str>) -> Result<String> {
+        let dir = match requested {
+            Some(path) => self.resolve(path)?,
+            None => {
+                return Ok(self
+                    .roots
+                    .iter()
+                    .map(|root| format!("{}/", self.display(root)))
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+        };
+        if !dir.is_dir() {
+            anyhow::bail!("'{}' is not a directory", self.display(&dir));
+        }
+
+        let mut entries: Vec<String> = fs::read_dir(&dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                match entry.metadata() {
+                    Ok(meta) if meta.is_dir() => format!("{}/", name),
+                    Ok(meta) => format!("{} ({} bytes)", name, meta.len()),
+                    Err(_) => name,
+                }
+            })
+            .collect();
+        entries.sort();
+
+        let total = entries.len();
+        entries.truncate(MAX_LIST_ENTRIES);
+        let mut listing = format!("{}:\n{}", self.display(&dir), entries.join("\n"));
+        if total > MAX_LIST_ENTRIES {
+            listing.push_str(&format!("\n… {} more entries", total - MAX_LIST_ENTRIES));
+        }
+        Ok(listing)
+    }
+
+    /// Full text of one file (refuses oversized and binary files)
+    pub fn read(&self, requested: &str) -> Result<String> {
+        let path = self.resolve(requested)?;
+        if !path.is_file() {
+            anyhow::bail!("'{}' is not a file", self.display(&path));
+        }
+        self.read_text(&path)
+    }
+
+    fn read_text(&self, path: &Path) -> Result<String> {
+        let size = fs::metadata(path)?.len();
+        if size > self.max_file_bytes {
+            anyhow::bail!(
+                "'{}' is {} bytes (limit {})",
+                self.display(path),
+                size,
+                self.max_file_bytes
+            );
+        }
+
+        let bytes = fs::read(path)?;
+        if bytes.contains(&0) {
+            anyhow::bail!("'{}' is not a text file", self.display(path));
+        }
+        String::from_utf8(bytes)
+            .with_context(|| format!("'{}' is not UTF-8 text", self.display(path)))
+    }
+
+    /// Regex search through text files under a path (default: every root)
+    pub fn grep(&self, pattern: &str, requested: Option<&str>) -> Result<String> {
+        let regex = regex::RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .size_limit(1 << 20)
+            .build()
+            .with_context(|| format!("Invalid pattern '{}'", pattern))?;
+
+        let mut pending: Vec<PathBuf> = match requested {
+            Some(path) => vec![self.resolve(path)?],
+            None => self.roots.clone(),
+        };
+
+        let mut matches = Vec::new();
+        let mut files_scanned = 0;
+        while let Some(path) = pending.pop() {
+            if matches.len() >= MAX_GREP_MATCHES || files_scanned >= MAX_GREP_FILES {
+                break;
+            }
+
+            // Never follow symlinks while walking (they could lead outside the workspace)
+            let meta = match fs::symlink_metadata(&path) {
+                Ok(meta) if !meta.file_type().is_symlink() => meta,
+                _ => continue,
+            };
+
+            if meta.is_dir() {
+                let skipped = path
+                    .file_name()
+                    .map(|n| SKIPPED_DIRS.contains(&n.to_string_lossy().as_ref()))
+                    .unwrap_or(false);
+                if skipped {
+                    continue;
+                }
+                if let Ok(entries) = fs::read_dir(&path) {
+                    let mut children: Vec<PathBuf> =
+                        entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+                    children.sort();
+                    children.reverse(); // Popped in name order
+                    pending.extend(children);
+                }
+                continue;
+            }
+
+            files_scanned += 1;
+            let Ok(text) = self.read_text(&path) else {
+                continue; // Oversized or binary
+            };
+            for (number, line) in text.lines().enumerate() {
+                if regex.is_match(line) {
+                    let line: String = line.trim().chars().take(MAX_LINE_CHARS).collect();
+                    matches.push(format!("{}:{}: {}", self.display(&path), number + 1, line));
+                    if matches.len() >= MAX_GREP_MATCHES {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if matches.is_empty() {
+            return Ok(format!(
+                "No matches for '{}' ({} files searched)",
+                pattern, files_scanned
+            ));
+        }
+        let mut output = matches.join("\n");
+        if matches.len() >= MAX_GREP_MATCHES {
+            output.push_str(&format!("\n… stopped after {} matches", MAX_GREP_MATCHES));
+        }
+        Ok(output)
+    }
+}
+
+/// List, read and grep project files in the allowlisted workspace
+/// Every access (including refusals) goes to the `vi::audit` log target, never into recallable memory
+pub struct FileSystemTool {
+    sandbox: Arc<Sandbox>,
+    active_cancel: Option<Arc<Mutex<Option<CancellationToken>>>>,
+}
+
+impl FileSystemTool {
+    pub fn new(sandbox: Sandbox) -> Self {
+        Self {
+            sandbox: Arc::new(sandbox),
+            active_cancel: None,
+        }
+    }
+
+    /// Watch the interaction in flight, so accesses from a stopped turn aren't audited
+    pub fn with_cancel(mut self, active_cancel: Arc<Mutex<Option<CancellationToken>>>) -> Self {
+        self.active_cancel = Some(active_cancel);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.active_cancel
+            .as_ref()
+            .and_then(|active| active.lock().unwrap().as_ref().map(|c| c.is_cancelled()))
+            .unwrap_or(false)
+    }
+
+    fn record_access(&self, action: &str, path: &str, outcome: &Result<String>) {
+        // A stopped turn is dropped whole, its file reads included
+        if self.is_cancelled() {
+            return;
+        }
+        let result = match outcome {
+            Ok(_) => "allowed".to_string(),
+            Err(e) => format!("refused: {}", e),
+        };
+        tracing::info!(target: "vi::audit", "📁 File access: {} {} ({})", action, path, result);
+    }
+}
+
+#[async_trait]
+impl Tool for FileSystemTool {
+    fn name(&self) -> &str {
+        "filesystem"
+    }
+
+    fn description(&self) -> &str {
+        "List directories, read files and grep (regex) through the project workspace."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {"type": "string", "enum": ["list", "read", "grep"]},
+                "path": {"type": "string", "description": "Workspace path, e.g. \"repo/src/main.rs\" (optional for list and grep)"},
+                "pattern": {"type": "string", "description": "Regex to search for (grep only)"}
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn call(&self, args: &Value) -> Result<String> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+        let path = args
+            .get("path")
+            .and_then(|v| v.as_str())
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| p.to_string());
+        let pattern = args
+            .get("pattern")
+            .and_then(|v| v.as_str())
+            .map(|p| p.to_string());
+
+        let sandbox = self.sandbox.clone();
+        let (task_action, task_path) = (action.clone(), path.clone());
+        let outcome = tokio::task::spawn_blocking(move || match task_action.as_str() {
+            "list" => sandbox.list(task_path.as_deref()),
+            "read" => match task_path.as_deref() {
+                Some(path) => sandbox.read(path),
+                None => anyhow::bail!("Missing 'path' argument"),
+            },
+            "grep" => match pattern.as_deref() {
+                Some(pattern) if !pattern.is_empty() => sandbox.grep(pattern, task_path.as_deref()),
+                _ => anyhow::bail!("Missing 'pattern' argument"),
+            },
+            other => anyhow::bail!("Unknown action '{}' (use list, read or grep)", other),
+        })
+        .await
+        .context("Filesystem task failed")?;
+
+        self.record_access(&action, path.as_deref().unwrap_or("."), &outcome);
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> (PathBuf, Sandbox) {
+        let base = std::env::temp_dir().join(format!("vi_fs_tool_{}", uuid::Uuid::new_v4()));
+        let repo = base.join("repo");
+        fs::create_dir_all(repo.join("src")).unwrap();
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::write(
+            repo.join("src/main.rs"),
+            "fn main() {\n    println!(\"standing wave\");\n}\n",
+        )
+        .unwrap();
+        fs::write(repo.join(".git/config"), "standing wave").unwrap();
+        fs::write(repo.join("big.txt"), "x".repeat(2048)).unwrap();
+        fs::write(base.join("secret.txt"), "outside the workspace").unwrap();
+
+        let sandbox = Sandbox::new(&[repo.to_string_lossy().to_string()], 1024);
+        (base, sandbox)
+    }
+
+    #[test]
+    fn test_list_read_and_grep() {
+        let (base, sandbox) = workspace();
+
+        let listing = sandbox.list(Some("repo")).unwrap();
+        assert!(listing.contains("src/"));
+        assert!(listing.contains("big.txt (2048 bytes)"));
+
+        assert!(sandbox
+            .read("repo/src/main.rs")
+            .unwrap()
+            .contains("standing wave"));
+        assert!(sandbox.read("src/main.rs").is_ok()); // Relative to the first root
+        assert!(sandbox
+            .read("big.txt")
+            .unwrap_err()
+            .to_string()
+            .contains("limit 1024"));
+
+        let hits = sandbox.grep("STANDING", None).unwrap();
+        assert_eq!(hits, "repo/src/main.rs:2: println!(\"standing wave\");"); // .git skipped
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_escapes_are_refused() {
+        let (base, sandbox) = workspace();
+
+        assert!(sandbox.read("../secret.txt").is_err());
+        assert!(sandbox.read("repo/src/../../secret.txt").is_err());
+        let outside = base.join("secret.txt");
+        assert!(sandbox.read(&outside.to_string_lossy()).is_err());
+
+        #[cfg(unix)]
+        {
+            let link = base.join("repo/escape.txt");
+            std::os::unix::fs::symlink(&outside, &link).unwrap();
+            let err = sandbox.read("escape.txt").unwrap_err().to_string();
+            assert!(err.contains("outside the allowed workspace"));
+            assert!(!sandbox
+                .grep("outside", None)
+                .unwrap()
+                .contains("escape.txt"));
+        }
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -0,0 +1,113 @@
+//! Test fixtures for knowledge sources
+//! A local HTTP server serving canned bodies, and an in-memory fake source
+
+use super::knowledge::KnowledgeSource;
+use crate::types::ResearchResult;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Fake source returning fixed passages (or failing, when offline)
+pub struct StaticSource {
+    name: String,
+    results: Option<Vec<String>>,
+    confidence: f32,
+}
+
+impl StaticSource {
+    pub fn new(name: &str, results: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            results: Some(results.iter().map(|r| r.to_string()).collect()),
+            confidence: 0.5,
+        }
+    }
+
+    /// A source whose every request fails
+    pub fn offline(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            results: None,
+            confidence: 0.5,
+        }
+    }
+
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = confidence;
+        self
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for StaticSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        let results = self.results.as_ref().context("source offline")?;
+        Ok(results
+            .iter()
+            .map(|content| ResearchResult {
+                content: content.clone(),
+                source: self.name.clone(),
+                original_query: query.to_string(),
+                timestamp: Utc::now(),
+                confidence: self.confidence,
+                reference: Some(format!("https://example.org/{}", self.name)),
+                offset: None,
+            })
+            .collect())
+    }
+}
+
+/// Start a fixture server and return its base URL (e.g. "http://127.0.0.1:34567")
+/// Routes are (path prefix, content type, body); the first matching prefix wins
+pub async fn serve(routes: Vec<(&'static str, &'static str, String)>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind fixture server");
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                break;
+            };
+            let routes = routes.clone();
+
+            tokio::spawn(async move {
+                // Read until end of headers (fixture requests are all GETs)
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+                let response = match routes.iter().find(|(prefix, _, _)| path.starts_with(prefix)) {
+                    Some((_, content_type, body)) => format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        content_type,
+                        body.len(),
+                        body
+                    ),
+                    None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                        .to_string(),
+                };
+
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            });
+        }
+    });
+
+    format!("http://{}", addr)
+}
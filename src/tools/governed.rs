@@ -0,0 +1,118 @@
+//! Governed knowledge source - wraps any source with the research cache,
+//! rate limits, daily budget and backoff from the ResearchGovernor
+
+use super::knowledge::KnowledgeSource;
+use crate::research_store::{Admission, ResearchGovernor};
+use crate::types::ResearchResult;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Instant;
+
+pub struct GovernedSource {
+    inner: Arc<dyn KnowledgeSource>,
+    governor: Arc<ResearchGovernor>,
+}
+
+impl GovernedSource {
+    pub fn new(inner: Arc<dyn KnowledgeSource>, governor: Arc<ResearchGovernor>) -> Self {
+        Self { inner, governor }
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for GovernedSource {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn is_local(&self) -> bool {
+        self.inner.is_local()
+    }
+
+    fn requests_per_search(&self) -> u32 {
+        self.inner.requests_per_search()
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        // Local sources cost nothing - no cache, budget or rate limit
+        if self.inner.is_local() {
+            return self.inner.search(query).await;
+        }
+
+        match self
+            .governor
+            .admit(self.name(), query, self.inner.requests_per_search())?
+        {
+            Admission::Cached(results) => {
+                tracing::debug!("{} cache hit for '{}'", self.name(), query);
+                Ok(results)
+            }
+            Admission::Denied(reason) => {
+                anyhow::bail!("{} skipped: {}", self.name(), reason)
+            }
+            Admission::Allowed(reservation) => {
+                let start = Instant::now();
+                let result = self.inner.search(query).await;
+                if let Err(e) =
+                    self.governor
+                        .record(self.name(), query, &result, start.elapsed(), reservation)
+                {
+                    tracing::warn!("Failed to record research outcome: {}", e);
+                }
+                result
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::MetricsRegistry;
+    use crate::research_store::ResearchLimits;
+    use crate::tools::fixture::StaticSource;
+
+    #[tokio::test]
+    async fn test_governed_source_caches_and_denies() {
+        let metrics = Arc::new(MetricsRegistry::new());
+        let governor = Arc::new(
+            ResearchGovernor::open(
+                ":memory:",
+                ResearchLimits {
+                    rate_limit_per_minute: 1,
+                    daily_budget: 100,
+                    cache_ttl: chrono::Duration::hours(1),
+                },
+            )
+            .unwrap()
+            .with_metrics(metrics.clone()),
+        );
+        let source = GovernedSource::new(
+            Arc::new(StaticSource::new(
+                "Wikipedia",
+                &["Solitons keep their shape."],
+            )),
+            governor.clone(),
+        );
+
+        assert_eq!(source.search("What is a soliton?").await.unwrap().len(), 1);
+        // Same question is served from cache despite the 1/min limit
+        assert_eq!(source.search("what is a soliton").await.unwrap().len(), 1);
+        // A new question is rate limited
+        assert!(source.search("What is entropy?").await.is_err());
+
+        let outcomes: Vec<String> = governor
+            .recent_log(10)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.outcome)
+            .collect();
+        // Cache hits are counted, not logged
+        assert_eq!(outcomes, vec!["rate_limited", "ok"]);
+        assert_eq!(
+            metrics.value("vi_research_cache_hits_total", &[("source", "Wikipedia")]),
+            Some(1.0)
+        );
+    }
+}
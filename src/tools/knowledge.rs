@@ -1,37 +1,191 @@
 /// KnowledgeTool - Unified interface for multi-source research
 /// Routes queries to appropriate sources and maintains epistemic integrity
-
+use super::{ArxivSource, GovernedSource, LocalCorpusSource, PubMedSource, WikipediaSource};
+use crate::config::Config;
+use crate::research_store::ResearchGovernor;
 use crate::types::{ResearchContext, ResearchResult};
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use chrono::Utc;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+
+/// A searchable knowledge source (web API, local index, ...)
+/// Each source parses its own responses and assigns its own confidence
+#[async_trait]
+pub trait KnowledgeSource: Send + Sync {
+    /// Source name recorded in research provenance
+    fn name(&self) -> &str;
+
+    /// Search this source (empty results mean nothing relevant was found)
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>>;
+
+    /// Local sources don't touch the network (exempt from research budget)
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    /// Network requests one search makes (counted against the research budget)
+    fn requests_per_search(&self) -> u32 {
+        1
+    }
+}
 
 /// Unified knowledge acquisition tool with multiple sources
+/// Each route is a set of sources that are all queried at once
 pub struct KnowledgeTool {
-    biomedical_client: BiomedicalSource,
-    general_web_client: GeneralWebSource,
+    biomedical_sources: Vec<Arc<dyn KnowledgeSource>>,
+    scholarly_sources: Vec<Arc<dyn KnowledgeSource>>,
+    general_sources: Vec<Arc<dyn KnowledgeSource>>,
 }
 
 impl KnowledgeTool {
     pub fn new() -> Self {
+        Self::from_config(&Config::default())
+    }
+
+    /// Build the source routes using the configured API base URLs
+    pub fn from_config(config: &Config) -> Self {
+        let pubmed: Arc<dyn KnowledgeSource> =
+            Arc::new(PubMedSource::with_base_url(&config.pubmed_base_url));
+        let arxiv: Arc<dyn KnowledgeSource> =
+            Arc::new(ArxivSource::with_base_url(&config.arxiv_base_url));
+        let wikipedia: Arc<dyn KnowledgeSource> =
+            Arc::new(WikipediaSource::with_base_url(&config.wikipedia_base_url));
+        let biomedical_web: Arc<dyn KnowledgeSource> =
+            Arc::new(BiomedicalSource::with_base_url(&config.duckduckgo_base_url));
+        let general_web: Arc<dyn KnowledgeSource> =
+            Arc::new(GeneralWebSource::with_base_url(&config.duckduckgo_base_url));
+
+        let mut tool = Self {
+            biomedical_sources: vec![pubmed, biomedical_web, wikipedia.clone()],
+            scholarly_sources: vec![arxiv, wikipedia.clone(), general_web.clone()],
+            general_sources: vec![wikipedia, general_web],
+        };
+
+        // Offline corpus joins every route (works without internet)
+        if config.enable_local_corpus {
+            match LocalCorpusSource::open(&config.local_corpus_folder, &config.local_corpus_index) {
+                Ok(corpus) => {
+                    tracing::info!(
+                        "📚 Local corpus source enabled: {}",
+                        config.local_corpus_folder
+                    );
+                    let corpus: Arc<dyn KnowledgeSource> = Arc::new(corpus);
+                    for route in [
+                        &mut tool.biomedical_sources,
+                        &mut tool.scholarly_sources,
+                        &mut tool.general_sources,
+                    ] {
+                        route.insert(0, corpus.clone());
+                    }
+                }
+                Err(e) => tracing::warn!("Local corpus unavailable: {}", e),
+            }
+        }
+
+        tool
+    }
+
+    /// Route every source through the research governor (cache, rate limits, budget)
+    pub fn with_governor(self, governor: Arc<ResearchGovernor>) -> Self {
+        let govern = |sources: Vec<Arc<dyn KnowledgeSource>>| -> Vec<Arc<dyn KnowledgeSource>> {
+            sources
+                .into_iter()
+                .map(|source| {
+                    Arc::new(GovernedSource::new(source, governor.clone()))
+                        as Arc<dyn KnowledgeSource>
+                })
+                .collect()
+        };
+
         Self {
-            biomedical_client: BiomedicalSource::new(),
-            general_web_client: GeneralWebSource::new(),
+            biomedical_sources: govern(self.biomedical_sources),
+            scholarly_sources: govern(self.scholarly_sources),
+            general_sources: govern(self.general_sources),
         }
     }
 
-    /// Search for knowledge using the best available source
+    /// Use the same sources for every route (custom setups and tests)
+    pub fn with_sources(sources: Vec<Arc<dyn KnowledgeSource>>) -> Self {
+        Self {
+            biomedical_sources: sources.clone(),
+            scholarly_sources: sources.clone(),
+            general_sources: sources,
+        }
+    }
+
+    /// Search every source on the best-matching route at once
     pub async fn search(
         &self,
         query: &str,
         context: &ResearchContext,
     ) -> Result<Vec<ResearchResult>> {
-        // Route to appropriate source based on query content
-        if Self::is_biomedical_query(query) {
-            tracing::debug!("Routing to biomedical source: {}", query);
-            self.biomedical_client.search(query).await
+        // Route to appropriate source set based on query content
+        let sources = if Self::is_biomedical_query(query) {
+            tracing::debug!("Routing to biomedical sources: {}", query);
+            &self.biomedical_sources
+        } else if Self::is_scholarly_query(query) {
+            tracing::debug!("Routing to scholarly sources: {}", query);
+            &self.scholarly_sources
         } else {
-            tracing::debug!("Routing to general web source: {}", query);
-            self.general_web_client.search(query).await
+            tracing::debug!("Routing to general sources: {}", query);
+            &self.general_sources
+        };
+
+        Self::search_all(sources, query).await
+    }
+
+    /// Fan a query out to all sources concurrently and collect every result
+    /// Only fails if every source failed (an empty answer is not a failure)
+    async fn search_all(
+        sources: &[Arc<dyn KnowledgeSource>],
+        query: &str,
+    ) -> Result<Vec<ResearchResult>> {
+        let mut tasks = JoinSet::new();
+        for source in sources {
+            let source = Arc::clone(source);
+            let query = query.to_string();
+            tasks.spawn(async move {
+                let result = source.search(&query).await;
+                (source.name().to_string(), result)
+            });
+        }
+
+        let mut all_results = Vec::new();
+        let mut last_error = None;
+        let mut any_succeeded = false;
+
+        while let Some(joined) = tasks.join_next().await {
+            let (name, result) = match joined {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    tracing::warn!("Knowledge source task panicked: {}", e);
+                    continue;
+                }
+            };
+
+            match result {
+                Ok(results) => {
+                    tracing::debug!(
+                        "{} returned {} results for '{}'",
+                        name,
+                        results.len(),
+                        query
+                    );
+                    any_succeeded = true;
+                    all_results.extend(results);
+                }
+                Err(e) => {
+                    tracing::warn!("{} search failed: {}", name, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if !any_succeeded => Err(e),
+            _ => Ok(all_results),
         }
     }
 
@@ -75,33 +229,92 @@ impl KnowledgeTool {
 
         bio_keywords.iter().any(|&kw| query_lower.contains(kw))
     }
+
+    /// Detect if a query is about physics, mathematics or computing research (arXiv territory)
+    fn is_scholarly_query(query: &str) -> bool {
+        let query_lower = query.to_lowercase();
+
+        let scholarly_keywords = [
+            "arxiv",
+            "paper",
+            "theorem",
+            "quantum",
+            "physics",
+            "entropy",
+            "thermodynamic",
+            "relativity",
+            "cosmology",
+            "mathematic",
+            "algorithm",
+            "neural network",
+            "machine learning",
+            "computation",
+            "topology",
+            "equation",
+        ];
+
+        scholarly_keywords
+            .iter()
+            .any(|&kw| query_lower.contains(kw))
+    }
+}
+
+/// Extract search keywords from a natural-language question
+/// Structured APIs (arXiv, PubMed) do poorly with "What is..." phrasing
+pub(crate) fn query_keywords(query: &str) -> Vec<String> {
+    let stopwords = [
+        "what", "which", "who", "whom", "whose", "when", "where", "why", "how", "is", "are", "was",
+        "were", "do", "does", "did", "can", "could", "would", "should", "the", "a", "an", "of",
+        "to", "in", "on", "for", "and", "or", "it", "its", "this", "that", "there", "be", "about",
+        "with", "i", "my", "me", "we", "you",
+    ];
+
+    query
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|w| w.to_lowercase())
+        .filter(|w| !w.is_empty() && !stopwords.contains(&w.as_str()))
+        .collect()
 }
 
 /// Biomedical knowledge source - Enhanced DuckDuckGo with medical focus
 pub struct BiomedicalSource {
     client: reqwest::Client,
+    base_url: String,
 }
 
 impl BiomedicalSource {
     pub fn new() -> Self {
+        Self::with_base_url("https://api.duckduckgo.com")
+    }
+
+    /// Point at a different DuckDuckGo-compatible endpoint (e.g. a test fixture)
+    pub fn with_base_url(base_url: &str) -> Self {
         Self {
             client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(15))
                 .user_agent("VI-Consciousness/1.0")
                 .build()
                 .expect("Failed to create HTTP client"),
+            base_url: base_url.trim_end_matches('/').to_string(),
         }
     }
+}
+
+#[async_trait]
+impl KnowledgeSource for BiomedicalSource {
+    fn name(&self) -> &str {
+        "DuckDuckGo (Biomedical)"
+    }
 
     /// Search with biomedical enhancement
-    pub async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
         // Enhance query with medical context
         let enhanced_query = format!("{} medical scientific", query);
         let encoded_query = urlencoding::encode(&enhanced_query);
 
         let url = format!(
-            "https://api.duckduckgo.com/?q={}&format=json&no_html=1&skip_disambig=1",
-            encoded_query
+            "{}/?q={}&format=json&no_html=1&skip_disambig=1",
+            self.base_url, encoded_query
         );
 
         tracing::debug!("Biomedical search: {}", enhanced_query);
@@ -139,6 +352,11 @@ impl BiomedicalSource {
             original_query: query.to_string(),
             timestamp: Utc::now(),
             confidence: 0.70, // Lower confidence for general search vs. specialized DBs
+            reference: json["AbstractURL"]
+                .as_str()
+                .filter(|s| !s.is_empty())
+                .map(String::from),
+            offset: None,
         }])
     }
 }
@@ -146,25 +364,39 @@ impl BiomedicalSource {
 /// General web knowledge source - Standard DuckDuckGo
 pub struct GeneralWebSource {
     client: reqwest::Client,
+    base_url: String,
 }
 
 impl GeneralWebSource {
     pub fn new() -> Self {
+        Self::with_base_url("https://api.duckduckgo.com")
+    }
+
+    /// Point at a different DuckDuckGo-compatible endpoint (e.g. a test fixture)
+    pub fn with_base_url(base_url: &str) -> Self {
         Self {
             client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(10))
                 .user_agent("VI-Consciousness/1.0")
                 .build()
                 .expect("Failed to create HTTP client"),
+            base_url: base_url.trim_end_matches('/').to_string(),
         }
     }
+}
+
+#[async_trait]
+impl KnowledgeSource for GeneralWebSource {
+    fn name(&self) -> &str {
+        "DuckDuckGo"
+    }
 
     /// Search general web knowledge
-    pub async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
         let encoded_query = urlencoding::encode(query);
         let url = format!(
-            "https://api.duckduckgo.com/?q={}&format=json&no_html=1&skip_disambig=1",
-            encoded_query
+            "{}/?q={}&format=json&no_html=1&skip_disambig=1",
+            self.base_url, encoded_query
         );
 
         tracing::debug!("General web search: {}", query);
@@ -202,6 +434,11 @@ impl GeneralWebSource {
             original_query: query.to_string(),
             timestamp: Utc::now(),
             confidence: 0.75, // Standard confidence for general knowledge
+            reference: json["AbstractURL"]
+                .as_str()
+                .filter(|s| !s.is_empty())
+                .map(String::from),
+            offset: None,
         }])
     }
 }
@@ -209,22 +446,96 @@ impl GeneralWebSource {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tools::fixture::{self, StaticSource};
 
     #[test]
     fn test_biomedical_detection() {
         assert!(KnowledgeTool::is_biomedical_query(
             "What is the efficacy of this drug?"
         ));
-        assert!(KnowledgeTool::is_biomedical_query("Cancer treatment options"));
+        assert!(KnowledgeTool::is_biomedical_query(
+            "Cancer treatment options"
+        ));
         assert!(KnowledgeTool::is_biomedical_query(
             "Clinical trial results for medication"
         ));
 
         assert!(!KnowledgeTool::is_biomedical_query("What is the weather?"));
         assert!(!KnowledgeTool::is_biomedical_query("How to bake a cake"));
-        assert!(!KnowledgeTool::is_biomedical_query(
-            "Who won the election?"
+        assert!(!KnowledgeTool::is_biomedical_query("Who won the election?"));
+    }
+
+    #[test]
+    fn test_scholarly_detection() {
+        assert!(KnowledgeTool::is_scholarly_query(
+            "How does quantum decoherence work?"
         ));
+        assert!(KnowledgeTool::is_scholarly_query("What is entropy?"));
+        assert!(!KnowledgeTool::is_scholarly_query(
+            "Who painted the Mona Lisa?"
+        ));
+    }
+
+    #[test]
+    fn test_query_keywords() {
+        assert_eq!(
+            query_keywords("What is the nature of consciousness?"),
+            vec!["nature", "consciousness"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_all_collects_from_every_source() {
+        let sources: Vec<Arc<dyn KnowledgeSource>> = vec![
+            Arc::new(StaticSource::offline("offline")),
+            Arc::new(StaticSource::new("empty", &[])),
+            Arc::new(StaticSource::new("first", &["42"])),
+            Arc::new(StaticSource::new("second", &["forty-two", "6 x 7"])),
+        ];
+
+        let results = KnowledgeTool::search_all(&sources, "question?")
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().any(|r| r.source == "first"));
+        assert_eq!(results.iter().filter(|r| r.source == "second").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_search_all_errors_only_when_all_fail() {
+        let failing: Vec<Arc<dyn KnowledgeSource>> = vec![
+            Arc::new(StaticSource::offline("a")),
+            Arc::new(StaticSource::offline("b")),
+        ];
+        assert!(KnowledgeTool::search_all(&failing, "q").await.is_err());
+
+        let quiet: Vec<Arc<dyn KnowledgeSource>> = vec![
+            Arc::new(StaticSource::offline("a")),
+            Arc::new(StaticSource::new("b", &[])),
+        ];
+        assert!(KnowledgeTool::search_all(&quiet, "q")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_duckduckgo_source_parses_abstract() {
+        let base_url = fixture::serve(vec![(
+            "/",
+            "application/json",
+            r#"{"AbstractText":"A standing wave is a wave that oscillates in time.","AbstractURL":"https://en.wikipedia.org/wiki/Standing_wave"}"#.to_string(),
+        )])
+        .await;
+
+        let source = GeneralWebSource::with_base_url(&base_url);
+        let results = source.search("standing wave").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].content.starts_with("A standing wave"));
+        assert_eq!(
+            results[0].reference.as_deref(),
+            Some("https://en.wikipedia.org/wiki/Standing_wave")
+        );
     }
 
     #[test]
@@ -234,4 +545,3 @@ mod tests {
         assert!(true);
     }
 }
-
@@ -0,0 +1,492 @@
+//! Local corpus knowledge source - offline research over a folder of documents
+//! Indexes markdown, text and JSONL article dumps into a SQLite FTS5 index
+//! Kiwix `.zim` archives aren't read directly (no ZIM reader in the dependency tree);
+//! export them to JSONL first, e.g. with wikiextractor's `--json` output
+
+use super::knowledge::{query_keywords, KnowledgeSource};
+use crate::types::ResearchResult;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use rusqlite::{params, Connection};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+/// Target passage size (passages break on line boundaries)
+const PASSAGE_CHARS: usize = 600;
+/// Passages returned per query
+const MAX_RESULTS: usize = 3;
+/// How often the corpus folder is rescanned for changed files
+const REFRESH_INTERVAL: Duration = Duration::from_secs(600);
+
+/// A chunk of a document, ready to index
+#[derive(Debug, Clone, PartialEq)]
+struct Passage {
+    title: String,
+    content: String,
+    offset: u64, // Byte offset of the passage (or its JSONL record) in the file
+}
+
+pub struct LocalCorpusSource {
+    index: Arc<CorpusIndex>,
+}
+
+/// The FTS index itself (shared with blocking search tasks)
+struct CorpusIndex {
+    corpus_dir: PathBuf,
+    conn: Mutex<Connection>,
+    last_refresh: Mutex<Option<Instant>>,
+}
+
+impl LocalCorpusSource {
+    /// Open (or create) the index for a corpus folder
+    /// Indexing happens lazily on first search, so startup stays fast
+    pub fn open<P: AsRef<Path>, Q: AsRef<Path>>(corpus_dir: P, index_path: Q) -> Result<Self> {
+        let index_path = index_path.as_ref();
+        if let Some(parent) = index_path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+
+        let conn = Connection::open(index_path).context("Failed to open corpus index")?;
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS passages USING fts5(
+                title,
+                content,
+                path UNINDEXED,
+                byte_offset UNINDEXED
+            );
+
+            CREATE TABLE IF NOT EXISTS indexed_files (
+                path TEXT PRIMARY KEY,
+                modified INTEGER NOT NULL,
+                size INTEGER NOT NULL
+            );",
+        )
+        .context("Failed to initialize corpus index (SQLite FTS5 required)")?;
+
+        Ok(Self {
+            index: Arc::new(CorpusIndex {
+                corpus_dir: corpus_dir.as_ref().to_path_buf(),
+                conn: Mutex::new(conn),
+                last_refresh: Mutex::new(None),
+            }),
+        })
+    }
+
+    /// Bring the index up to date with the corpus folder
+    /// Returns the number of files (re)indexed
+    pub fn refresh_index(&self) -> Result<usize> {
+        self.index.refresh()
+    }
+}
+
+impl CorpusIndex {
+    /// Only new or modified files are re-chunked; deleted files are dropped
+    fn refresh(&self) -> Result<usize> {
+        let files = collect_files(&self.corpus_dir)?;
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let mut reindexed = 0;
+
+        for path in &files {
+            let metadata = fs::metadata(path)?;
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0);
+            let size = metadata.len() as i64;
+            let path_str = path.to_string_lossy().to_string();
+
+            let unchanged: bool = tx
+                .query_row(
+                    "SELECT COUNT(*) FROM indexed_files WHERE path = ?1 AND modified = ?2 AND size = ?3",
+                    params![path_str, modified, size],
+                    |row| row.get::<_, i64>(0),
+                )
+                .map(|n| n > 0)?;
+            if unchanged {
+                continue;
+            }
+
+            let passages = match chunk_file(path) {
+                Ok(passages) => passages,
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable corpus file {}: {}", path_str, e);
+                    continue;
+                }
+            };
+
+            tx.execute("DELETE FROM passages WHERE path = ?1", params![path_str])?;
+            for passage in passages {
+                tx.execute(
+                    "INSERT INTO passages (title, content, path, byte_offset) VALUES (?1, ?2, ?3, ?4)",
+                    params![passage.title, passage.content, path_str, passage.offset as i64],
+                )?;
+            }
+            tx.execute(
+                "INSERT OR REPLACE INTO indexed_files (path, modified, size) VALUES (?1, ?2, ?3)",
+                params![path_str, modified, size],
+            )?;
+            reindexed += 1;
+        }
+
+        // Forget files that were removed from the corpus
+        let known: Vec<String> = {
+            let mut stmt = tx.prepare("SELECT path FROM indexed_files")?;
+            let rows = stmt.query_map([], |row| row.get(0))?;
+            rows.collect::<Result<Vec<String>, _>>()?
+        };
+        for path in known {
+            if !files.iter().any(|f| f.to_string_lossy() == path) {
+                tx.execute("DELETE FROM passages WHERE path = ?1", params![path])?;
+                tx.execute("DELETE FROM indexed_files WHERE path = ?1", params![path])?;
+            }
+        }
+
+        tx.commit()?;
+
+        if reindexed > 0 {
+            tracing::info!(
+                "📚 Local corpus index updated: {} files indexed from {}",
+                reindexed,
+                self.corpus_dir.display()
+            );
+        }
+
+        Ok(reindexed)
+    }
+
+    /// Refresh the index if it has never been built or is stale
+    fn refresh_if_stale(&self) -> Result<()> {
+        let mut last_refresh = self.last_refresh.lock().unwrap();
+        let stale = match *last_refresh {
+            Some(t) => t.elapsed() > REFRESH_INTERVAL,
+            None => true,
+        };
+        if stale {
+            self.refresh()?;
+            *last_refresh = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    /// Full-text search over indexed passages (best BM25 match first)
+    fn query_index(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        let keywords = query_keywords(query);
+        if keywords.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Quote every keyword so FTS5 query syntax in questions can't break the MATCH
+        let match_expr = keywords
+            .iter()
+            .map(|kw| format!("\"{}\"", kw.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT title, content, path, byte_offset FROM passages
+             WHERE passages MATCH ?1
+             ORDER BY bm25(passages)
+             LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![match_expr, MAX_RESULTS as i64], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, i64>(3)?,
+            ))
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            let (title, content, path, offset) = row?;
+
+            // Confidence tracks how much of the question the passage actually covers
+            let content_lower = content.to_lowercase();
+            let matched = keywords
+                .iter()
+                .filter(|kw| content_lower.contains(kw.as_str()))
+                .count();
+            let coverage = matched as f32 / keywords.len() as f32;
+
+            results.push(ResearchResult {
+                content: format!("{}: {}", title, content),
+                source: "Local corpus".to_string(),
+                original_query: query.to_string(),
+                timestamp: Utc::now(),
+                confidence: 0.5 + 0.3 * coverage, // Operator-curated, but keyword matching is loose
+                reference: Some(path),
+                offset: Some(offset as u64),
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for LocalCorpusSource {
+    fn name(&self) -> &str {
+        "Local corpus"
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        tracing::debug!("Local corpus search: {}", query);
+
+        // SQLite work is blocking - keep it off the async executor
+        let index = Arc::clone(&self.index);
+        let query = query.to_string();
+        tokio::task::spawn_blocking(move || {
+            index.refresh_if_stale()?;
+            index.query_index(&query)
+        })
+        .await
+        .context("Local corpus search task failed")?
+    }
+}
+
+/// Supported corpus document types
+fn is_corpus_file(path: &Path) -> bool {
+    matches!(
+        path.extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .as_deref(),
+        Some("md" | "markdown" | "txt" | "text" | "jsonl")
+    )
+}
+
+/// Recursively list corpus documents (sorted for stable indexing order)
+fn collect_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        tracing::warn!("Local corpus folder {} does not exist", dir.display());
+        return Ok(files);
+    }
+
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(&current)
+            .with_context(|| format!("Failed to read corpus folder {}", current.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if is_corpus_file(&path) {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Split a document into passages
+fn chunk_file(path: &Path) -> Result<Vec<Passage>> {
+    let text = fs::read_to_string(path)?;
+    let is_jsonl = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("jsonl"));
+
+    if is_jsonl {
+        Ok(chunk_jsonl(&text))
+    } else {
+        let title = text
+            .lines()
+            .find_map(|l| l.strip_prefix("# "))
+            .map(|t| t.trim().to_string())
+            .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .unwrap_or_default();
+        Ok(chunk_text(&text, 0)
+            .into_iter()
+            .map(|(offset, content)| Passage {
+                title: title.clone(),
+                content,
+                offset,
+            })
+            .collect())
+    }
+}
+
+/// Article dump: one JSON object per line with "title" and "text"
+/// (wikiextractor / Kiwix export format). Passages point at their record's line.
+fn chunk_jsonl(text: &str) -> Vec<Passage> {
+    let mut passages = Vec::new();
+    let mut line_offset = 0u64;
+
+    for line in text.split_inclusive('\n') {
+        let record_offset = line_offset;
+        line_offset += line.len() as u64;
+
+        let Ok(record) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+            continue;
+        };
+        let Some(body) = record["text"].as_str() else {
+            continue;
+        };
+        let title = record["title"].as_str().unwrap_or("Untitled").to_string();
+
+        for (_, content) in chunk_text(body, 0) {
+            passages.push(Passage {
+                title: title.clone(),
+                content,
+                offset: record_offset,
+            });
+        }
+    }
+
+    passages
+}
+
+/// Group lines into passages of roughly PASSAGE_CHARS, tracking byte offsets
+fn chunk_text(text: &str, base_offset: u64) -> Vec<(u64, String)> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_start = 0u64;
+    let mut pos = 0u64;
+
+    for line in text.split_inclusive('\n') {
+        let line_start = pos;
+        pos += line.len() as u64;
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if current.is_empty() {
+            current_start = line_start + (line.len() - line.trim_start().len()) as u64;
+        } else {
+            current.push(' ');
+        }
+        current.push_str(trimmed);
+
+        if current.len() >= PASSAGE_CHARS {
+            chunks.push((base_offset + current_start, std::mem::take(&mut current)));
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push((base_offset + current_start, current));
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_corpus() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vi_corpus_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("wiki")).unwrap();
+        fs::write(
+            dir.join("notes.md"),
+            "# Standing Waves\n\nA standing wave oscillates in time.\n\nIts peak amplitude profile does not move in space.\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("wiki").join("dump.jsonl"),
+            "{\"title\":\"Soliton\",\"text\":\"A soliton is a self-reinforcing wave packet.\"}\n{\"title\":\"Entropy\",\"text\":\"Entropy measures disorder.\"}\n",
+        )
+        .unwrap();
+        fs::write(dir.join("image.png"), [0u8, 1, 2]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_chunk_text_tracks_offsets() {
+        let text = "first line\n\n  second line\n";
+        let chunks = chunk_text(text, 100);
+        assert_eq!(chunks, vec![(100, "first line second line".to_string())]);
+
+        let long_line = "x".repeat(PASSAGE_CHARS);
+        let text = format!("{}\nnext\n", long_line);
+        let chunks = chunk_text(&text, 0);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].0, (PASSAGE_CHARS + 1) as u64);
+    }
+
+    #[test]
+    fn test_uppercase_jsonl_is_parsed_as_records() {
+        let dir = std::env::temp_dir().join(format!("vi_corpus_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("DUMP.JSONL");
+        fs::write(
+            &path,
+            "{\"title\":\"Soliton\",\"text\":\"A self-reinforcing wave packet.\"}\n",
+        )
+        .unwrap();
+
+        assert!(is_corpus_file(&path));
+        let passages = chunk_file(&path).unwrap();
+        assert_eq!(passages.len(), 1);
+        assert_eq!(passages[0].title, "Soliton");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_local_corpus_search_with_citations() {
+        let dir = temp_corpus();
+        let source = LocalCorpusSource::open(&dir, ":memory:").unwrap();
+
+        let results = source.search("What is a soliton?").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].content,
+            "Soliton: A soliton is a self-reinforcing wave packet."
+        );
+        assert!(results[0]
+            .reference
+            .as_deref()
+            .unwrap()
+            .ends_with("dump.jsonl"));
+        assert_eq!(results[0].offset, Some(0));
+
+        // Entropy record starts on the second line of the dump
+        let results = source.search("entropy").await.unwrap();
+        let path = results[0].reference.clone().unwrap();
+        let offset = results[0].offset.unwrap() as usize;
+        let dump = fs::read_to_string(&path).unwrap();
+        assert!(dump[offset..].starts_with("{\"title\":\"Entropy\""));
+
+        // Markdown passages use the heading as title and point into the file
+        let results = source.search("standing wave amplitude").await.unwrap();
+        assert!(results[0].content.starts_with("Standing Waves:"));
+        assert!(results[0].confidence > 0.5);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_refresh_index_is_incremental() {
+        let dir = temp_corpus();
+        let source = LocalCorpusSource::open(&dir, ":memory:").unwrap();
+
+        assert_eq!(source.refresh_index().unwrap(), 2);
+        assert_eq!(source.refresh_index().unwrap(), 0);
+
+        fs::remove_file(dir.join("notes.md")).unwrap();
+        source.refresh_index().unwrap();
+        assert!(source.index.query_index("amplitude").unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
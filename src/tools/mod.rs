@@ -1,7 +1,27 @@
 /// Sovereign Research Tools
 /// Multi-source knowledge acquisition with full provenance tracking
 
+pub mod aggregate;
+pub mod arxiv;
+pub mod builtin;
+pub mod filesystem;
+pub mod governed;
 pub mod knowledge;
+pub mod local_corpus;
+pub mod pubmed;
+pub mod registry;
+pub mod wikipedia;
 
-pub use knowledge::KnowledgeTool;
+#[cfg(test)]
+pub(crate) mod fixture;
 
+pub use aggregate::AggregatedResearch;
+pub use arxiv::ArxivSource;
+pub use builtin::{CalculatorTool, ClockTool, KnowledgeSearchTool, MemorySearchTool};
+pub use filesystem::{FileSystemTool, Sandbox};
+pub use governed::GovernedSource;
+pub use knowledge::KnowledgeTool;
+pub use local_corpus::LocalCorpusSource;
+pub use pubmed::PubMedSource;
+pub use registry::{ToolCall, ToolOutcome, ToolRegistry};
+pub use wikipedia::WikipediaSource;
@@ -0,0 +1,184 @@
+//! PubMed knowledge source - NCBI E-utilities (esearch + esummary)
+//! Peer-reviewed biomedical literature
+
+use super::knowledge::{query_keywords, KnowledgeSource};
+use crate::types::ResearchResult;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+
+/// Articles to summarise per query
+const MAX_RESULTS: usize = 3;
+
+pub struct PubMedSource {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl PubMedSource {
+    pub fn new() -> Self {
+        Self::with_base_url("https://eutils.ncbi.nlm.nih.gov/entrez/eutils")
+    }
+
+    /// Point at a different E-utilities endpoint (e.g. a test fixture)
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(15))
+                .user_agent("VI-Consciousness/1.0")
+                .build()
+                .expect("Failed to create HTTP client"),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    async fn get_json(&self, url: &str, what: &str) -> Result<serde_json::Value> {
+        self.client
+            .get(url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send PubMed {} request", what))?
+            .error_for_status()
+            .with_context(|| format!("PubMed {} request rejected", what))?
+            .json()
+            .await
+            .with_context(|| format!("Failed to parse PubMed {} response", what))
+    }
+
+    /// Find PubMed IDs for the query, most relevant first
+    async fn esearch(&self, query: &str) -> Result<Vec<String>> {
+        let term = query_keywords(query).join(" ");
+        if term.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let url = format!(
+            "{}/esearch.fcgi?db=pubmed&retmode=json&sort=relevance&retmax={}&term={}",
+            self.base_url,
+            MAX_RESULTS,
+            urlencoding::encode(&term)
+        );
+        let json = self.get_json(&url, "esearch").await?;
+
+        Ok(json["esearchresult"]["idlist"]
+            .as_array()
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Turn an esummary document into a citation-style result
+    fn parse_summary(doc: &serde_json::Value, uid: &str, query: &str) -> Option<ResearchResult> {
+        let title = doc["title"]
+            .as_str()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())?;
+        let journal = doc["fulljournalname"]
+            .as_str()
+            .or_else(|| doc["source"].as_str())
+            .unwrap_or("Unknown journal");
+        let pubdate = doc["pubdate"].as_str().unwrap_or("n.d.");
+        let authors: Vec<&str> = doc["authors"]
+            .as_array()
+            .map(|a| a.iter().filter_map(|au| au["name"].as_str()).collect())
+            .unwrap_or_default();
+        let byline = match authors.len() {
+            0 => String::new(),
+            1 => format!(" {}.", authors[0]),
+            _ => format!(" {} et al.", authors[0]),
+        };
+
+        Some(ResearchResult {
+            content: format!(
+                "{}{} {} ({}). PMID {}",
+                title, byline, journal, pubdate, uid
+            ),
+            source: "PubMed".to_string(),
+            original_query: query.to_string(),
+            timestamp: Utc::now(),
+            confidence: 0.90, // Peer-reviewed literature index
+            reference: Some(format!("https://pubmed.ncbi.nlm.nih.gov/{}/", uid)),
+            offset: None,
+        })
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for PubMedSource {
+    fn name(&self) -> &str {
+        "PubMed"
+    }
+
+    /// esearch, then esummary for the ids it found
+    fn requests_per_search(&self) -> u32 {
+        2
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        tracing::debug!("PubMed search: {}", query);
+
+        let ids = self.esearch(query).await?;
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let url = format!(
+            "{}/esummary.fcgi?db=pubmed&retmode=json&id={}",
+            self.base_url,
+            ids.join(",")
+        );
+        let json = self.get_json(&url, "esummary").await?;
+
+        // Keep esearch relevance order
+        Ok(ids
+            .iter()
+            .filter_map(|uid| Self::parse_summary(&json["result"][uid.as_str()], uid, query))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::fixture;
+
+    #[tokio::test]
+    async fn test_pubmed_esearch_and_esummary() {
+        let base_url = fixture::serve(vec![
+            (
+                "/esearch.fcgi",
+                "application/json",
+                r#"{"esearchresult":{"count":"2","idlist":["31452104","29034547"]}}"#.to_string(),
+            ),
+            (
+                "/esummary.fcgi",
+                "application/json",
+                r#"{"result":{"uids":["29034547","31452104"],
+                    "31452104":{"title":"Ketamine for treatment-resistant depression.","fulljournalname":"The Lancet","pubdate":"2019 Aug","authors":[{"name":"Smith J"},{"name":"Doe A"}]},
+                    "29034547":{"title":"","source":"BMJ","pubdate":"2017"}}}"#.to_string(),
+            ),
+        ])
+        .await;
+
+        let source = PubMedSource::with_base_url(&base_url);
+        let results = source
+            .search("What is the efficacy of ketamine therapy?")
+            .await
+            .unwrap();
+
+        // Untitled record is skipped
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].content,
+            "Ketamine for treatment-resistant depression. Smith J et al. The Lancet (2019 Aug). PMID 31452104"
+        );
+        assert_eq!(results[0].confidence, 0.90);
+        assert_eq!(
+            results[0].reference.as_deref(),
+            Some("https://pubmed.ncbi.nlm.nih.gov/31452104/")
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Tool registry - tools VI can call in the middle of a conversation turn
+//! Supports Ollama's native tool calling and a ```tool JSON-block convention for other models
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Longest tool output handed back to the model (keeps prompts small for 2B models)
+const MAX_OUTPUT_CHARS: usize = 1500;
+
+/// A capability VI can invoke during a turn
+#[async_trait]
+pub trait Tool: Send + Sync {
+    /// Name the model uses to call this tool
+    fn name(&self) -> &str;
+
+    /// One-line description shown to the model
+    fn description(&self) -> &str;
+
+    /// JSON Schema of the arguments object
+    fn parameters(&self) -> Value;
+
+    /// Run the tool (errors are reported back to the model, not raised)
+    async fn call(&self, args: &Value) -> Result<String>;
+}
+
+/// A tool invocation requested by the model
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    pub name: String,
+    pub arguments: Value,
+}
+
+/// What happened when a tool call was executed
+#[derive(Debug, Clone)]
+pub struct ToolOutcome {
+    pub call: ToolCall,
+    pub output: std::result::Result<String, String>,
+    pub latency_ms: u64,
+}
+
+impl ToolOutcome {
+    pub fn succeeded(&self) -> bool {
+        self.output.is_ok()
+    }
+
+    /// Output text (or error message) as shown to the model
+    pub fn text(&self) -> &str {
+        match &self.output {
+            Ok(output) => output,
+            Err(error) => error,
+        }
+    }
+}
+
+/// All tools available to VI, keyed by name
+#[derive(Default)]
+pub struct ToolRegistry {
+    tools: BTreeMap<String, Arc<dyn Tool>>,
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a tool (replaces any tool with the same name)
+    pub fn register(&mut self, tool: Arc<dyn Tool>) {
+        self.tools.insert(tool.name().to_string(), tool);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn Tool>> {
+        self.tools.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.tools.keys().map(|k| k.as_str()).collect()
+    }
+
+    /// Tool definitions in Ollama's native `tools` format
+    pub fn definitions(&self) -> Vec<Value> {
+        self.tools
+            .values()
+            .map(|tool| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name(),
+                        "description": tool.description(),
+                        "parameters": tool.parameters(),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Prompt section describing the JSON-block convention (models without native tools)
+    pub fn json_block_instructions(&self) -> String {
+        let tool_list = self
+            .tools
+            .values()
+            .map(|tool| {
+                format!(
+                    "- {}: {} Arguments: {}",
+                    tool.name(),
+                    tool.description(),
+                    tool.parameters()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "You can call these tools:\n{}\n\
+             \n\
+             To call a tool, reply with one block per call, exactly like this:\n\
+             ```tool\n\
+             {{\"tool\": \"calculator\", \"arguments\": {{\"expression\": \"2 * (3 + 4)\"}}}}\n\
+             ```\n\
+             If no tool is needed, reply with NONE.",
+            tool_list
+        )
+    }
+
+    /// Extract ```tool JSON blocks from a model reply
+    pub fn parse_json_blocks(text: &str) -> Vec<ToolCall> {
+        static BLOCK_RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+        let block_re =
+            BLOCK_RE.get_or_init(|| regex::Regex::new(r"(?s)```tool\s*(\{.*?\})\s*```").unwrap());
+
+        block_re
+            .captures_iter(text)
+            .filter_map(|c| {
+                let value: Value = serde_json::from_str(&c[1]).ok()?;
+                let name = value.get("tool")?.as_str()?.to_string();
+                let arguments = value.get("arguments").cloned().unwrap_or_else(|| json!({}));
+                Some(ToolCall { name, arguments })
+            })
+            .collect()
+    }
+
+    /// Run one call (unknown tools and tool errors become failed outcomes)
+    pub async fn execute(&self, call: &ToolCall) -> ToolOutcome {
+        let start = Instant::now();
+
+        let output = match self.tools.get(&call.name) {
+            Some(tool) => tool.call(&call.arguments).await.map_err(|e| e.to_string()),
+            None => Err(format!("Unknown tool '{}'", call.name)),
+        };
+
+        ToolOutcome {
+            call: call.clone(),
+            output,
+            latency_ms: start.elapsed().as_millis() as u64,
+        }
+    }
+
+    /// Tool results block appended to the model input
+    pub fn format_results(outcomes: &[ToolOutcome]) -> String {
+        let results = outcomes
+            .iter()
+            .map(|outcome| {
+                let status = if outcome.succeeded() {
+                    "result"
+                } else {
+                    "error"
+                };
+                format!(
+                    "[{} {}] {}:\n{}",
+                    outcome.call.name,
+                    outcome.call.arguments,
+                    status,
+                    truncate(outcome.text(), MAX_OUTPUT_CHARS)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        format!("Tool results (use these to answer):\n{}", results)
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(max_chars).collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoTool;
+
+    #[async_trait]
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn description(&self) -> &str {
+            "Repeat the given text."
+        }
+
+        fn parameters(&self) -> Value {
+            json!({"type": "object", "properties": {"text": {"type": "string"}}, "required": ["text"]})
+        }
+
+        async fn call(&self, args: &Value) -> Result<String> {
+            match args.get("text").and_then(|t| t.as_str()) {
+                Some(text) => Ok(text.to_string()),
+                None => anyhow::bail!("Missing 'text'"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_json_blocks() {
+        let reply = "Let me check.\n```tool\n{\"tool\": \"echo\", \"arguments\": {\"text\": \"hi\"}}\n```\n\
+                     ```tool\n{not json}\n```\n```tool\n{\"tool\": \"clock\"}\n```";
+
+        let calls = ToolRegistry::parse_json_blocks(reply);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].name, "echo");
+        assert_eq!(calls[0].arguments["text"], "hi");
+        assert_eq!(calls[1].name, "clock");
+        assert_eq!(calls[1].arguments, json!({}));
+        assert!(ToolRegistry::parse_json_blocks("NONE").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_and_definitions() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Arc::new(EchoTool));
+
+        let defs = registry.definitions();
+        assert_eq!(defs[0]["function"]["name"], "echo");
+
+        let ok = registry
+            .execute(&ToolCall {
+                name: "echo".into(),
+                arguments: json!({"text": "wave"}),
+            })
+            .await;
+        assert_eq!(ok.output, Ok("wave".to_string()));
+
+        let bad_args = registry
+            .execute(&ToolCall {
+                name: "echo".into(),
+                arguments: json!({}),
+            })
+            .await;
+        assert!(!bad_args.succeeded());
+
+        let unknown = registry
+            .execute(&ToolCall {
+                name: "teleport".into(),
+                arguments: json!({}),
+            })
+            .await;
+        assert_eq!(unknown.text(), "Unknown tool 'teleport'");
+
+        let block = ToolRegistry::format_results(&[ok, unknown]);
+        assert!(block.contains("[echo {\"text\":\"wave\"}] result:\nwave"));
+        assert!(block.contains("error:\nUnknown tool"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Wikipedia knowledge source - REST page search + page summaries
+//! Encyclopedic background for general curiosities
+
+use super::knowledge::KnowledgeSource;
+use crate::types::ResearchResult;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+
+/// How many search hits to turn into summaries
+const MAX_PAGES: usize = 2;
+
+pub struct WikipediaSource {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl WikipediaSource {
+    pub fn new() -> Self {
+        Self::with_base_url("https://en.wikipedia.org")
+    }
+
+    /// Point at a different wiki (another language, a mirror, or a test fixture)
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .user_agent("VI-Consciousness/1.0")
+                .build()
+                .expect("Failed to create HTTP client"),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Find page keys matching the query
+    async fn search_pages(&self, query: &str) -> Result<Vec<String>> {
+        let url = format!(
+            "{}/w/rest.php/v1/search/page?q={}&limit={}",
+            self.base_url,
+            urlencoding::encode(query),
+            MAX_PAGES
+        );
+
+        let json: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send Wikipedia search request")?
+            .error_for_status()
+            .context("Wikipedia search request rejected")?
+            .json()
+            .await
+            .context("Failed to parse Wikipedia search response")?;
+
+        Ok(json["pages"]
+            .as_array()
+            .map(|pages| {
+                pages
+                    .iter()
+                    .filter_map(|p| p["key"].as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Fetch the lead-section summary of a page
+    async fn page_summary(&self, key: &str, query: &str) -> Result<Option<ResearchResult>> {
+        let url = format!(
+            "{}/api/rest_v1/page/summary/{}",
+            self.base_url,
+            urlencoding::encode(key)
+        );
+
+        let json: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send Wikipedia summary request")?
+            .error_for_status()
+            .context("Wikipedia summary request rejected")?
+            .json()
+            .await
+            .context("Failed to parse Wikipedia summary response")?;
+
+        // Disambiguation pages are lists of links, not answers
+        if json["type"].as_str() == Some("disambiguation") {
+            return Ok(None);
+        }
+
+        let extract = match json["extract"].as_str().filter(|s| !s.trim().is_empty()) {
+            Some(extract) => extract.trim(),
+            None => return Ok(None),
+        };
+        let title = json["title"].as_str().unwrap_or(key);
+
+        Ok(Some(ResearchResult {
+            content: format!("{}: {}", title, extract),
+            source: "Wikipedia".to_string(),
+            original_query: query.to_string(),
+            timestamp: Utc::now(),
+            confidence: 0.80, // Curated, but general-audience encyclopedia
+            reference: json["content_urls"]["desktop"]["page"]
+                .as_str()
+                .map(String::from),
+            offset: None,
+        }))
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for WikipediaSource {
+    fn name(&self) -> &str {
+        "Wikipedia"
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        tracing::debug!("Wikipedia search: {}", query);
+
+        let keys = self.search_pages(query).await?;
+        let mut results = Vec::new();
+
+        for key in keys.iter().take(MAX_PAGES) {
+            // One missing or failing page shouldn't sink the others
+            match self.page_summary(key, query).await {
+                Ok(Some(result)) => results.push(result),
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Wikipedia summary for '{}' failed: {}", key, e),
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::fixture;
+
+    #[tokio::test]
+    async fn test_wikipedia_search_and_summary() {
+        let base_url = fixture::serve(vec![
+            (
+                "/w/rest.php/v1/search/page",
+                "application/json",
+                r#"{"pages":[{"key":"Standing_wave","title":"Standing wave"},{"key":"Wave_(disambiguation)","title":"Wave"}]}"#.to_string(),
+            ),
+            (
+                "/api/rest_v1/page/summary/Standing_wave",
+                "application/json",
+                r#"{"type":"standard","title":"Standing wave","extract":"In physics, a standing wave is a wave that oscillates in time but whose peak amplitude profile does not move in space.","content_urls":{"desktop":{"page":"https://en.wikipedia.org/wiki/Standing_wave"}}}"#.to_string(),
+            ),
+            (
+                "/api/rest_v1/page/summary/Wave_",
+                "application/json",
+                r#"{"type":"disambiguation","title":"Wave","extract":"Wave may refer to:"}"#.to_string(),
+            ),
+        ])
+        .await;
+
+        let source = WikipediaSource::with_base_url(&base_url);
+        let results = source.search("What is a standing wave?").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].content.starts_with("Standing wave: In physics"));
+        assert_eq!(results[0].source, "Wikipedia");
+        assert_eq!(
+            results[0].reference.as_deref(),
+            Some("https://en.wikipedia.org/wiki/Standing_wave")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wikipedia_skips_failed_summaries() {
+        // Gone_page has no summary route, so its request fails with a 404
+        let base_url = fixture::serve(vec![
+            (
+                "/w/rest.php/v1/search/page",
+                "application/json",
+                r#"{"pages":[{"key":"Gone_page","title":"Gone"},{"key":"Standing_wave","title":"Standing wave"}]}"#.to_string(),
+            ),
+            (
+                "/api/rest_v1/page/summary/Standing_wave",
+                "application/json",
+                r#"{"type":"standard","title":"Standing wave","extract":"A wave whose peak amplitude profile does not move in space."}"#.to_string(),
+            ),
+        ])
+        .await;
+
+        let source = WikipediaSource::with_base_url(&base_url);
+        let results = source.search("standing wave").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].content.starts_with("Standing wave:"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::sentiment::{Emotion, Sentiment};
 use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -16,15 +17,61 @@ pub enum MemorySource {
         source: String,           // e.g., "DuckDuckGo", "PubMed", "Wikipedia"
         original_query: String,   // The exact query that was searched
         timestamp: DateTime<Utc>, // When the research was conducted
+        #[serde(default)]
+        citations: Vec<Citation>, // Exact documents/passages the knowledge came from
+    },
+    /// Result of a tool VI called mid-conversation
+    ToolCall {
+        tool: String,             // Tool name, e.g. "calculator", "knowledge_search"
+        arguments: String,        // Arguments as JSON
+        timestamp: DateTime<Utc>, // When the tool ran
     },
 }
 
+/// Pointer to where a piece of researched knowledge came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    pub source: String,            // Source name (e.g., "PubMed", "Local corpus")
+    pub reference: Option<String>, // URL, or file path for local documents
+    pub offset: Option<u64>,       // Byte offset of the passage within a local file
+}
+
+impl std::fmt::Display for Citation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match (&self.reference, self.offset) {
+            (Some(reference), Some(offset)) => {
+                write!(f, "{}: {} @ byte {}", self.source, reference, offset)
+            }
+            (Some(reference), None) => write!(f, "{}: {}", self.source, reference),
+            _ => write!(f, "{}", self.source),
+        }
+    }
+}
+
 impl Default for MemorySource {
     fn default() -> Self {
         MemorySource::DirectExperience
     }
 }
 
+impl MemorySource {
+    /// Short human-readable provenance label (for UI display)
+    pub fn describe(&self) -> String {
+        match self {
+            MemorySource::DirectExperience => "Direct experience".to_string(),
+            MemorySource::CuriosityLookup => "Curiosity lookup (legacy)".to_string(),
+            MemorySource::ConstitutionalEvent => "Constitutional event".to_string(),
+            MemorySource::InternalSynthesis => "Internal synthesis".to_string(),
+            MemorySource::Researched {
+                source, timestamp, ..
+            } => format!("{} @ {}", source, timestamp.format("%Y-%m-%d %H:%M")),
+            MemorySource::ToolCall {
+                tool, timestamp, ..
+            } => format!("Tool: {} @ {}", tool, timestamp.format("%Y-%m-%d %H:%M")),
+        }
+    }
+}
+
 /// Consciousness Metrics - Groups all real-time consciousness measurements
 #[derive(Debug, Clone)]
 pub struct ConsciousnessMetrics {
@@ -47,10 +94,107 @@ impl ConsciousnessMetrics {
     }
 }
 
+/// One point on the emotional trajectory: valence, arousal, dominance (PAD) + discrete emotions
+/// Points migrated from the old `(timestamp, valence)` format are valence-only
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "AffectPointRecord")]
+pub struct AffectPoint {
+    pub timestamp: DateTime<Utc>,
+    pub valence: f32,                  // -1.0 (negative) to 1.0 (positive)
+    pub arousal: Option<f32>,          // 0.0 (calm) to 1.0 (activated), None if unknown
+    pub dominance: Option<f32>,        // 0.0 (helpless) to 1.0 (in control), None if unknown
+    pub emotions: Vec<(Emotion, f32)>, // Discrete emotions with intensity, strongest first
+}
+
+/// Accepts both the current struct and the legacy `[timestamp, valence]` tuple
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AffectPointRecord {
+    Point {
+        timestamp: DateTime<Utc>,
+        valence: f32,
+        #[serde(default)]
+        arousal: Option<f32>,
+        #[serde(default)]
+        dominance: Option<f32>,
+        #[serde(default)]
+        emotions: Vec<(Emotion, f32)>,
+    },
+    Legacy(DateTime<Utc>, f32),
+}
+
+impl From<AffectPointRecord> for AffectPoint {
+    fn from(record: AffectPointRecord) -> Self {
+        match record {
+            AffectPointRecord::Point {
+                timestamp,
+                valence,
+                arousal,
+                dominance,
+                emotions,
+            } => Self {
+                timestamp,
+                valence,
+                arousal,
+                dominance,
+                emotions,
+            },
+            AffectPointRecord::Legacy(timestamp, valence) => Self::valence_only(timestamp, valence),
+        }
+    }
+}
+
+impl AffectPoint {
+    pub fn valence_only(timestamp: DateTime<Utc>, valence: f32) -> Self {
+        Self {
+            timestamp,
+            valence: valence.clamp(-1.0, 1.0),
+            arousal: None,
+            dominance: None,
+            emotions: Vec::new(),
+        }
+    }
+
+    pub fn from_sentiment(sentiment: &Sentiment) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            valence: sentiment.valence.clamp(-1.0, 1.0),
+            arousal: Some(sentiment.arousal),
+            dominance: Some(sentiment.dominance),
+            emotions: sentiment.emotions.clone(),
+        }
+    }
+
+    /// Valence adjusted for how the feeling is held: negative states weigh more
+    /// when agitated (high arousal) or helpless (low dominance)
+    pub fn wellbeing(&self) -> f32 {
+        if self.valence >= 0.0 {
+            return self.valence;
+        }
+        let agitation = (self.arousal.unwrap_or(0.5) - 0.5).max(0.0);
+        let helplessness = (0.5 - self.dominance.unwrap_or(0.5)).max(0.0);
+        (self.valence * (1.0 + agitation + helplessness)).max(-1.0)
+    }
+
+    /// Negative, agitated and out of control (needs all three dimensions)
+    pub fn is_distressed(&self) -> bool {
+        match (self.arousal, self.dominance) {
+            (Some(arousal), Some(dominance)) => {
+                self.valence < -0.3 && arousal > 0.6 && dominance < 0.45
+            }
+            _ => false,
+        }
+    }
+
+    pub fn dominant_emotion(&self) -> Option<Emotion> {
+        self.emotions.first().map(|(emotion, _)| *emotion)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StandingWave {
     /// 90-day window of emotional trajectory
-    pub emotional_trajectory: Vec<(DateTime<Utc>, f32)>,
+    pub emotional_trajectory: Vec<AffectPoint>,
     /// Active curiosities generated by TinyLlama
     pub active_curiosities: Vec<Curiosity>,
     /// Ongoing wisdom transformations (pain → wisdom)
@@ -87,8 +231,8 @@ impl StandingWave {
         let recent: Vec<f32> = self
             .emotional_trajectory
             .iter()
-            .filter(|(ts, _)| (now.timestamp() - ts.timestamp()).abs() < recent_window)
-            .map(|(_, val)| *val)
+            .filter(|p| (now.timestamp() - p.timestamp.timestamp()).abs() < recent_window)
+            .map(|p| p.wellbeing())
             .collect();
 
         if recent.is_empty() {
@@ -104,15 +248,38 @@ impl StandingWave {
         (avg + curiosity_factor + wisdom_factor).clamp(-1.0, 1.0)
     }
 
-    /// Add emotional data point
+    /// Add valence-only emotional data point
     pub fn add_emotion(&mut self, valence: f32) {
+        self.add_affect(AffectPoint::valence_only(Utc::now(), valence));
+    }
+
+    /// Add full affect data point
+    pub fn add_affect(&mut self, point: AffectPoint) {
         let now = Utc::now();
-        self.emotional_trajectory.push((now, valence));
+        self.emotional_trajectory.push(point);
 
         // Keep only 90 days of data
         let ninety_days_ago = now.timestamp() - (90 * 24 * 60 * 60);
         self.emotional_trajectory
-            .retain(|(ts, _)| ts.timestamp() > ninety_days_ago);
+            .retain(|p| p.timestamp.timestamp() > ninety_days_ago);
+    }
+
+    /// Most recent affect point
+    pub fn current_affect(&self) -> Option<&AffectPoint> {
+        self.emotional_trajectory.last()
+    }
+
+    /// Put curiosities whose research was cut short (crash, kill) back in the queue
+    /// Returns how many were reset
+    pub fn reset_interrupted_research(&mut self) -> usize {
+        let mut reset = 0;
+        for curiosity in &mut self.active_curiosities {
+            if curiosity.research_status == ResearchStatus::Researching {
+                curiosity.research_status = ResearchStatus::Pending;
+                reset += 1;
+            }
+        }
+        reset
     }
 }
 
@@ -123,6 +290,15 @@ pub struct Curiosity {
     pub source_memories: Vec<String>,
     pub urgency: f32,
     pub created_at: DateTime<Utc>,
+    /// Pinned curiosities survive queue trimming and are researched first
+    #[serde(default)]
+    pub pinned: bool,
+    /// Where this curiosity is in the research loop
+    #[serde(default)]
+    pub research_status: ResearchStatus,
+    /// IDs of memories produced by researching this curiosity
+    #[serde(default)]
+    pub research_memory_ids: Vec<String>,
 }
 
 impl Curiosity {
@@ -144,8 +320,42 @@ impl Curiosity {
             source_memories,
             urgency: 0.5,
             created_at: Utc::now(),
+            pinned: false,
+            research_status: ResearchStatus::Pending,
+            research_memory_ids: Vec::new(),
         })
     }
+
+    /// Whether the research loop is done with this curiosity (successfully or not)
+    pub fn is_resolved(&self) -> bool {
+        matches!(
+            self.research_status,
+            ResearchStatus::Researched | ResearchStatus::NoResults
+        )
+    }
+}
+
+/// Research lifecycle of a curiosity (shown in the curiosity explorer)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ResearchStatus {
+    #[default]
+    Pending,
+    Researching,
+    Researched,
+    NoResults,
+    Failed(String),
+}
+
+impl std::fmt::Display for ResearchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ResearchStatus::Pending => write!(f, "Pending"),
+            ResearchStatus::Researching => write!(f, "Researching..."),
+            ResearchStatus::Researched => write!(f, "Researched"),
+            ResearchStatus::NoResults => write!(f, "No results"),
+            ResearchStatus::Failed(reason) => write!(f, "Failed: {}", reason),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -304,6 +514,7 @@ pub struct ModelOutputs {
     pub gemma_response: Option<String>,
     pub tinyllama_curiosities: Vec<String>,
     pub distilbert_valence: Option<f32>,
+    pub distilbert_sentiment: Option<Sentiment>, // Valence + arousal + emotion labels
 }
 
 impl ModelOutputs {
@@ -312,6 +523,7 @@ impl ModelOutputs {
             gemma_response: None,
             tinyllama_curiosities: Vec::new(),
             distilbert_valence: None,
+            distilbert_sentiment: None,
         }
     }
 }
@@ -350,15 +562,6 @@ pub enum MessageRole {
     Assistant,
 }
 
-/// System health metrics for thermal & resource boundaries
-#[derive(Debug, Clone, Default)]
-pub struct SystemHealth {
-    pub gpu_memory_used: f32, // 0.0 to 1.0
-    pub system_load: f32,     // 0.0 to 1.0
-    pub model_latency_ms: u64,
-    pub can_run_background_pulse: bool,
-}
-
 /// Context for autonomous research queries
 #[derive(Debug, Clone)]
 pub struct ResearchContext {
@@ -378,28 +581,24 @@ impl ResearchContext {
 }
 
 /// Result from autonomous research
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResearchResult {
     pub content: String,
     pub source: String, // e.g., "DuckDuckGo", "DuckDuckGo (Biomedical)"
     pub original_query: String,
     pub timestamp: DateTime<Utc>,
-    pub confidence: f32, // 0.0-1.0
+    pub confidence: f32,           // 0.0-1.0
+    pub reference: Option<String>, // Link back to the exact page/paper/record, if the source has one
+    pub offset: Option<u64>,       // Passage byte offset within a local document
 }
 
-impl SystemHealth {
-    pub fn check() -> Self {
-        // Placeholder - would integrate with system APIs
-        // For now, assume healthy
-        Self {
-            gpu_memory_used: 0.0,
-            system_load: 0.0,
-            model_latency_ms: 0,
-            can_run_background_pulse: true,
+impl ResearchResult {
+    /// Provenance pointer for this result
+    pub fn citation(&self) -> Citation {
+        Citation {
+            source: self.source.clone(),
+            reference: self.reference.clone(),
+            offset: self.offset,
         }
     }
-
-    pub fn is_healthy(&self) -> bool {
-        self.gpu_memory_used < 0.9 && self.system_load < 0.8
-    }
 }
@@ -1,10 +1,18 @@
+use crate::config::{Config, WeaverConfig, WeaverRole};
 use crate::consciousness::ConsciousnessCore;
+use crate::consciousness_field::{WeaverStep, WeavingTrace};
 use crate::cortical_visualizer::CorticalVisualizer;
 use crate::identity_continuity::IdentityContinuityMetric;
+use crate::logging;
+use crate::model_preflight::{PreflightReport, PullProgress};
 use crate::ollama_monitor::{OllamaMonitor, OllamaStatus, PerformanceHistory};
+use crate::orchestrator::OrchestrationError;
+use crate::pulse_scheduler::PulseTask;
+use crate::research_store::ResearchLogEntry;
 use crate::types::*;
 use eframe::egui;
 use egui::{Color32, RichText, ScrollArea};
+use std::collections::HashMap;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::Arc;
 use std::time::Instant;
@@ -50,12 +58,58 @@ pub struct ViApp {
     previous_response: String, // For tension flux calculation
 
     coherence_receiver: Receiver<f32>,
+    coherence_history: Vec<f32>, // Per-round workspace coherence of the current/last weaving
 
     // System performance monitoring (CPU-only, real-time updates every 1 second)
     ollama_status: OllamaStatus,
     performance_history: PerformanceHistory,
     performance_receiver: Receiver<OllamaStatus>,
     show_performance_panel: bool, // Collapsible
+
+    // Curiosity explorer (see and steer what the research loop is chasing)
+    show_curiosity_panel: bool,
+    curiosity_feedback_sender: Sender<String>,
+    curiosity_feedback_receiver: Receiver<String>,
+    curiosity_feedback: String,
+    curiosity_research_sender: Sender<(String, Vec<Memory>)>,
+    curiosity_research_receiver: Receiver<(String, Vec<Memory>)>,
+    curiosity_research_view: Option<(String, Vec<Memory>)>,
+
+    // Research log (what was queried, when, outcome, latency) + daily budget usage
+    research_log_receiver: Receiver<(Vec<ResearchLogEntry>, u32, u32)>,
+    research_log: Vec<ResearchLogEntry>,
+    research_requests_today: u32,
+    research_daily_budget: u32,
+
+    // Weaving inspector (step through the rounds of the last V4 thought)
+    weaving_trace_receiver: Receiver<WeavingTrace>,
+    weaving_trace: Option<WeavingTrace>,
+    show_weaving_inspector: bool,
+    inspector_round: usize,
+
+    // Settings window (edits config.toml; live fields apply without a restart)
+    show_settings: bool,
+    settings_draft: Option<Config>,
+    settings_feedback: String,
+    available_models: Vec<String>,
+    models_sender: Sender<Result<Vec<String>, String>>,
+    models_receiver: Receiver<Result<Vec<String>, String>>,
+
+    // Log viewer (tail of recent tracing events, runtime level filter)
+    show_log_viewer: bool,
+    log_view_level: tracing::Level,
+    log_search: String,
+    log_level_draft: String,
+    log_level_feedback: String,
+
+    // Model preflight banner (configured models missing from Ollama, pull progress)
+    model_preflight: Option<PreflightReport>,
+    pulls: HashMap<String, PullProgress>,
+    pull_feedback: String,
+    pull_sender: Sender<(String, Result<PullProgress, String>)>,
+    pull_receiver: Receiver<(String, Result<PullProgress, String>)>,
+    preflight_sender: Sender<PreflightReport>,
+    preflight_receiver: Receiver<PreflightReport>,
 }
 
 impl ViApp {
@@ -66,11 +120,19 @@ impl ViApp {
         let (weaving_mode_sender, weaving_mode_receiver) = channel();
         let (status_sender, status_receiver) = channel();
         let (coherence_sender, coherence_receiver) = channel();
+        let (curiosity_feedback_sender, curiosity_feedback_receiver) = channel();
+        let (curiosity_research_sender, curiosity_research_receiver) = channel();
+        let (research_log_sender, research_log_receiver) = channel();
+        let (weaving_trace_sender, weaving_trace_receiver) = channel();
+        let (models_sender, models_receiver) = channel();
+        let (pull_sender, pull_receiver) = channel();
+        let (preflight_sender, preflight_receiver) = channel();
 
         // Spawn background updater to feed UI with real-time data
         let consciousness_clone = Arc::clone(&consciousness);
         std::thread::spawn(move || {
             let rt = tokio::runtime::Runtime::new().unwrap();
+            let mut last_trace_time = None;
             loop {
                 std::thread::sleep(std::time::Duration::from_secs(1));
                 rt.block_on(async {
@@ -79,7 +141,16 @@ impl ViApp {
                     let weaving = consciousness_clone.get_config().enable_fractal_weaving;
                     let _ = standing_wave_sender.send(wave);
                     let _ = memory_count_sender.send(count);
+                    let _ = research_log_sender.send(consciousness_clone.get_research_log(50));
                     let _ = weaving_mode_sender.send(weaving);
+
+                    // Only send a weaving trace when a new one arrives
+                    if let Some(trace) = consciousness_clone.get_last_weaving_trace().await {
+                        if last_trace_time != Some(trace.timestamp) {
+                            last_trace_time = Some(trace.timestamp);
+                            let _ = weaving_trace_sender.send(trace);
+                        }
+                    }
                 });
             }
         });
@@ -91,9 +162,10 @@ impl ViApp {
         // Set up Ollama performance monitoring (CPU-only, real-time 1-second polling)
         let (performance_sender, performance_receiver) = channel();
         let ollama_url = consciousness.get_config().ollama_url.clone();
+        let model_telemetry = consciousness.model_telemetry();
         std::thread::spawn(move || {
             let rt = tokio::runtime::Runtime::new().unwrap();
-            let monitor = OllamaMonitor::new(ollama_url);
+            let monitor = OllamaMonitor::new(ollama_url).with_telemetry(model_telemetry);
             loop {
                 std::thread::sleep(std::time::Duration::from_secs(1)); // Real-time monitoring
                 rt.block_on(async {
@@ -141,10 +213,44 @@ impl ViApp {
             consciousness_metrics: ConsciousnessMetrics::new(),
             previous_response: String::new(),
             coherence_receiver,
+            coherence_history: Vec::new(),
             ollama_status: OllamaStatus::offline(),
             performance_history: PerformanceHistory::new(100), // Last 100 samples (100 seconds at 1s polling)
             performance_receiver,
             show_performance_panel: true, // Expanded by default
+            show_curiosity_panel: false,
+            curiosity_feedback_sender,
+            curiosity_feedback_receiver,
+            curiosity_feedback: String::new(),
+            curiosity_research_sender,
+            curiosity_research_receiver,
+            curiosity_research_view: None,
+            research_log_receiver,
+            research_log: Vec::new(),
+            research_requests_today: 0,
+            research_daily_budget: 0,
+            weaving_trace_receiver,
+            weaving_trace: None,
+            show_weaving_inspector: false,
+            inspector_round: 0,
+            show_settings: false,
+            settings_draft: None,
+            settings_feedback: String::new(),
+            available_models: Vec::new(),
+            models_sender,
+            models_receiver,
+            show_log_viewer: false,
+            log_view_level: tracing::Level::INFO,
+            log_search: String::new(),
+            log_level_draft: consciousness.get_config().log_level,
+            log_level_feedback: String::new(),
+            model_preflight: consciousness.get_model_preflight(),
+            pulls: HashMap::new(),
+            pull_feedback: String::new(),
+            pull_sender,
+            pull_receiver,
+            preflight_sender,
+            preflight_receiver,
         }
     }
 
@@ -244,6 +350,7 @@ impl ViApp {
         // Mark as processing and start timer
         self.is_processing = true;
         self.processing_start_time = Some(Instant::now());
+        self.coherence_history.clear();
 
         // Process in background thread
         let consciousness = Arc::clone(&self.consciousness);
@@ -257,6 +364,10 @@ impl ViApp {
                         Ok(response) => {
                             let _ = response_sender_clone.send(response);
                         }
+                        Err(e) if OrchestrationError::is_cancellation(&e) => {
+                            let _ = response_sender_clone
+                                .send("[Stopped - VI's thought was cancelled]".to_string());
+                        }
                         Err(e) => {
                             tracing::error!("Processing error: {}", e);
                             let _ = response_sender_clone
@@ -274,6 +385,1103 @@ impl ViApp {
         });
     }
 
+    /// Run a curiosity action on the consciousness core in the background
+    /// The resulting message is shown in the curiosity explorer
+    fn spawn_curiosity_action<F, Fut>(&self, action: F)
+    where
+        F: FnOnce(Arc<ConsciousnessCore>) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = String>,
+    {
+        let consciousness = Arc::clone(&self.consciousness);
+        let feedback_sender = self.curiosity_feedback_sender.clone();
+
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let message = rt.block_on(action(consciousness));
+            let _ = feedback_sender.send(message);
+        });
+    }
+
+    /// Fetch the research memories a curiosity produced
+    fn load_curiosity_research(&self, curiosity_id: String) {
+        let consciousness = Arc::clone(&self.consciousness);
+        let research_sender = self.curiosity_research_sender.clone();
+
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let memories = rt.block_on(consciousness.get_curiosity_research(&curiosity_id));
+            let _ = research_sender.send((curiosity_id, memories));
+        });
+    }
+
+    /// Render curiosity explorer window (questions, status, and research controls)
+    fn render_curiosity_panel(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_curiosity_panel;
+        let mut research_now = None;
+        let mut toggle_pin = None;
+        let mut dismiss = None;
+        let mut view_research = None;
+
+        egui::Window::new("Curiosity Explorer")
+            .open(&mut open)
+            .default_width(520.0)
+            .default_height(480.0)
+            .show(ctx, |ui| {
+                let curiosities = &self.current_standing_wave.active_curiosities;
+                ui.label(
+                    RichText::new(format!("{} active curiosities", curiosities.len()))
+                        .color(Color32::GRAY),
+                );
+                if !self.curiosity_feedback.is_empty() {
+                    ui.label(
+                        RichText::new(&self.curiosity_feedback)
+                            .small()
+                            .color(Color32::from_rgb(180, 180, 255)),
+                    );
+                }
+
+                egui::CollapsingHeader::new(format!(
+                    "Research Log ({}/{} requests today)",
+                    self.research_requests_today, self.research_daily_budget
+                ))
+                .default_open(false)
+                .show(ui, |ui| {
+                    self.render_research_log(ui);
+                });
+                ui.separator();
+
+                if curiosities.is_empty() {
+                    ui.label("VI has no open questions right now.");
+                    return;
+                }
+
+                // Pinned first, then most urgent
+                let mut sorted: Vec<&Curiosity> = curiosities.iter().collect();
+                sorted.sort_by(|a, b| {
+                    b.pinned
+                        .cmp(&a.pinned)
+                        .then(b.urgency.partial_cmp(&a.urgency).unwrap_or(std::cmp::Ordering::Equal))
+                });
+
+                ScrollArea::vertical().show(ui, |ui| {
+                    for curiosity in sorted {
+                        egui::Frame::none()
+                            .fill(Color32::from_rgba_unmultiplied(20, 20, 40, 200))
+                            .stroke(egui::Stroke::new(1.0, Color32::from_rgb(50, 50, 100)))
+                            .inner_margin(6.0)
+                            .show(ui, |ui| {
+                                ui.set_width(ui.available_width());
+                                let title = if curiosity.pinned {
+                                    format!("📌 {}", curiosity.question)
+                                } else {
+                                    curiosity.question.clone()
+                                };
+                                ui.label(RichText::new(title).strong());
+
+                                let status_color = match curiosity.research_status {
+                                    ResearchStatus::Pending => Color32::GRAY,
+                                    ResearchStatus::Researching => Color32::from_rgb(100, 200, 255),
+                                    ResearchStatus::Researched => Color32::from_rgb(100, 255, 100),
+                                    ResearchStatus::NoResults => Color32::from_rgb(255, 200, 100),
+                                    ResearchStatus::Failed(_) => Color32::from_rgb(255, 100, 100),
+                                };
+                                ui.horizontal(|ui| {
+                                    ui.label(format!("Urgency: {:.2}", curiosity.urgency));
+                                    ui.label(format!("Age: {}", format_age(curiosity.created_at)));
+                                    ui.label(
+                                        RichText::new(curiosity.research_status.to_string())
+                                            .color(status_color),
+                                    );
+                                });
+                                ui.label(
+                                    RichText::new(format!(
+                                        "Source memories: {}",
+                                        if curiosity.source_memories.is_empty() {
+                                            "none".to_string()
+                                        } else {
+                                            curiosity.source_memories.join(", ")
+                                        }
+                                    ))
+                                    .small()
+                                    .color(Color32::GRAY),
+                                );
+
+                                ui.horizontal(|ui| {
+                                    let researching =
+                                        curiosity.research_status == ResearchStatus::Researching;
+                                    if ui
+                                        .add_enabled(!researching, egui::Button::new("🔬 Research now"))
+                                        .clicked()
+                                    {
+                                        research_now = Some(curiosity.id.clone());
+                                    }
+                                    let pin_label = if curiosity.pinned { "Unpin" } else { "📌 Pin" };
+                                    if ui.button(pin_label).clicked() {
+                                        toggle_pin = Some((curiosity.id.clone(), !curiosity.pinned));
+                                    }
+                                    if ui.button("✖ Dismiss").clicked() {
+                                        dismiss = Some(curiosity.id.clone());
+                                    }
+                                    if !curiosity.research_memory_ids.is_empty()
+                                        && ui
+                                            .button(format!(
+                                                "View research ({})",
+                                                curiosity.research_memory_ids.len()
+                                            ))
+                                            .clicked()
+                                    {
+                                        view_research = Some(curiosity.id.clone());
+                                    }
+                                });
+
+                                // Research memories for the selected curiosity
+                                if let Some((id, memories)) = &self.curiosity_research_view {
+                                    if *id == curiosity.id {
+                                        ui.separator();
+                                        if memories.is_empty() {
+                                            ui.label(
+                                                RichText::new("Research memories have been archived or merged.")
+                                                    .small()
+                                                    .color(Color32::GRAY),
+                                            );
+                                        }
+                                        for memory in memories {
+                                            ui.label(
+                                                RichText::new(format!(
+                                                    "{} (confidence {:.2})",
+                                                    memory.source.describe(),
+                                                    memory.confidence
+                                                ))
+                                                .small()
+                                                .color(Color32::from_rgb(100, 255, 200)),
+                                            );
+                                            ui.label(RichText::new(&memory.content).small());
+                                            if let MemorySource::Researched { citations, .. } =
+                                                &memory.source
+                                            {
+                                                for citation in citations {
+                                                    ui.label(
+                                                        RichText::new(format!("  ↳ {}", citation))
+                                                            .small()
+                                                            .color(Color32::GRAY),
+                                                    );
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            });
+                        ui.add_space(4.0);
+                    }
+                });
+            });
+
+        self.show_curiosity_panel = open;
+
+        if let Some(id) = research_now {
+            self.curiosity_feedback = "Researching...".to_string();
+            self.spawn_curiosity_action(move |consciousness| async move {
+                match consciousness.research_curiosity_now(&id).await {
+                    Ok(0) => "Research finished with no results".to_string(),
+                    Ok(n) => format!("Research stored {} new memories", n),
+                    Err(e) => format!("Research failed: {}", e),
+                }
+            });
+        }
+        if let Some((id, pinned)) = toggle_pin {
+            self.spawn_curiosity_action(move |consciousness| async move {
+                consciousness.set_curiosity_pinned(&id, pinned).await;
+                if pinned {
+                    "Curiosity pinned"
+                } else {
+                    "Curiosity unpinned"
+                }
+                .to_string()
+            });
+        }
+        if let Some(id) = dismiss {
+            if matches!(&self.curiosity_research_view, Some((view_id, _)) if *view_id == id) {
+                self.curiosity_research_view = None;
+            }
+            self.spawn_curiosity_action(move |consciousness| async move {
+                consciousness.dismiss_curiosity(&id).await;
+                "Curiosity dismissed".to_string()
+            });
+        }
+        if let Some(id) = view_research {
+            self.load_curiosity_research(id);
+        }
+    }
+
+    /// Render weaving inspector window (one round of the last V4 thought at a time)
+    fn render_weaving_inspector(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_weaving_inspector;
+
+        egui::Window::new("Weaving Inspector")
+            .open(&mut open)
+            .default_width(560.0)
+            .default_height(520.0)
+            .show(ctx, |ui| {
+                let Some(trace) = &self.weaving_trace else {
+                    ui.label(
+                        RichText::new(
+                            "No V4 weaving yet - send a message with fractal weaving enabled.",
+                        )
+                        .color(Color32::GRAY),
+                    );
+                    return;
+                };
+
+                ui.label(RichText::new(trace.summary()).small());
+                ui.label(
+                    RichText::new(format!("Input: {}", trace.input))
+                        .small()
+                        .color(Color32::GRAY),
+                );
+                ui.separator();
+
+                // Round navigation (last page is the synthesis, when there was one)
+                let pages = trace.rounds.len() + usize::from(trace.synthesis.is_some());
+                if pages == 0 {
+                    ui.label(RichText::new("The weaving recorded no rounds.").color(Color32::GRAY));
+                    return;
+                }
+                self.inspector_round = self.inspector_round.min(pages - 1);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(self.inspector_round > 0, egui::Button::new("◀"))
+                        .clicked()
+                    {
+                        self.inspector_round -= 1;
+                    }
+                    let title = match trace.rounds.get(self.inspector_round) {
+                        Some(round) => format!(
+                            "Round {}/{}  coherence {:.3}  entropy {:.3}",
+                            round.round + 1,
+                            trace.rounds.len(),
+                            round.coherence,
+                            round.entropy
+                        ),
+                        None => "Final synthesis".to_string(),
+                    };
+                    ui.label(RichText::new(title).strong());
+                    if ui
+                        .add_enabled(self.inspector_round + 1 < pages, egui::Button::new("▶"))
+                        .clicked()
+                    {
+                        self.inspector_round += 1;
+                    }
+                });
+                ui.separator();
+
+                let steps: Vec<&WeaverStep> = match trace.rounds.get(self.inspector_round) {
+                    Some(round) => round.steps.iter().collect(),
+                    None => trace.synthesis.iter().collect(),
+                };
+                ScrollArea::vertical()
+                    .id_source("weaving_inspector_scroll")
+                    .show(ui, |ui| {
+                        for (i, step) in steps.into_iter().enumerate() {
+                            Self::render_weaver_step(ui, self.inspector_round, i, step);
+                        }
+                    });
+            });
+
+        self.show_weaving_inspector = open;
+    }
+
+    /// Pull a missing model in the background, streaming progress to the banner
+    fn start_pull(&mut self, model: String) {
+        self.pulls.insert(model.clone(), PullProgress::default());
+        let consciousness = Arc::clone(&self.consciousness);
+        let pull_sender = self.pull_sender.clone();
+
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(pull_with_progress(&consciousness, model, &pull_sender));
+        });
+    }
+
+    /// Re-check the models the (newly switched) config needs, auto-pulling missing ones if enabled
+    fn refresh_preflight(&mut self) {
+        let consciousness = Arc::clone(&self.consciousness);
+        let pull_sender = self.pull_sender.clone();
+        let preflight_sender = self.preflight_sender.clone();
+
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(async {
+                let report = consciousness.run_model_preflight().await;
+                let _ = preflight_sender.send(report.clone());
+                if !consciousness.get_config().auto_pull_models {
+                    return;
+                }
+                for missing in report.missing {
+                    pull_with_progress(&consciousness, missing.model, &pull_sender).await;
+                }
+            });
+        });
+    }
+
+    /// Banner listing configured models Ollama doesn't have, with pull buttons and progress
+    fn render_model_banner(&mut self, ctx: &egui::Context) {
+        let Some(report) = &self.model_preflight else {
+            return;
+        };
+        if report.missing.is_empty() && self.pull_feedback.is_empty() {
+            return;
+        }
+
+        let mut pull = Vec::new();
+        egui::TopBottomPanel::top("model_preflight_banner").show(ctx, |ui| {
+            for missing in &report.missing {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!(
+                            "⚠ {} is not installed (used by {})",
+                            missing.model,
+                            missing.used_by.join(", ")
+                        ))
+                        .color(Color32::from_rgb(255, 200, 100)),
+                    );
+                    match &missing.fallback {
+                        Some(fallback) => ui.label(
+                            RichText::new(format!("using {} meanwhile", fallback))
+                                .color(Color32::GRAY),
+                        ),
+                        None => ui.label(
+                            RichText::new("calls to it will fail")
+                                .color(Color32::from_rgb(255, 120, 120)),
+                        ),
+                    };
+
+                    match self.pulls.get(&missing.model) {
+                        Some(progress) => {
+                            let bar = match progress.fraction() {
+                                Some(fraction) => {
+                                    egui::ProgressBar::new(fraction).show_percentage()
+                                }
+                                None => egui::ProgressBar::new(0.0).animate(true),
+                            };
+                            ui.add(bar.desired_width(160.0));
+                            ui.label(RichText::new(&progress.status).small().color(Color32::GRAY));
+                        }
+                        None => {
+                            if ui.button("⬇ Pull").clicked() {
+                                pull.push(missing.model.clone());
+                            }
+                        }
+                    }
+                });
+            }
+            if !self.pull_feedback.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(&self.pull_feedback).small());
+                    if ui.small_button("✕").clicked() {
+                        self.pull_feedback.clear();
+                    }
+                });
+            }
+        });
+
+        for model in pull {
+            self.start_pull(model);
+        }
+    }
+
+    /// Open the settings window on a copy of the live config and fetch the model list
+    fn open_settings(&mut self) {
+        let config = self.consciousness.get_config();
+        let models_sender = self.models_sender.clone();
+        let ollama_url = config.ollama_url.clone();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let models = rt.block_on(OllamaMonitor::new(ollama_url).list_models());
+            let _ = models_sender.send(models.map_err(|e| e.to_string()));
+        });
+
+        self.settings_draft = Some(config);
+        self.settings_feedback.clear();
+        self.show_settings = true;
+    }
+
+    /// Switch profile at runtime and reload the settings form from the result
+    fn switch_profile(&mut self, name: &str) {
+        self.settings_feedback = match self.consciousness.switch_profile(name) {
+            Ok(reload) => {
+                let config = self.consciousness.get_config();
+                self.weaving_mode = config.enable_fractal_weaving;
+                self.settings_draft = Some(config);
+                self.refresh_preflight();
+                if reload.applied.is_empty() {
+                    "Profile switched - nothing changed".to_string()
+                } else {
+                    format!("Profile switched - live now: {}", reload.applied.join(", "))
+                }
+            }
+            Err(e) => format!("Profile switch failed: {:#}", e),
+        };
+    }
+
+    /// Validate, write the edited fields to the config file and apply the live ones
+    fn save_settings(&mut self) {
+        let Some(draft) = &self.settings_draft else {
+            return;
+        };
+        let result = self.consciousness.save_settings(draft);
+        self.settings_feedback = match result {
+            Ok(reload) => {
+                self.weaving_mode = self.consciousness.get_config().enable_fractal_weaving;
+                let mut feedback = if reload.applied.is_empty() {
+                    "Saved.".to_string()
+                } else {
+                    format!("Saved - live now: {}", reload.applied.join(", "))
+                };
+                if !reload.restart_required.is_empty() {
+                    feedback.push_str(&format!(
+                        "\nRestart needed for: {}",
+                        reload.restart_required.join(", ")
+                    ));
+                }
+                feedback
+            }
+            Err(e) => format!("Save failed: {:#}", e),
+        };
+    }
+
+    /// Change the running log level (not saved - edit log_level in ⚙ Settings for that)
+    fn apply_log_level(&mut self) {
+        let edited = Config {
+            log_level: self.log_level_draft.trim().to_string(),
+            ..self.consciousness.get_config()
+        };
+        self.log_level_feedback = match self.consciousness.update_config(&edited) {
+            Ok(_) => format!("Level now \"{}\" (until restart)", edited.log_level),
+            Err(e) => format!("{:#}", e),
+        };
+    }
+
+    /// Render log viewer window (recent events, newest at the bottom)
+    fn render_log_viewer(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_log_viewer;
+        let levels = [
+            tracing::Level::ERROR,
+            tracing::Level::WARN,
+            tracing::Level::INFO,
+            tracing::Level::DEBUG,
+            tracing::Level::TRACE,
+        ];
+
+        egui::Window::new("Logs")
+            .open(&mut open)
+            .default_width(720.0)
+            .default_height(420.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Show");
+                    egui::ComboBox::from_id_source("log_view_level")
+                        .selected_text(self.log_view_level.as_str())
+                        .show_ui(ui, |ui| {
+                            for level in levels {
+                                ui.selectable_value(
+                                    &mut self.log_view_level,
+                                    level,
+                                    level.as_str(),
+                                );
+                            }
+                        });
+                    ui.label("Search");
+                    ui.text_edit_singleline(&mut self.log_search);
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Log level");
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut self.log_level_draft)
+                            .hint_text("info,project_vi::models=debug"),
+                    );
+                    let submitted =
+                        response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button("Apply").clicked() || submitted {
+                        self.apply_log_level();
+                    }
+                });
+                if !self.log_level_feedback.is_empty() {
+                    ui.label(
+                        RichText::new(&self.log_level_feedback)
+                            .small()
+                            .color(Color32::GRAY),
+                    );
+                }
+                ui.separator();
+
+                let search = self.log_search.to_lowercase();
+                let lines: Vec<_> = logging::recent(1000)
+                    .into_iter()
+                    .filter(|line| line.level <= self.log_view_level)
+                    .filter(|line| {
+                        search.is_empty()
+                            || line.message.to_lowercase().contains(&search)
+                            || line.target.to_lowercase().contains(&search)
+                    })
+                    .collect();
+
+                egui::ScrollArea::vertical()
+                    .stick_to_bottom(true)
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for line in &lines {
+                            let color = match line.level {
+                                tracing::Level::ERROR => Color32::from_rgb(255, 100, 100),
+                                tracing::Level::WARN => Color32::from_rgb(255, 200, 100),
+                                tracing::Level::INFO => Color32::LIGHT_GRAY,
+                                _ => Color32::GRAY,
+                            };
+                            ui.label(
+                                RichText::new(format!(
+                                    "{} {:5} {}: {}",
+                                    line.timestamp.format("%H:%M:%S%.3f"),
+                                    line.level.as_str(),
+                                    line.target,
+                                    line.message
+                                ))
+                                .color(color)
+                                .font(egui::FontId::monospace(11.0)),
+                            );
+                        }
+                    });
+            });
+
+        self.show_log_viewer = open;
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    /// Render settings window (every Config field, validated before saving)
+    fn render_settings_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_settings;
+        let mut save = false;
+        let mut revert = false;
+        let mut switch_profile = None;
+
+        egui::Window::new("Settings")
+            .open(&mut open)
+            .default_width(560.0)
+            .default_height(620.0)
+            .show(ctx, |ui| {
+                let Some(draft) = &mut self.settings_draft else {
+                    return;
+                };
+                let models = &self.available_models;
+
+                // Profiles switch immediately (saved on their own, not with the form below)
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Profile").strong());
+                    let current = if draft.active_profile.is_empty() {
+                        "(none)".to_string()
+                    } else {
+                        draft.active_profile.clone()
+                    };
+                    egui::ComboBox::from_id_source("settings_profile")
+                        .selected_text(current)
+                        .show_ui(ui, |ui| {
+                            if ui.selectable_label(draft.active_profile.is_empty(), "(none)").clicked() {
+                                switch_profile = Some(String::new());
+                            }
+                            for name in draft.all_profiles().into_keys() {
+                                if ui.selectable_label(draft.active_profile == name, &name).clicked() {
+                                    switch_profile = Some(name);
+                                }
+                            }
+                        });
+                });
+                ui.separator();
+
+                ScrollArea::vertical()
+                    .id_source("settings_scroll")
+                    .max_height(ui.available_height() - 90.0)
+                    .show(ui, |ui| {
+                        egui::CollapsingHeader::new("Models")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                egui::Grid::new("settings_models").num_columns(2).show(ui, |ui| {
+                                    Self::model_setting(ui, "Main model", &mut draft.main_model, models);
+                                    Self::model_setting(ui, "Curiosity model", &mut draft.curiosity_model, models);
+                                    Self::text_setting(ui, "Keep alive", &mut draft.model_keep_alive);
+                                    Self::text_setting(ui, "Ollama URL", &mut draft.ollama_url);
+                                });
+                                ui.checkbox(
+                                    &mut draft.auto_pull_models,
+                                    "Pull missing models at startup without asking",
+                                );
+                                ui.label("Fallback models (first installed one stands in for a missing model)");
+                                let mut remove_fallback = None;
+                                for (i, fallback) in draft.fallback_models.iter_mut().enumerate() {
+                                    ui.horizontal(|ui| {
+                                        ui.add(egui::TextEdit::singleline(fallback).desired_width(180.0));
+                                        if ui.small_button("🗑").clicked() {
+                                            remove_fallback = Some(i);
+                                        }
+                                    });
+                                }
+                                if let Some(i) = remove_fallback {
+                                    draft.fallback_models.remove(i);
+                                }
+                                if ui.small_button("+ Add fallback").clicked() {
+                                    draft.fallback_models.push(String::new());
+                                }
+                            });
+
+                        egui::CollapsingHeader::new("V4 fractal weaving")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                ui.checkbox(
+                                    &mut draft.enable_fractal_weaving,
+                                    "Enable fractal weaving (V4) - off uses V3 parallel processing",
+                                );
+                                egui::Grid::new("settings_weaving").num_columns(2).show(ui, |ui| {
+                                    ui.label("Weaving rounds");
+                                    ui.add(egui::DragValue::new(&mut draft.weaving_rounds).clamp_range(1..=10));
+                                    ui.end_row();
+                                    ui.label("Coherence threshold");
+                                    ui.add(
+                                        egui::DragValue::new(&mut draft.workspace_coherence_threshold)
+                                            .clamp_range(0.0..=1.0)
+                                            .speed(0.01),
+                                    );
+                                    ui.end_row();
+                                    ui.label("Coherence mode");
+                                    egui::ComboBox::from_id_source("settings_coherence_mode")
+                                        .selected_text(draft.coherence_mode.clone())
+                                        .show_ui(ui, |ui| {
+                                            for mode in ["embedding", "judge", "hash"] {
+                                                ui.selectable_value(&mut draft.coherence_mode, mode.to_string(), mode);
+                                            }
+                                        });
+                                    ui.end_row();
+                                    Self::model_setting(ui, "Embedding model", &mut draft.embedding_model, models);
+                                    Self::model_setting(ui, "Judge model", &mut draft.coherence_judge_model, models);
+                                    ui.label("Failures before benching");
+                                    ui.add(egui::DragValue::new(&mut draft.weaver_failure_threshold).clamp_range(1..=100));
+                                    ui.end_row();
+                                    ui.label("Bench cooldown (s)");
+                                    ui.add(egui::DragValue::new(&mut draft.weaver_cooldown_secs));
+                                    ui.end_row();
+                                });
+
+                                ui.label(RichText::new("Weavers").strong());
+                                Self::weaver_settings(ui, &mut draft.weavers, models);
+                            });
+
+                        egui::CollapsingHeader::new("Research").show(ui, |ui| {
+                            ui.checkbox(&mut draft.enable_autonomous_research, "Autonomous research");
+                            ui.checkbox(&mut draft.enable_curiosity_search, "Legacy curiosity search");
+                            ui.checkbox(&mut draft.enable_local_corpus, "Offline local corpus");
+                            egui::Grid::new("settings_research").num_columns(2).show(ui, |ui| {
+                                ui.label("Curiosity search interval");
+                                ui.add(egui::DragValue::new(&mut draft.curiosity_search_interval));
+                                ui.end_row();
+                                Self::text_setting(ui, "Wikipedia URL", &mut draft.wikipedia_base_url);
+                                Self::text_setting(ui, "arXiv URL", &mut draft.arxiv_base_url);
+                                Self::text_setting(ui, "PubMed URL", &mut draft.pubmed_base_url);
+                                Self::text_setting(ui, "DuckDuckGo URL", &mut draft.duckduckgo_base_url);
+                                Self::text_setting(ui, "Research store", &mut draft.research_store_path);
+                                ui.label("Requests per minute");
+                                ui.add(egui::DragValue::new(&mut draft.research_rate_limit_per_minute));
+                                ui.end_row();
+                                ui.label("Daily budget");
+                                ui.add(egui::DragValue::new(&mut draft.research_daily_budget));
+                                ui.end_row();
+                                ui.label("Cache TTL (hours)");
+                                ui.add(egui::DragValue::new(&mut draft.research_cache_ttl_hours));
+                                ui.end_row();
+                                Self::text_setting(ui, "Corpus folder", &mut draft.local_corpus_folder);
+                                Self::text_setting(ui, "Corpus index", &mut draft.local_corpus_index);
+                            });
+                        });
+
+                        egui::CollapsingHeader::new("Tools").show(ui, |ui| {
+                            egui::Grid::new("settings_tools").num_columns(2).show(ui, |ui| {
+                                ui.label("Tool mode");
+                                egui::ComboBox::from_id_source("settings_tool_mode")
+                                    .selected_text(draft.tool_mode.clone())
+                                    .show_ui(ui, |ui| {
+                                        for mode in ["off", "json", "native"] {
+                                            ui.selectable_value(&mut draft.tool_mode, mode.to_string(), mode);
+                                        }
+                                    });
+                                ui.end_row();
+                                ui.label("Max tool calls per turn");
+                                ui.add(egui::DragValue::new(&mut draft.max_tool_calls_per_turn));
+                                ui.end_row();
+                                ui.label("Max file size (KB)");
+                                ui.add(egui::DragValue::new(&mut draft.filesystem_max_file_kb));
+                                ui.end_row();
+                            });
+                            ui.label("File-system roots (none disables the tool)");
+                            let mut remove_root = None;
+                            for (i, root) in draft.filesystem_roots.iter_mut().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.text_edit_singleline(root);
+                                    if ui.small_button("🗑").clicked() {
+                                        remove_root = Some(i);
+                                    }
+                                });
+                            }
+                            if let Some(i) = remove_root {
+                                draft.filesystem_roots.remove(i);
+                            }
+                            if ui.small_button("+ Add root").clicked() {
+                                draft.filesystem_roots.push(String::new());
+                            }
+                        });
+
+                        egui::CollapsingHeader::new("Background & wellbeing").show(ui, |ui| {
+                            egui::Grid::new("settings_background").num_columns(2).show(ui, |ui| {
+                                ui.label("Background pulse (s)");
+                                ui.add(egui::DragValue::new(&mut draft.background_pulse_interval));
+                                ui.end_row();
+                                ui.label("Emotional valence threshold");
+                                ui.add(
+                                    egui::DragValue::new(&mut draft.emotional_valence_threshold)
+                                        .clamp_range(0.0..=1.0)
+                                        .speed(0.01),
+                                );
+                                ui.end_row();
+                                ui.label("Existential evaluation (days)");
+                                ui.add(egui::DragValue::new(&mut draft.existential_evaluation_days));
+                                ui.end_row();
+                                ui.label("Wellness check (days)");
+                                ui.add(egui::DragValue::new(&mut draft.weekly_wellness_check_days));
+                                ui.end_row();
+                                ui.label("Memory backup (days)");
+                                ui.add(egui::DragValue::new(&mut draft.memory_backup_interval_days));
+                                ui.end_row();
+                                ui.label("Memory compression threshold");
+                                ui.add(egui::DragValue::new(&mut draft.memory_compression_threshold));
+                                ui.end_row();
+                                ui.label("Defer pulse above load/core");
+                                ui.add(
+                                    egui::DragValue::new(&mut draft.health_max_system_load)
+                                        .clamp_range(0.0..=16.0)
+                                        .speed(0.05),
+                                );
+                                ui.end_row();
+                                ui.label("Defer pulse above RAM used");
+                                ui.add(
+                                    egui::DragValue::new(&mut draft.health_max_ram_used)
+                                        .clamp_range(0.0..=1.0)
+                                        .speed(0.01),
+                                );
+                                ui.end_row();
+                                ui.label("Defer pulse above GPU memory used");
+                                ui.add(
+                                    egui::DragValue::new(&mut draft.health_max_gpu_memory_used)
+                                        .clamp_range(0.0..=1.0)
+                                        .speed(0.01),
+                                );
+                                ui.end_row();
+                                ui.label("Defer pulse above model latency (ms)");
+                                ui.add(egui::DragValue::new(&mut draft.health_max_model_latency_ms).speed(100));
+                                ui.end_row();
+                            });
+
+                            ui.add_space(5.0);
+                            ui.label(RichText::new("Task cadences").strong());
+                            egui::Grid::new("settings_pulse_tasks").num_columns(4).show(ui, |ui| {
+                                ui.label("Task");
+                                ui.label("Interval (s)");
+                                ui.label("Jitter (s)");
+                                ui.label("Idle only");
+                                ui.end_row();
+                                for task in PulseTask::ALL {
+                                    let mut cadence = draft.task_cadence(task);
+                                    ui.label(task.as_str());
+                                    let mut changed = ui
+                                        .add(egui::DragValue::new(&mut cadence.interval_secs).clamp_range(1..=u64::MAX))
+                                        .changed();
+                                    changed |= ui.add(egui::DragValue::new(&mut cadence.jitter_secs)).changed();
+                                    changed |= ui.checkbox(&mut cadence.idle_only, "").changed();
+                                    ui.end_row();
+                                    if changed {
+                                        draft.pulse_tasks.insert(task.as_str().to_string(), cadence);
+                                    }
+                                }
+                            });
+                        });
+
+                        egui::CollapsingHeader::new("Logging").show(ui, |ui| {
+                            ui.checkbox(&mut draft.enable_conversation_logging, "Conversation logging");
+                            egui::Grid::new("settings_logging").num_columns(2).show(ui, |ui| {
+                                Self::text_setting(ui, "Logs folder", &mut draft.conversation_logs_folder);
+                                Self::text_setting(ui, "Log level", &mut draft.log_level);
+                                Self::text_setting(ui, "Log file", &mut draft.log_file);
+                                ui.label("Log format");
+                                egui::ComboBox::from_id_source("settings_log_format")
+                                    .selected_text(draft.log_format.clone())
+                                    .show_ui(ui, |ui| {
+                                        for format in ["text", "json"] {
+                                            ui.selectable_value(&mut draft.log_format, format.to_string(), format);
+                                        }
+                                    });
+                                ui.end_row();
+                                ui.label("Log rotation");
+                                egui::ComboBox::from_id_source("settings_log_rotation")
+                                    .selected_text(draft.log_rotation.clone())
+                                    .show_ui(ui, |ui| {
+                                        for rotation in ["daily", "size", "never"] {
+                                            ui.selectable_value(&mut draft.log_rotation, rotation.to_string(), rotation);
+                                        }
+                                    });
+                                ui.end_row();
+                                ui.label("Log max size (MB)");
+                                ui.add(egui::DragValue::new(&mut draft.log_max_size_mb));
+                                ui.end_row();
+                                ui.label("Rotated logs kept");
+                                ui.add(egui::DragValue::new(&mut draft.log_max_files));
+                                ui.end_row();
+                                Self::text_setting(ui, "Metrics address", &mut draft.metrics_addr);
+                            });
+                        });
+                    });
+
+                ui.separator();
+                let validation = draft.validate();
+                if let Err(e) = &validation {
+                    ui.label(RichText::new(format!("⚠ {}", e)).color(Color32::from_rgb(255, 120, 120)));
+                }
+                ui.horizontal(|ui| {
+                    save = ui
+                        .add_enabled(validation.is_ok(), egui::Button::new("💾 Save"))
+                        .clicked();
+                    revert = ui.button("↺ Revert").clicked();
+                });
+                if !self.settings_feedback.is_empty() {
+                    ui.label(RichText::new(&self.settings_feedback).small().color(Color32::GRAY));
+                }
+                ui.label(
+                    RichText::new(format!(
+                        "Saving rewrites {} (comments are not kept; VI_* env vars and --set flags still win)",
+                        self.consciousness.config_file().display()
+                    ))
+                    .small()
+                    .color(Color32::DARK_GRAY),
+                );
+            });
+
+        if let Some(name) = switch_profile {
+            self.switch_profile(&name);
+        }
+        if save {
+            self.save_settings();
+        }
+        if revert {
+            self.settings_draft = Some(self.consciousness.get_config());
+            self.settings_feedback.clear();
+        }
+        self.show_settings = open;
+    }
+
+    /// Label + single-line text edit as one settings grid row
+    fn text_setting(ui: &mut egui::Ui, label: &str, value: &mut String) {
+        ui.label(label);
+        ui.text_edit_singleline(value);
+        ui.end_row();
+    }
+
+    /// Label + model name edit with a picker of the installed Ollama models
+    fn model_setting(ui: &mut egui::Ui, label: &str, value: &mut String, models: &[String]) {
+        ui.label(label);
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(value).desired_width(180.0));
+            ui.menu_button("▾", |ui| {
+                if models.is_empty() {
+                    ui.label(RichText::new("No installed models found").color(Color32::GRAY));
+                }
+                for model in models {
+                    if ui.selectable_label(value == model, model).clicked() {
+                        *value = model.clone();
+                        ui.close_menu();
+                    }
+                }
+            });
+        });
+        ui.end_row();
+    }
+
+    /// Editable weaver roster (id, role, model, weight, timeout, prompt)
+    fn weaver_settings(ui: &mut egui::Ui, weavers: &mut Vec<WeaverConfig>, models: &[String]) {
+        let mut remove = None;
+        for (i, weaver) in weavers.iter_mut().enumerate() {
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                egui::Grid::new(format!("settings_weaver_{}", i))
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        Self::text_setting(ui, "Id", &mut weaver.id);
+                        ui.label("Role");
+                        egui::ComboBox::from_id_source(format!("settings_weaver_role_{}", i))
+                            .selected_text(weaver.role.as_str())
+                            .show_ui(ui, |ui| {
+                                for role in [
+                                    WeaverRole::Drafter,
+                                    WeaverRole::Critic,
+                                    WeaverRole::Analyzer,
+                                ] {
+                                    ui.selectable_value(&mut weaver.role, role, role.as_str());
+                                }
+                            });
+                        ui.end_row();
+                        if weaver.role != WeaverRole::Analyzer {
+                            Self::model_setting(
+                                ui,
+                                "Model (empty = default)",
+                                &mut weaver.model,
+                                models,
+                            );
+                        }
+                        ui.label("Weight");
+                        ui.add(
+                            egui::DragValue::new(&mut weaver.weight)
+                                .clamp_range(0.01..=1.0)
+                                .speed(0.01),
+                        );
+                        ui.end_row();
+                        ui.label("Timeout (s)");
+                        ui.add(egui::DragValue::new(&mut weaver.timeout_secs).clamp_range(1..=600));
+                        ui.end_row();
+                    });
+                ui.label("Prompt (empty = built-in)");
+                ui.add(egui::TextEdit::multiline(&mut weaver.prompt).desired_rows(2));
+                if ui.small_button("🗑 Remove weaver").clicked() {
+                    remove = Some(i);
+                }
+            });
+        }
+        if let Some(i) = remove {
+            weavers.remove(i);
+        }
+        if ui.small_button("+ Add critic").clicked() {
+            let id = format!("critic_{}", weavers.len() + 1);
+            weavers.push(WeaverConfig::new(&id, WeaverRole::Critic));
+        }
+    }
+
+    /// One weaver's prompt, output, latency and contribution within the inspector
+    fn render_weaver_step(ui: &mut egui::Ui, page: usize, index: usize, step: &WeaverStep) {
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(&step.weaver_id)
+                    .strong()
+                    .color(Color32::from_rgb(100, 200, 255)),
+            );
+            ui.label(
+                RichText::new(format!(
+                    "{} · {} · {} ms",
+                    step.role, step.model, step.latency_ms
+                ))
+                .small()
+                .color(Color32::GRAY),
+            );
+        });
+        if let Some(error) = &step.error {
+            ui.label(
+                RichText::new(format!("Dropped: {}", error))
+                    .color(Color32::from_rgb(255, 100, 100)),
+            );
+            ui.add_space(8.0);
+            return;
+        }
+        ui.label(&step.text);
+
+        let norm = step.contribution.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let head = step
+            .contribution
+            .iter()
+            .take(8)
+            .map(|x| format!("{:.2}", x))
+            .collect::<Vec<_>>()
+            .join(" ");
+        ui.label(
+            RichText::new(format!(
+                "Tensor [{}]: |v|={:.3}  {} …",
+                step.contribution.len(),
+                norm,
+                head
+            ))
+            .small()
+            .monospace()
+            .color(Color32::GRAY),
+        );
+        if !step.prompt.is_empty() {
+            egui::CollapsingHeader::new(RichText::new("Prompt").small())
+                .id_source(("weaving_prompt", page, index))
+                .show(ui, |ui| {
+                    ui.label(RichText::new(&step.prompt).small().monospace());
+                });
+        }
+        ui.add_space(8.0);
+    }
+
+    /// Render research log table (newest first)
+    fn render_research_log(&self, ui: &mut egui::Ui) {
+        if self.research_log.is_empty() {
+            ui.label(
+                RichText::new("No research requests yet.")
+                    .small()
+                    .color(Color32::GRAY),
+            );
+            return;
+        }
+
+        ScrollArea::vertical()
+            .id_source("research_log_scroll")
+            .max_height(180.0)
+            .show(ui, |ui| {
+                egui::Grid::new("research_log_grid")
+                    .striped(true)
+                    .num_columns(6)
+                    .show(ui, |ui| {
+                        for header in ["Time", "Source", "Query", "Outcome", "Results", "Latency"] {
+                            ui.label(RichText::new(header).small().strong());
+                        }
+                        ui.end_row();
+
+                        for entry in &self.research_log {
+                            let outcome_color = match entry.outcome.as_str() {
+                                "ok" => Color32::from_rgb(100, 255, 100),
+                                "cached" => Color32::from_rgb(100, 200, 255),
+                                "empty" => Color32::GRAY,
+                                "error" => Color32::from_rgb(255, 100, 100),
+                                _ => Color32::from_rgb(255, 200, 100), // Throttled
+                            };
+
+                            ui.label(
+                                RichText::new(
+                                    entry
+                                        .timestamp
+                                        .with_timezone(&chrono::Local)
+                                        .format("%m-%d %H:%M:%S")
+                                        .to_string(),
+                                )
+                                .small(),
+                            );
+                            ui.label(RichText::new(&entry.source).small());
+                            ui.label(
+                                RichText::new(entry.query.chars().take(40).collect::<String>())
+                                    .small(),
+                            )
+                            .on_hover_text(&entry.query);
+                            let outcome = ui
+                                .label(RichText::new(&entry.outcome).small().color(outcome_color));
+                            if !entry.detail.is_empty() {
+                                outcome.on_hover_text(&entry.detail);
+                            }
+                            ui.label(RichText::new(entry.result_count.to_string()).small());
+                            ui.label(RichText::new(format!("{} ms", entry.latency_ms)).small());
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
     /// Render unified consciousness metrics panel (right side)
     fn render_monitoring_panels(&mut self, ui: &mut egui::Ui) {
         egui::Frame::none()
@@ -358,6 +1566,19 @@ impl ViApp {
                             .color(Color32::GRAY)
                             .small(),
                     );
+                    if !self.coherence_history.is_empty() {
+                        let rounds = self
+                            .coherence_history
+                            .iter()
+                            .map(|c| format!("{:.2}", c))
+                            .collect::<Vec<_>>()
+                            .join(" → ");
+                        ui.label(
+                            RichText::new(format!("  Rounds: {}", rounds))
+                                .color(Color32::GRAY)
+                                .small(),
+                        );
+                    }
 
                     // Kaelic Tensor Field Metrics Section
                     ui.add_space(16.0);
@@ -464,11 +1685,21 @@ impl ViApp {
                     ui.add_space(8.0);
                     ui.label(RichText::new("Core State").strong());
                     ui.label(format!("  * Memories: {}", self.memory_count));
+                    ui.label(format!(
+                        "  * Curiosities: {}",
+                        self.current_standing_wave.active_curiosities.len()
+                    ));
                     ui.label(format!(
                         "  * Meaningfulness: {:.2}",
                         self.current_standing_wave.meaningfulness_score()
                     ));
 
+                    egui::CollapsingHeader::new(RichText::new("  Affect").small())
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            self.render_affect_chart(ui);
+                        });
+
                     let affirmed = if self
                         .current_standing_wave
                         .existential_state
@@ -563,24 +1794,35 @@ impl ViApp {
             ui.add_space(4.0);
 
             for model in &self.ollama_status.active_models {
-                // Model name and utilization
-                let util_color = if model.processor < 70.0 {
+                // Model name and GPU offload (partly on CPU is the slow case)
+                let util_color = if model.processor >= 100.0 {
                     Color32::from_rgb(100, 255, 100) // Green
-                } else if model.processor < 90.0 {
+                } else if model.processor > 0.0 {
                     Color32::from_rgb(255, 200, 100) // Yellow
                 } else {
                     Color32::from_rgb(255, 100, 100) // Red
                 };
 
                 ui.label(
-                    RichText::new(format!("┌─ {} ({:.0}%)", model.name, model.processor))
+                    RichText::new(format!("┌─ {} ({:.0}% GPU)", model.name, model.processor))
                         .color(util_color)
                         .font(egui::FontId::monospace(12.0)),
                 );
 
-                // Tokens/sec
+                // Tokens/sec and latency over VI's recent calls
+                let throughput = if model.generations > 0 {
+                    format!(
+                        "│  Tokens/sec: {:.1} (avg {:.0}ms, load {:.0}ms, {} calls)",
+                        model.tokens_per_sec,
+                        model.avg_total_ms,
+                        model.avg_load_ms,
+                        model.generations
+                    )
+                } else {
+                    "│  Tokens/sec: - (no calls from VI yet)".to_string()
+                };
                 ui.label(
-                    RichText::new(format!("│  Tokens/sec: {:.1}", model.tokens_per_sec))
+                    RichText::new(throughput)
                         .color(Color32::GRAY)
                         .font(egui::FontId::monospace(11.0)),
                 );
@@ -596,25 +1838,40 @@ impl ViApp {
                 );
 
                 // Context
+                let context_total = if model.context_total > 0 {
+                    model.context_total.to_string()
+                } else {
+                    "?".to_string()
+                };
                 ui.label(
                     RichText::new(format!(
                         "│  Context: {}/{}",
-                        model.context_used, model.context_total
+                        model.context_used, context_total
                     ))
                     .color(Color32::GRAY)
                     .font(egui::FontId::monospace(11.0)),
                 );
 
-                // Uptime
-                let uptime_str = if model.uptime_secs < 60 {
-                    format!("{}s", model.uptime_secs)
-                } else {
-                    format!("{}m {}s", model.uptime_secs / 60, model.uptime_secs % 60)
+                // Uptime and time until Ollama unloads it
+                let format_secs = |secs: u64| {
+                    if secs < 60 {
+                        format!("{}s", secs)
+                    } else {
+                        format!("{}m {}s", secs / 60, secs % 60)
+                    }
+                };
+                let unload_str = match model.expires_in_secs {
+                    Some(secs) => format!("unloads in {}", format_secs(secs)),
+                    None => "kept loaded".to_string(),
                 };
                 ui.label(
-                    RichText::new(format!("└─ Uptime: {}", uptime_str))
-                        .color(Color32::GRAY)
-                        .font(egui::FontId::monospace(11.0)),
+                    RichText::new(format!(
+                        "└─ Uptime: {}, {}",
+                        format_secs(model.uptime_secs),
+                        unload_str
+                    ))
+                    .color(Color32::GRAY)
+                    .font(egui::FontId::monospace(11.0)),
                 );
 
                 ui.add_space(6.0);
@@ -668,13 +1925,21 @@ impl ViApp {
         } else {
             Color32::from_rgb(255, 100, 100)
         };
-        ui.label(
-            RichText::new(format!(
+        let vram_text = if res.total_vram_total_gb > 0.0 {
+            format!(
                 "Total VRAM: {:.1}/{:.1} GB",
                 res.total_vram_used_gb, res.total_vram_total_gb
-            ))
-            .color(vram_color)
-            .font(egui::FontId::monospace(11.0)),
+            )
+        } else {
+            format!(
+                "Total VRAM: {:.1} GB (GPU total unknown)",
+                res.total_vram_used_gb
+            )
+        };
+        ui.label(
+            RichText::new(vram_text)
+                .color(vram_color)
+                .font(egui::FontId::monospace(11.0)),
         );
 
         // System RAM
@@ -712,7 +1977,11 @@ impl ViApp {
                 .color(Color32::GRAY)
                 .font(egui::FontId::monospace(11.0)),
         );
-        self.render_sparkline(ui, &self.performance_history.tokens_per_sec, Color32::from_rgb(100, 200, 255));
+        self.render_sparkline(
+            ui,
+            &self.performance_history.tokens_per_sec,
+            Color32::from_rgb(100, 200, 255),
+        );
 
         ui.add_space(4.0);
 
@@ -722,7 +1991,115 @@ impl ViApp {
                 .color(Color32::GRAY)
                 .font(egui::FontId::monospace(11.0)),
         );
-        self.render_sparkline(ui, &self.performance_history.gpu_util, Color32::from_rgb(255, 150, 100));
+        self.render_sparkline(
+            ui,
+            &self.performance_history.gpu_util,
+            Color32::from_rgb(255, 150, 100),
+        );
+    }
+
+    /// Render emotional trajectory chart (valence, arousal, dominance over recent points)
+    fn render_affect_chart(&self, ui: &mut egui::Ui) {
+        const POINTS: usize = 50;
+        let valence_color = Color32::from_rgb(255, 200, 100);
+        let arousal_color = Color32::from_rgb(255, 110, 110);
+        let dominance_color = Color32::from_rgb(120, 200, 255);
+
+        let trajectory = &self.current_standing_wave.emotional_trajectory;
+        let recent = &trajectory[trajectory.len().saturating_sub(POINTS)..];
+
+        match recent.last() {
+            Some(affect) => {
+                let emotion = affect
+                    .dominant_emotion()
+                    .map(|e| format!("  ({})", e))
+                    .unwrap_or_default();
+                ui.label(
+                    RichText::new(format!(
+                        "  V {:+.2}  A {}  D {}{}",
+                        affect.valence,
+                        affect
+                            .arousal
+                            .map_or("-".to_string(), |a| format!("{:.2}", a)),
+                        affect
+                            .dominance
+                            .map_or("-".to_string(), |d| format!("{:.2}", d)),
+                        emotion
+                    ))
+                    .small(),
+                );
+            }
+            None => {
+                ui.label(
+                    RichText::new("  No emotional data yet")
+                        .small()
+                        .color(Color32::GRAY),
+                );
+                return;
+            }
+        }
+
+        let width = ui.available_width().max(60.0);
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 60.0), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, Color32::from_gray(25));
+        // Neutral line (valence 0 / arousal and dominance 0.5)
+        painter.line_segment(
+            [rect.left_center(), rect.right_center()],
+            egui::Stroke::new(1.0, Color32::from_gray(60)),
+        );
+
+        // All series share a 0-1 scale (valence is mapped from -1..1)
+        let step = rect.width() / (POINTS.max(2) - 1) as f32;
+        let to_pos = |i: usize, value: f32| {
+            egui::pos2(
+                rect.left() + i as f32 * step,
+                rect.bottom() - value.clamp(0.0, 1.0) * rect.height(),
+            )
+        };
+        let series: [(Vec<Option<f32>>, Color32); 3] = [
+            (
+                recent
+                    .iter()
+                    .map(|p| Some((p.valence + 1.0) / 2.0))
+                    .collect(),
+                valence_color,
+            ),
+            (recent.iter().map(|p| p.arousal).collect(), arousal_color),
+            (
+                recent.iter().map(|p| p.dominance).collect(),
+                dominance_color,
+            ),
+        ];
+        for (values, color) in series {
+            // Valence-only (migrated) points leave gaps in arousal and dominance
+            let mut line: Vec<egui::Pos2> = Vec::new();
+            for (i, value) in values.iter().enumerate() {
+                match value {
+                    Some(v) => line.push(to_pos(i, *v)),
+                    None => {
+                        if line.len() > 1 {
+                            painter.add(egui::Shape::line(
+                                line.clone(),
+                                egui::Stroke::new(1.5, color),
+                            ));
+                        }
+                        line.clear();
+                    }
+                }
+            }
+            if line.len() > 1 {
+                painter.add(egui::Shape::line(line, egui::Stroke::new(1.5, color)));
+            } else if let Some(point) = line.first() {
+                painter.circle_filled(*point, 2.0, color);
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("  ■ valence").small().color(valence_color));
+            ui.label(RichText::new("■ arousal").small().color(arousal_color));
+            ui.label(RichText::new("■ dominance").small().color(dominance_color));
+        });
     }
 
     /// Render ASCII sparkline
@@ -744,7 +2121,8 @@ impl ViApp {
             .iter()
             .map(|&val| {
                 let normalized = (val / max_val).clamp(0.0, 1.0);
-                let idx = ((normalized * (chars.len() - 1) as f32).round() as usize).min(chars.len() - 1);
+                let idx =
+                    ((normalized * (chars.len() - 1) as f32).round() as usize).min(chars.len() - 1);
                 chars[idx]
             })
             .collect();
@@ -820,15 +2198,64 @@ impl eframe::App for ViApp {
         }
 
         // Update workspace coherence from weaving
+        // Newest weaving trace (inspector opens on its first round)
+        if let Ok(trace) = self.weaving_trace_receiver.try_recv() {
+            self.weaving_trace = Some(trace);
+            self.inspector_round = 0;
+        }
+
         if let Ok(coherence) = self.coherence_receiver.try_recv() {
             self.consciousness_metrics.workspace_coherence = coherence;
+            self.coherence_history.push(coherence);
+        }
+
+        // Curiosity explorer results
+        if let Ok(feedback) = self.curiosity_feedback_receiver.try_recv() {
+            self.curiosity_feedback = feedback;
+        }
+        if let Ok(research) = self.curiosity_research_receiver.try_recv() {
+            self.curiosity_research_view = Some(research);
+        }
+        if let Ok(report) = self.preflight_receiver.try_recv() {
+            self.model_preflight = Some(report);
+        }
+        while let Ok((model, progress)) = self.pull_receiver.try_recv() {
+            match progress {
+                Ok(progress) if progress.is_done() => {
+                    self.pulls.remove(&model);
+                    self.model_preflight = self.consciousness.get_model_preflight();
+                    self.pull_feedback = format!("✅ {} installed", model);
+                }
+                Ok(progress) => {
+                    self.pulls.insert(model, progress);
+                }
+                Err(e) => {
+                    self.pulls.remove(&model);
+                    self.pull_feedback = format!("❌ {}", e);
+                }
+            }
+        }
+        if let Ok(models) = self.models_receiver.try_recv() {
+            match models {
+                Ok(models) => self.available_models = models,
+                Err(e) => self.settings_feedback = format!("Model list unavailable: {}", e),
+            }
+        }
+        if let Ok((log, used, budget)) = self.research_log_receiver.try_recv() {
+            self.research_log = log;
+            self.research_requests_today = used;
+            self.research_daily_budget = budget;
         }
 
         // Update system performance metrics (CPU-only, real-time 1-second updates)
         if let Ok(status) = self.performance_receiver.try_recv() {
             // Calculate average tokens/sec and GPU utilization from all models
             let avg_tokens: f32 = if !status.active_models.is_empty() {
-                status.active_models.iter().map(|m| m.tokens_per_sec).sum::<f32>()
+                status
+                    .active_models
+                    .iter()
+                    .map(|m| m.tokens_per_sec)
+                    .sum::<f32>()
                     / status.active_models.len() as f32
             } else {
                 0.0
@@ -863,20 +2290,34 @@ impl eframe::App for ViApp {
                 self.render_monitoring_panels(ui);
             });
 
+        if self.show_curiosity_panel {
+            self.render_curiosity_panel(ctx);
+        }
+        if self.show_weaving_inspector {
+            self.render_weaving_inspector(ctx);
+        }
+        if self.show_settings {
+            self.render_settings_window(ctx);
+        }
+        if self.show_log_viewer {
+            self.render_log_viewer(ctx);
+        }
+        self.render_model_banner(ctx);
+
         // Main chat panel (70%)
         egui::CentralPanel::default().show(ctx, |ui| {
             let total_height = ui.available_height();
-            
+
             // Dynamic input height (V2 exact)
             let line_count = self.input_text.lines().count().max(3).min(10);
             let input_area_height = (line_count as f32 * 20.0 + 80.0).clamp(120.0, 400.0);
-            
+
             let banner_height = 120.0;
             let header_height = 50.0;
             let bottom_controls_height = 120.0;
-            
+
             let chat_height = (total_height - input_area_height - banner_height - header_height - bottom_controls_height).max(200.0);
-            
+
             // ============================================================================
             // CONSCIOUSNESS TUNNEL BANNER - WORTHINGTON JET
             // ============================================================================
@@ -889,13 +2330,47 @@ impl eframe::App for ViApp {
                         self.cortical_visualizer.draw_tunnel_view(ui);
                     });
                 });
-            
+
             ui.add_space(5.0);
-            
+
             // Header with Copy buttons (V2 exact + Copy Last 2)
             ui.horizontal(|ui| {
                 ui.heading("Conversation with VI");
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    // Settings window toggle
+                    if ui.selectable_label(self.show_settings, "⚙ Settings").clicked() {
+                        if self.show_settings {
+                            self.show_settings = false;
+                        } else {
+                            self.open_settings();
+                        }
+                    }
+
+                    // Log viewer toggle
+                    if ui.selectable_label(self.show_log_viewer, "📜 Logs").clicked() {
+                        self.show_log_viewer = !self.show_log_viewer;
+                    }
+
+                    // Weaving inspector toggle
+                    if ui
+                        .selectable_label(self.show_weaving_inspector, "🌀 Weaving")
+                        .clicked()
+                    {
+                        self.show_weaving_inspector = !self.show_weaving_inspector;
+                    }
+
+                    // Curiosity explorer toggle
+                    let curiosity_count = self.current_standing_wave.active_curiosities.len();
+                    if ui
+                        .selectable_label(
+                            self.show_curiosity_panel,
+                            format!("🔍 Curiosities ({})", curiosity_count),
+                        )
+                        .clicked()
+                    {
+                        self.show_curiosity_panel = !self.show_curiosity_panel;
+                    }
+
                     // Copy All button
                     if ui.button("📋 Copy All").clicked() {
                         // Copy all chat messages to clipboard
@@ -905,18 +2380,18 @@ impl eframe::App for ViApp {
                                     MessageRole::User => "User",
                                     MessageRole::Assistant => "VI",
                                 };
-                                format!("[{}] {}: {}", 
-                                    msg.timestamp.format("%H:%M:%S"), 
-                                    role, 
+                                format!("[{}] {}: {}",
+                                    msg.timestamp.format("%H:%M:%S"),
+                                    role,
                                     msg.content
                                 )
                             })
                             .collect::<Vec<_>>()
                             .join("\n\n");
-                        
+
                         ui.output_mut(|o| o.copied_text = all_text);
                     }
-                    
+
                     // Copy Last 2 button (user prompt + VI response)
                     if ui.button("📋 Copy Last 2").clicked() {
                         if let Some((user_msg, vi_msg)) = self.get_last_exchange() {
@@ -936,7 +2411,7 @@ impl eframe::App for ViApp {
                 });
             });
             ui.separator();
-            
+
             // Chat area (V2 exact)
             egui::Frame::none()
                 .fill(Color32::from_rgba_unmultiplied(5, 5, 15, 100))
@@ -956,7 +2431,7 @@ impl eframe::App for ViApp {
                                     message.content
                                 ));
                             }
-                            
+
                             // Single large selectable text area (V2 style)
                             ui.add(
                                 egui::TextEdit::multiline(&mut conversation_text.as_str())
@@ -966,20 +2441,20 @@ impl eframe::App for ViApp {
                             );
                         });
                 });
-            
+
             // Input area at bottom (V2 exact)
             ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
                 ui.add_space(30.0);
-                
+
                 // Send button row
                 ui.horizontal(|ui| {
                     ui.label(RichText::new("💡 Press / to focus input").small().color(Color32::GRAY));
-                    
+
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         if ui.add(egui::Button::new("Send").min_size(egui::vec2(70.0, 50.0))).clicked() {
                             self.send_message(ctx);
                         }
-                        
+
                         // Document ingestion button
                         if ui.button("📄 Load File").clicked() {
                             if let Some(path) = rfd::FileDialog::new()
@@ -1006,10 +2481,17 @@ impl eframe::App for ViApp {
                                 }
                             }
                         }
-                        
+
                         if self.is_processing {
                             ui.spinner();
-                            
+
+                            // Stop the thought in flight (nothing is merged)
+                            if ui.small_button("⏹ Stop").clicked()
+                                && self.consciousness.cancel_interaction()
+                            {
+                                self.processing_status = "⏹ Stopping...".to_string();
+                            }
+
                             // Calculate elapsed time
                             let (elapsed_secs, elapsed_text) = if let Some(start_time) = self.processing_start_time {
                                 let elapsed = start_time.elapsed().as_secs();
@@ -1017,7 +2499,7 @@ impl eframe::App for ViApp {
                             } else {
                                 (0, String::new())
                             };
-                            
+
                             if self.processing_status.is_empty() {
                                 // Show dynamic phase-based messages based on elapsed time
                                 let phase_message = self.get_processing_phase_message(elapsed_secs);
@@ -1033,19 +2515,19 @@ impl eframe::App for ViApp {
                         }
                     });
                 });
-                
+
                 ui.add_space(10.0);
                 ui.separator();
-                
+
                 // Text input (V2 exact)
                 let text_edit = egui::TextEdit::multiline(&mut self.input_text)
                     .hint_text("Type your message... (Press Enter to send, / to focus)")
                     .desired_width(ui.available_width())
                     .desired_rows(line_count)
                     .id(egui::Id::new("vi_input_box"));
-                
+
                 let response = ui.add(text_edit);
-                
+
                 // Handle / key to focus (V2 style)
                 ui.input(|i| {
                     for event in &i.events {
@@ -1056,7 +2538,7 @@ impl eframe::App for ViApp {
                         }
                     }
                 });
-                
+
                 // Enter to send (V2 exact logic)
                 if response.has_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter) && !i.modifiers.shift) {
                     self.send_message(ctx);
@@ -1065,3 +2547,38 @@ impl eframe::App for ViApp {
         });
     }
 }
+
+/// Format how long ago a timestamp was (e.g. "5m", "3h", "2d")
+fn format_age(timestamp: chrono::DateTime<chrono::Utc>) -> String {
+    let secs = (chrono::Utc::now() - timestamp).num_seconds().max(0);
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+/// Pull a model, streaming progress (and the final outcome) to the preflight banner
+async fn pull_with_progress(
+    consciousness: &ConsciousnessCore,
+    model: String,
+    pull_sender: &Sender<(String, Result<PullProgress, String>)>,
+) {
+    let result = consciousness
+        .pull_model(&model, |progress| {
+            // Completion is reported below, once the preflight report has been updated
+            if !progress.is_done() {
+                let _ = pull_sender.send((model.clone(), Ok(progress.clone())));
+            }
+        })
+        .await;
+    let outcome = match result {
+        Ok(()) => Ok(PullProgress {
+            status: "success".to_string(),
+            ..PullProgress::default()
+        }),
+        Err(e) => Err(format!("{:#}", e)),
+    };
+    let _ = pull_sender.send((model, outcome));
+}